@@ -1,4 +1,4 @@
-use dharitri_wasm_debug::*;
+use dharitri_wasm_debug::{world_mock::EndpointKind, *};
 
 fn world() -> BlockchainMock {
     let mut blockchain = BlockchainMock::new();
@@ -12,3 +12,16 @@ fn world() -> BlockchainMock {
 fn adder_rs() {
     dharitri_wasm_debug::denali_rs("denali/adder.scen.json", world());
 }
+
+#[test]
+fn adder_endpoint_coverage_rs() {
+    let coverage =
+        dharitri_wasm_debug::denali_rs_endpoint_coverage("denali/adder.scen.json", world());
+
+    let adder_coverage = &coverage.contracts["file:output/adder.wasm"];
+    assert!(adder_coverage.uncalled_endpoints().is_empty());
+    assert_eq!(adder_coverage.endpoints["init"].calls, 1);
+    assert_eq!(adder_coverage.endpoints["add"].calls, 1);
+    assert_eq!(adder_coverage.endpoints["add"].statuses[&0], 1);
+    assert_eq!(adder_coverage.endpoints["getSum"].kind, EndpointKind::View);
+}
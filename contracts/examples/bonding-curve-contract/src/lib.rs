@@ -5,7 +5,7 @@ dharitri_wasm::derive_imports!();
 
 use dharitri_wasm_modules::{
     bonding_curve,
-    bonding_curve::utils::{events, owner_endpoints, storage, user_endpoints},
+    bonding_curve::utils::{curve_operations, events, owner_endpoints, storage, user_endpoints},
 };
 
 #[dharitri_wasm::contract]
@@ -13,6 +13,7 @@ pub trait Contract:
     bonding_curve::BondingCurveModule
     + storage::StorageModule
     + events::EventsModule
+    + curve_operations::CurveOperationsModule
     + user_endpoints::UserEndpointsModule
     + owner_endpoints::OwnerEndpointsModule
 {
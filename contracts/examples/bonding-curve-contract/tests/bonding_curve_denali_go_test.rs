@@ -0,0 +1,34 @@
+#[test]
+fn buy_go() {
+    dharitri_wasm_debug::denali_go("denali/buy.scen.json");
+}
+
+#[test]
+fn claim_go() {
+    dharitri_wasm_debug::denali_go("denali/claim.scen.json");
+}
+
+#[test]
+fn deploy_go() {
+    dharitri_wasm_debug::denali_go("denali/deploy.scen.json");
+}
+
+#[test]
+fn deposit_go() {
+    dharitri_wasm_debug::denali_go("denali/deposit.scen.json");
+}
+
+#[test]
+fn deposit_more_view_go() {
+    dharitri_wasm_debug::denali_go("denali/deposit_more_view.scen.json");
+}
+
+#[test]
+fn sell_go() {
+    dharitri_wasm_debug::denali_go("denali/sell.scen.json");
+}
+
+#[test]
+fn set_bonding_curve_go() {
+    dharitri_wasm_debug::denali_go("denali/set_bonding_curve.scen.json");
+}
@@ -0,0 +1,322 @@
+use dharitri_wasm::{
+    api::ManagedTypeApi,
+    derive::TypeAbi,
+    dharitri_codec,
+    dharitri_codec::dharitri_codec_derive::{NestedDecode, NestedEncode},
+    types::{BigUint, ManagedVec, SCResult},
+};
+use dharitri_wasm_debug::DebugApi;
+use dharitri_wasm_modules::bonding_curve::{
+    curves::{
+        curve_function::{CurveFunction, Rounding},
+        exponential_function::ExponentialFunction,
+        fixed_point::{self, CURVE_PRECISION},
+        linear_function::LinearFunction,
+        logarithmic_function::LogarithmicFunction,
+        piecewise_linear_function::{CurvePoint, PiecewiseLinearFunction},
+        power_function::PowerFunction,
+    },
+    function_selector::FunctionSelector,
+    utils::structs::CurveArguments,
+};
+
+fn big(value: u64) -> BigUint<DebugApi> {
+    BigUint::from(value)
+}
+
+fn fixed(value: u64) -> BigUint<DebugApi> {
+    BigUint::from(value) * CURVE_PRECISION
+}
+
+fn arguments() -> CurveArguments<DebugApi> {
+    CurveArguments {
+        available_supply: big(1000),
+        balance: big(1000),
+    }
+}
+
+/// Buy and sell prices of `amount` tokens starting from `token_start`.
+fn prices<T: CurveFunction<DebugApi>>(curve: &T, token_start: u64, amount: u64) -> (u64, u64) {
+    let buy_price = curve
+        .calculate_price(&big(token_start), &big(amount), &arguments(), Rounding::Up)
+        .unwrap();
+    let sell_price = curve
+        .calculate_price(
+            &big(token_start),
+            &big(amount),
+            &arguments(),
+            Rounding::Down,
+        )
+        .unwrap();
+    (buy_price.to_u64().unwrap(), sell_price.to_u64().unwrap())
+}
+
+fn assert_close(actual: BigUint<DebugApi>, expected: u128, tolerance: u128) {
+    let actual = actual.to_u64().map(u128::from).unwrap_or_else(|| {
+        let bytes = actual.to_bytes_be();
+        let mut padded = [0u8; 16];
+        padded[16 - bytes.len()..].copy_from_slice(bytes.as_slice());
+        u128::from_be_bytes(padded)
+    });
+    let difference = actual.abs_diff(expected);
+    assert!(
+        difference <= tolerance,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn fixed_point_exp_ln_test() {
+    let _ = DebugApi::dummy();
+
+    assert_eq!(fixed_point::exp(&big(0)).unwrap(), fixed(1));
+    assert_close(
+        fixed_point::exp(&fixed(1)).unwrap(),
+        2_718_281_828_459_045_235,
+        100,
+    );
+    assert_eq!(fixed_point::ln(&big(1)).unwrap(), big(0));
+    assert_close(
+        fixed_point::ln(&big(10)).unwrap(),
+        2_302_585_092_994_045_684,
+        100,
+    );
+    assert_close(
+        fixed_point::ln(&big(1_000_000)).unwrap(),
+        13_815_510_557_964_274_104,
+        100,
+    );
+    assert!(fixed_point::ln(&big(0)).is_err());
+    assert!(fixed_point::exp(&fixed(1000)).is_err());
+}
+
+#[test]
+fn linear_function_test() {
+    let _ = DebugApi::dummy();
+    let linear = LinearFunction {
+        initial_price: big(1),
+        linear_coefficient: big(1),
+    };
+
+    // x^2 / 2 + x
+    assert_eq!(prices(&linear, 0, 10), (60, 60));
+    assert_eq!(prices(&linear, 0, 3), (8, 7));
+    assert_eq!(prices(&linear, 10, 5), (68, 67));
+}
+
+#[test]
+fn exponential_function_test() {
+    let _ = DebugApi::dummy();
+    let exponential = ExponentialFunction {
+        initial_price: big(100),
+        growth_rate: BigUint::from(CURVE_PRECISION / 100),
+    };
+
+    // 100 * (e^(0.01 * 10) - 1) / 0.01 = 1051.709...
+    assert_eq!(prices(&exponential, 0, 10), (1052, 1051));
+    // 100 * (e^1.5 - e^1) / 0.01 = 17634.072...
+    assert_eq!(prices(&exponential, 100, 50), (17635, 17634));
+
+    let flat = ExponentialFunction {
+        initial_price: big(100),
+        growth_rate: big(0),
+    };
+    assert!(flat
+        .calculate_price(&big(0), &big(10), &arguments(), Rounding::Up)
+        .is_err());
+}
+
+#[test]
+fn power_function_test() {
+    let _ = DebugApi::dummy();
+    let power = PowerFunction {
+        initial_price: big(5),
+        coefficient: fixed(2),
+        exponent: 2,
+    };
+
+    // 2 * 10^3 / 3 + 5 * 10 = 716.666...
+    assert_eq!(prices(&power, 0, 10), (717, 716));
+    // 2 * (20^3 - 10^3) / 3 + 5 * 10 = 4716.666...
+    assert_eq!(prices(&power, 10, 10), (4717, 4716));
+}
+
+#[test]
+fn logarithmic_function_test() {
+    let _ = DebugApi::dummy();
+    let logarithmic = LogarithmicFunction {
+        initial_price: big(10),
+        coefficient: fixed(100),
+    };
+
+    // 100 * (101 * ln(101) - 100) + 10 * 100 = 37612.717...
+    assert_eq!(prices(&logarithmic, 0, 100), (37613, 37612));
+    // 4763.068...
+    assert_eq!(prices(&logarithmic, 100, 10), (4764, 4763));
+}
+
+#[test]
+fn piecewise_linear_function_test() {
+    let _ = DebugApi::dummy();
+    let mut points = ManagedVec::new();
+    points.push(CurvePoint {
+        supply: big(0),
+        price: big(10),
+    });
+    points.push(CurvePoint {
+        supply: big(100),
+        price: big(20),
+    });
+    points.push(CurvePoint {
+        supply: big(200),
+        price: big(5),
+    });
+    let piecewise = PiecewiseLinearFunction { points };
+
+    // 100 * (10 + 20) / 2 = 1500
+    assert_eq!(prices(&piecewise, 0, 100), (1500, 1500));
+    // 1500 + 50 * (20 + 12.5) / 2 = 2312.5
+    assert_eq!(prices(&piecewise, 0, 150), (2313, 2312));
+    // 2312.5 - 50 * (10 + 15) / 2 = 1687.5
+    assert_eq!(prices(&piecewise, 50, 100), (1688, 1687));
+    // constant after the last point
+    assert_eq!(prices(&piecewise, 250, 10), (50, 50));
+
+    let mut unordered_points = ManagedVec::new();
+    unordered_points.push(CurvePoint {
+        supply: big(0),
+        price: big(10),
+    });
+    unordered_points.push(CurvePoint {
+        supply: big(0),
+        price: big(20),
+    });
+    let unordered = PiecewiseLinearFunction {
+        points: unordered_points,
+    };
+    assert!(unordered
+        .calculate_price(&big(0), &big(10), &arguments(), Rounding::Up)
+        .is_err());
+
+    // the invalid point lies after the requested supply
+    let mut late_unordered_points = ManagedVec::new();
+    late_unordered_points.push(CurvePoint {
+        supply: big(0),
+        price: big(10),
+    });
+    late_unordered_points.push(CurvePoint {
+        supply: big(100),
+        price: big(20),
+    });
+    late_unordered_points.push(CurvePoint {
+        supply: big(50),
+        price: big(30),
+    });
+    let late_unordered = PiecewiseLinearFunction {
+        points: late_unordered_points,
+    };
+    assert!(late_unordered
+        .calculate_price(&big(0), &big(10), &arguments(), Rounding::Up)
+        .is_err());
+}
+
+#[test]
+fn function_selector_test() {
+    let _ = DebugApi::dummy();
+
+    let linear = FunctionSelector::Linear(LinearFunction {
+        initial_price: big(1),
+        linear_coefficient: big(1),
+    });
+    assert_eq!(prices(&linear, 0, 3), (8, 7));
+
+    // (0 + 3)^3 / 3 + balance + initial cost
+    let custom_example = FunctionSelector::CustomExample(big(5));
+    assert_eq!(prices(&custom_example, 0, 3), (1014, 1014));
+
+    let none = FunctionSelector::<DebugApi>::default();
+    assert!(none
+        .calculate_price(&big(0), &big(3), &arguments(), Rounding::Up)
+        .is_err());
+
+    // curves already in storage keep their meaning
+    assert_eq!(
+        dharitri_codec::top_encode_to_vec_u8(&custom_example).unwrap(),
+        [&[1u8, 0, 0, 0, 1][..], &[5u8][..]].concat()
+    );
+    assert_eq!(dharitri_codec::top_encode_to_vec_u8(&none).unwrap(), [2u8]);
+}
+
+/// A curve defined outside the module: the price doubles every `doubling_supply` tokens.
+#[derive(NestedEncode, NestedDecode, TypeAbi)]
+struct StepCurve<M: ManagedTypeApi> {
+    initial_price: BigUint<M>,
+    doubling_supply: u64,
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for StepCurve<M> {
+    fn area_until(
+        &self,
+        supply: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> SCResult<BigUint<M>> {
+        let supply = supply.to_u64().unwrap();
+        let mut area = BigUint::zero();
+        let mut price = self.initial_price.clone();
+        let mut covered = 0;
+        while covered < supply {
+            let step = core::cmp::min(self.doubling_supply, supply - covered);
+            area += &price * step * CURVE_PRECISION;
+            price *= 2u32;
+            covered += step;
+        }
+        SCResult::Ok(area)
+    }
+}
+
+#[test]
+fn custom_curve_test() {
+    let _ = DebugApi::dummy();
+    let custom = StepCurve::<DebugApi> {
+        initial_price: big(3),
+        doubling_supply: 10,
+    };
+
+    // 10 * 3 + 10 * 6 + 5 * 12
+    assert_eq!(prices(&custom, 0, 25), (150, 150));
+    assert_eq!(prices(&custom, 15, 10), (90, 90));
+}
+
+#[test]
+fn buy_price_covers_sell_price_test() {
+    let _ = DebugApi::dummy();
+    let curves = [
+        FunctionSelector::Linear(LinearFunction {
+            initial_price: big(3),
+            linear_coefficient: big(7),
+        }),
+        FunctionSelector::Exponential(ExponentialFunction {
+            initial_price: big(11),
+            growth_rate: BigUint::from(CURVE_PRECISION / 1000),
+        }),
+        FunctionSelector::Power(PowerFunction {
+            initial_price: big(0),
+            coefficient: BigUint::from(CURVE_PRECISION / 3),
+            exponent: 3,
+        }),
+        FunctionSelector::Logarithmic(LogarithmicFunction {
+            initial_price: big(1),
+            coefficient: BigUint::from(CURVE_PRECISION / 7),
+        }),
+    ];
+
+    for curve in curves.iter() {
+        for (token_start, amount) in [(0, 1), (1, 1), (13, 29), (500, 3), (999, 1)] {
+            let (buy_price, sell_price) = prices(curve, token_start, amount);
+            assert!(buy_price >= sell_price);
+            assert!(buy_price - sell_price <= 1);
+        }
+    }
+}
@@ -23,6 +23,10 @@ pub trait AbiTester {
     #[payable("MOAX")]
     fn init(&self, _constructor_arg_1: i32, _constructor_arg_2: OnlyShowsUpInConstructor) {}
 
+    /// Called when the contract is upgraded, instead of the constructor.
+    #[upgrade]
+    fn upgrade(&self, _upgrade_arg: ManagedBuffer) {}
+
     /// Example endpoint docs.
     #[endpoint]
     #[output_name("single output")]
@@ -178,6 +182,15 @@ pub trait AbiTester {
     ) {
     }
 
+    #[endpoint]
+    #[payable("TOKEN-FOR-ABI", "OTHER-TOKEN-FOR-ABI")]
+    fn payable_token_list(
+        &self,
+        #[payment] _payment: BigUint,
+        #[payment_token] _token: TokenIdentifier,
+    ) {
+    }
+
     #[external_view]
     fn external_view(&self) {}
 }
@@ -7,6 +7,7 @@
 dharitri_wasm_node::wasm_endpoints! {
     abi_tester
     (
+        upgrade
         address_vs_h256
         echo_abi_test_type
         echo_enum
@@ -31,6 +32,7 @@ dharitri_wasm_node::wasm_endpoints! {
         payable_any_token
         payable_moax
         payable_some_token
+        payable_token_list
         sample_storage_mapper
         take_managed_type
         var_args
@@ -16,6 +16,7 @@ pub mod event_features;
 pub mod macro_features;
 pub mod managed_address_features;
 pub mod managed_buffer_features;
+pub mod managed_map_features;
 pub mod managed_vec_features;
 pub mod storage_direct_load;
 pub mod storage_direct_store;
@@ -47,6 +48,7 @@ pub trait BasicFeatures:
     + macro_features::Macros
     + managed_address_features::ManagedAddressFeatures
     + managed_buffer_features::ManagedBufferFeatures
+    + managed_map_features::ManagedMapFeatures
     + managed_vec_features::ManagedVecFeatures
     + storage_direct_load::StorageLoadFeatures
     + storage_direct_store::StorageStoreFeatures
@@ -23,6 +23,21 @@ pub trait BlockchainApiFeatures {
         self.blockchain().is_smart_contract(address)
     }
 
+    #[endpoint]
+    fn get_balance(&self, address: &ManagedAddress) -> BigUint {
+        self.blockchain().get_balance(address)
+    }
+
+    #[endpoint]
+    fn get_dct_balance(
+        &self,
+        address: &ManagedAddress,
+        token_id: &TokenIdentifier,
+        nonce: u64,
+    ) -> BigUint {
+        self.blockchain().get_dct_balance(address, token_id, nonce)
+    }
+
     #[endpoint]
     fn get_state_root_hash_legacy(&self) -> ManagedByteArray<Self::Api, 32> {
         self.blockchain().get_state_root_hash()
@@ -90,6 +90,40 @@ pub trait ManagedBufferFeatures {
         mb1 == mb2
     }
 
+    #[endpoint]
+    fn mbuffer_cmp(&self, mb1: ManagedBuffer, mb2: ManagedBuffer) -> i8 {
+        mb1.cmp(&mb2) as i8
+    }
+
+    #[endpoint]
+    fn mbuffer_find(&self, mb: ManagedBuffer, pattern: &[u8]) -> OptionalResult<usize> {
+        mb.find(pattern).into()
+    }
+
+    #[endpoint]
+    fn mbuffer_split(
+        &self,
+        mb: ManagedBuffer,
+        delimiter: &[u8],
+    ) -> ManagedMultiResultVec<ManagedBuffer> {
+        ManagedMultiResultVec::from(mb.split(delimiter))
+    }
+
+    #[endpoint]
+    fn mbuffer_to_hex(&self, mb: ManagedBuffer) -> ManagedBuffer {
+        mb.to_hex()
+    }
+
+    #[endpoint]
+    fn mbuffer_parse_hex(&self, mb: ManagedBuffer) -> OptionalResult<ManagedBuffer> {
+        mb.parse_hex().into()
+    }
+
+    #[endpoint]
+    fn mbuffer_parse_biguint_decimal(&self, mb: ManagedBuffer) -> OptionalResult<BigUint> {
+        mb.parse_as_biguint_decimal().into()
+    }
+
     #[endpoint]
     fn managed_address_zero(&self) -> ManagedAddress {
         ManagedAddress::zero()
@@ -0,0 +1,44 @@
+dharitri_wasm::imports!();
+
+pub type TokenAmountAsMultiArg<M> = MultiArg2<TokenIdentifier<M>, BigUint<M>>;
+
+#[dharitri_wasm::module]
+pub trait ManagedMapFeatures {
+    #[endpoint]
+    fn managed_map_sum_by_token(
+        &self,
+        #[var_args] payments: ManagedVarArgs<TokenAmountAsMultiArg<Self::Api>>,
+    ) -> ManagedMultiResultVec<MultiResult2<TokenIdentifier, BigUint>> {
+        let mut totals: ManagedMap<TokenIdentifier, BigUint> = ManagedMap::new();
+        for payment in payments.into_iter() {
+            let (token_id, amount) = payment.into_tuple();
+            totals
+                .entry(token_id)
+                .or_default()
+                .update(|total| *total += amount);
+        }
+
+        let mut result = ManagedMultiResultVec::new();
+        for (token_id, total) in totals.iter() {
+            result.push((token_id, total).into());
+        }
+        result
+    }
+
+    #[endpoint]
+    fn managed_set_distinct(
+        &self,
+        #[var_args] items: ManagedVarArgs<ManagedBuffer>,
+    ) -> ManagedMultiResultVec<ManagedBuffer> {
+        let mut set: ManagedSet<ManagedBuffer> = ManagedSet::new();
+        for item in items.into_iter() {
+            set.insert(item);
+        }
+
+        let mut result = ManagedMultiResultVec::new();
+        for item in set.iter() {
+            result.push(item);
+        }
+        result
+    }
+}
@@ -48,4 +48,63 @@ pub trait ManagedVecFeatures {
             Err("index out of bounds".into())
         }
     }
+
+    #[endpoint]
+    fn managed_vec_biguint_insert(
+        &self,
+        mv: ManagedVec<BigUint>,
+        index: usize,
+        item: BigUint,
+    ) -> ManagedVec<BigUint> {
+        let mut result = mv;
+        result.insert(index, item);
+        result
+    }
+
+    #[endpoint]
+    fn managed_vec_biguint_remove(
+        &self,
+        mv: ManagedVec<BigUint>,
+        index: usize,
+    ) -> ManagedVec<BigUint> {
+        let mut result = mv;
+        let _ = result.remove(index);
+        result
+    }
+
+    #[endpoint]
+    fn managed_vec_biguint_contains(&self, mv: &ManagedVec<BigUint>, item: &BigUint) -> bool {
+        mv.contains(item)
+    }
+
+    #[endpoint]
+    fn managed_vec_biguint_retain_greater_than(
+        &self,
+        mv: ManagedVec<BigUint>,
+        threshold: &BigUint,
+    ) -> ManagedVec<BigUint> {
+        let mut result = mv;
+        result.retain(|item| item > threshold);
+        result
+    }
+
+    #[endpoint]
+    fn managed_vec_biguint_sort_dedup(&self, mv: ManagedVec<BigUint>) -> ManagedVec<BigUint> {
+        let mut result = mv;
+        result.sort();
+        result.dedup();
+        result
+    }
+
+    #[endpoint]
+    fn managed_vec_biguint_binary_search(
+        &self,
+        mv: &ManagedVec<BigUint>,
+        item: &BigUint,
+    ) -> MultiResult2<bool, usize> {
+        let search_result = mv.binary_search(item);
+        let found = search_result.is_ok();
+        let index = search_result.unwrap_or_else(|insert_index| insert_index);
+        (found, index).into()
+    }
 }
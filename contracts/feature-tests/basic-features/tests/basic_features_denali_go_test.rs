@@ -220,6 +220,11 @@ fn events_legacy_go() {
     dharitri_wasm_debug::denali_go("denali/events_legacy.scen.json");
 }
 
+#[test]
+fn get_balance_go() {
+    dharitri_wasm_debug::denali_go("denali/get_balance.scen.json");
+}
+
 #[test]
 fn get_caller_go() {
     dharitri_wasm_debug::denali_go("denali/get_caller.scen.json");
@@ -230,6 +235,11 @@ fn get_cumulated_validator_rewards_go() {
     dharitri_wasm_debug::denali_go("denali/get_cumulated_validator_rewards.scen.json");
 }
 
+#[test]
+fn get_state_root_hash_go() {
+    dharitri_wasm_debug::denali_go("denali/get_state_root_hash.scen.json");
+}
+
 #[test]
 fn managed_buffer_concat_1_go() {
     dharitri_wasm_debug::denali_go("denali/managed_buffer_concat_1.scen.json");
@@ -265,6 +275,21 @@ fn managed_buffer_random_go() {
     dharitri_wasm_debug::denali_go("denali/managed_buffer_set_random.scen.json");
 }
 
+#[test]
+fn managed_buffer_text_go() {
+    dharitri_wasm_debug::denali_go("denali/managed_buffer_text.scen.json");
+}
+
+#[test]
+fn managed_map_and_set_go() {
+    dharitri_wasm_debug::denali_go("denali/managed_map_and_set.scen.json");
+}
+
+#[test]
+fn managed_vec_algorithms_go() {
+    dharitri_wasm_debug::denali_go("denali/managed_vec_algorithms.scen.json");
+}
+
 #[test]
 fn managed_vec_address_push_go() {
     dharitri_wasm_debug::denali_go("denali/managed_vec_address_push.scen.json");
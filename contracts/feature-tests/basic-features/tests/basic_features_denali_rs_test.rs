@@ -231,6 +231,11 @@ fn events_legacy_rs() {
     dharitri_wasm_debug::denali_rs("denali/events_legacy.scen.json", world());
 }
 
+#[test]
+fn get_balance_rs() {
+    dharitri_wasm_debug::denali_rs("denali/get_balance.scen.json", world());
+}
+
 #[test]
 fn get_caller_rs() {
     dharitri_wasm_debug::denali_rs("denali/get_caller.scen.json", world());
@@ -241,6 +246,11 @@ fn get_cumulated_validator_rewards_rs() {
     dharitri_wasm_debug::denali_rs("denali/get_cumulated_validator_rewards.scen.json", world());
 }
 
+#[test]
+fn get_state_root_hash_rs() {
+    dharitri_wasm_debug::denali_rs("denali/get_state_root_hash.scen.json", world());
+}
+
 #[test]
 fn managed_address_array_rs() {
     dharitri_wasm_debug::denali_rs("denali/managed_address_array.scen.json", world());
@@ -288,6 +298,21 @@ fn managed_buffer_random_rs() {
 }
 */
 
+#[test]
+fn managed_buffer_text_rs() {
+    dharitri_wasm_debug::denali_rs("denali/managed_buffer_text.scen.json", world());
+}
+
+#[test]
+fn managed_map_and_set_rs() {
+    dharitri_wasm_debug::denali_rs("denali/managed_map_and_set.scen.json", world());
+}
+
+#[test]
+fn managed_vec_algorithms_rs() {
+    dharitri_wasm_debug::denali_rs("denali/managed_vec_algorithms.scen.json", world());
+}
+
 #[test]
 fn managed_vec_address_push_rs() {
     dharitri_wasm_debug::denali_rs("denali/managed_vec_address_push.scen.json", world());
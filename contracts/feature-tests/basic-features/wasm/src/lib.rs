@@ -131,6 +131,7 @@ dharitri_wasm_node::wasm_endpoints! {
         echo_vec_u8
         finish_simple_enum_variant_1
         getListMapper
+        get_balance
         get_block_epoch
         get_block_nonce
         get_block_random_seed
@@ -138,6 +139,7 @@ dharitri_wasm_node::wasm_endpoints! {
         get_block_timestamp
         get_caller
         get_cumulated_validator_rewards
+        get_dct_balance
         get_gas_left
         get_nr_to_clear
         get_owner_address
@@ -193,10 +195,18 @@ dharitri_wasm_node::wasm_endpoints! {
         managed_address_eq
         managed_address_from
         managed_address_zero
+        managed_map_sum_by_token
+        managed_set_distinct
         managed_struct_eq
         managed_vec_address_push
+        managed_vec_biguint_binary_search
+        managed_vec_biguint_contains
         managed_vec_biguint_eq
+        managed_vec_biguint_insert
         managed_vec_biguint_push
+        managed_vec_biguint_remove
+        managed_vec_biguint_retain_greater_than
+        managed_vec_biguint_sort_dedup
         managed_vec_new
         managed_vec_set
         map_mapper_contains_key
@@ -221,17 +231,23 @@ dharitri_wasm_node::wasm_endpoints! {
         map_storage_mapper_insert_value
         map_storage_mapper_remove
         map_storage_mapper_view
+        mbuffer_cmp
         mbuffer_concat_1
         mbuffer_concat_2
         mbuffer_copy_slice
         mbuffer_eq
+        mbuffer_find
         mbuffer_from_boxed_bytes
         mbuffer_from_slice
         mbuffer_load_slice
         mbuffer_new
         mbuffer_overwrite
+        mbuffer_parse_biguint_decimal
+        mbuffer_parse_hex
         mbuffer_set_random
         mbuffer_set_slice
+        mbuffer_split
+        mbuffer_to_hex
         mul_assign_big_int
         mul_assign_big_int_ref
         mul_assign_big_uint
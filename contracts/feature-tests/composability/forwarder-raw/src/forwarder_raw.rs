@@ -393,7 +393,7 @@ pub trait ForwarderRaw {
             self.blockchain().get_gas_left(),
             &BigUint::zero(),
             &source_contract_address,
-            CodeMetadata::DEFAULT,
+            CodeMetadata::UPGRADEABLE,
             &arguments.to_arg_buffer(),
         )
     }
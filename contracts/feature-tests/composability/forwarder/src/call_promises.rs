@@ -0,0 +1,62 @@
+dharitri_wasm::imports!();
+
+const PROMISE_CALLBACK_GAS: u64 = 10_000_000;
+
+/// Registers several async calls during the same transaction, as promises.
+#[dharitri_wasm::module]
+pub trait CallPromisesModule {
+    #[proxy]
+    fn vault_proxy(&self) -> vault::Proxy<Self::Api>;
+
+    /// Splits the payment between `accept_funds` and `reject_funds`,
+    /// so that the first promise succeeds and the second one fails.
+    #[payable("MOAX")]
+    #[endpoint]
+    fn forward_promises_accept_and_reject_funds(
+        &self,
+        to: ManagedAddress,
+        #[payment] payment: BigUint,
+    ) {
+        let half_payment = payment / 2u32;
+
+        self.vault_proxy()
+            .contract(to.clone())
+            .accept_funds(TokenIdentifier::moax(), 0, half_payment.clone())
+            .async_call_promise()
+            .with_callback(self.callbacks().promise_success_callback(b"accept".into()))
+            .with_error_callback(self.callbacks().promise_error_callback(b"accept".into()))
+            .with_extra_gas_for_callback(PROMISE_CALLBACK_GAS)
+            .register_promise();
+
+        self.vault_proxy()
+            .contract(to)
+            .reject_funds(TokenIdentifier::moax(), half_payment)
+            .async_call_promise()
+            .with_callback(self.callbacks().promise_success_callback(b"reject".into()))
+            .with_error_callback(self.callbacks().promise_error_callback(b"reject".into()))
+            .with_extra_gas_for_callback(PROMISE_CALLBACK_GAS)
+            .register_promise();
+    }
+
+    #[callback]
+    fn promise_success_callback(&self, label: ManagedBuffer) {
+        self.promise_success_event(&label);
+    }
+
+    #[callback]
+    fn promise_error_callback(
+        &self,
+        label: ManagedBuffer,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        if let ManagedAsyncCallResult::Err(err) = result {
+            self.promise_error_event(&label, &err.err_msg);
+        }
+    }
+
+    #[event("promise_success")]
+    fn promise_success_event(&self, #[indexed] label: &ManagedBuffer);
+
+    #[event("promise_error")]
+    fn promise_error_event(&self, #[indexed] label: &ManagedBuffer, err_msg: &ManagedBuffer);
+}
@@ -166,6 +166,27 @@ pub trait ForwarderDctModule: storage::ForwarderStorageModule {
         self.send().dct_local_burn(&token_identifier, 0, &amount);
     }
 
+    /// Mints through the system SC, without a callback.
+    /// The minted tokens still end up in the balance of the contract.
+    #[endpoint]
+    fn mint_no_callback(&self, token_identifier: TokenIdentifier, amount: BigUint) {
+        self.send()
+            .dct_system_sc_proxy()
+            .mint(&token_identifier, &amount)
+            .async_call_promise()
+            .register_promise();
+    }
+
+    /// Burns through the system SC, without a callback.
+    #[endpoint]
+    fn burn_no_callback(&self, token_identifier: TokenIdentifier, amount: BigUint) {
+        self.send()
+            .dct_system_sc_proxy()
+            .burn(&token_identifier, &amount)
+            .async_call_promise()
+            .register_promise();
+    }
+
     #[endpoint]
     fn get_dct_local_roles(
         &self,
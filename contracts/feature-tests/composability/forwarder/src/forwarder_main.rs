@@ -2,6 +2,7 @@
 #![allow(clippy::type_complexity)]
 
 mod call_async;
+mod call_promises;
 pub mod call_sync;
 mod call_transf_exec;
 mod contract_change_owner;
@@ -20,6 +21,7 @@ dharitri_wasm::imports!();
 pub trait Forwarder:
     call_sync::ForwarderSyncCallModule
     + call_async::ForwarderAsyncCallModule
+    + call_promises::CallPromisesModule
     + call_transf_exec::ForwarderTransferExecuteModule
     + contract_change_owner::ChangeOwnerModule
     + contract_deploy::DeployContractModule
@@ -9,6 +9,7 @@ dharitri_wasm_node::wasm_endpoints! {
     (
         callBack
         accept_funds_func
+        burn_no_callback
         buy_nft
         callback_data
         callback_data_at_index
@@ -25,6 +26,7 @@ dharitri_wasm_node::wasm_endpoints! {
         forward_async_accept_funds_half_payment
         forward_async_accept_funds_with_fees
         forward_async_retrieve_funds
+        forward_promises_accept_and_reject_funds
         forward_sync_accept_funds
         forward_sync_accept_funds_multi_transfer
         forward_sync_accept_funds_then_read
@@ -45,6 +47,7 @@ dharitri_wasm_node::wasm_endpoints! {
         lastIssuedToken
         local_burn
         local_mint
+        mint_no_callback
         multi_transfer_via_async
         nft_add_quantity
         nft_burn
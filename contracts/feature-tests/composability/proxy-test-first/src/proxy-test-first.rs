@@ -68,7 +68,7 @@ pub trait ProxyTestFirst {
         let (address, results) = self
             .message_me_proxy()
             .init(payment, 123)
-            .deploy_contract(&code, CodeMetadata::DEFAULT);
+            .deploy_contract(&code, CodeMetadata::UPGRADEABLE);
         self.set_other_contract(&address);
 
         results
@@ -60,6 +60,11 @@ fn forw_raw_direct_moax_go() {
     dharitri_wasm_debug::denali_go("denali/forw_raw_direct_moax.scen.json");
 }
 
+#[test]
+fn forw_raw_direct_moax_non_payable_go() {
+    dharitri_wasm_debug::denali_go("denali/forw_raw_direct_moax_non_payable.scen.json");
+}
+
 #[test]
 fn forw_raw_direct_dct_go() {
     dharitri_wasm_debug::denali_go("denali/forw_raw_direct_dct.scen.json");
@@ -170,6 +175,11 @@ fn forwarder_call_async_retrieve_nft_go() {
     dharitri_wasm_debug::denali_go("denali/forwarder_call_async_retrieve_nft.scen.json");
 }
 
+#[test]
+fn forwarder_call_promises_go() {
+    dharitri_wasm_debug::denali_go("denali/forwarder_call_promises.scen.json");
+}
+
 #[test]
 fn forwarder_call_sync_accept_moax_go() {
     dharitri_wasm_debug::denali_go("denali/forwarder_call_sync_accept_moax.scen.json");
@@ -282,6 +292,11 @@ fn forwarder_contract_upgrade_go() {
     dharitri_wasm_debug::denali_go("denali/forwarder_contract_upgrade.scen.json");
 }
 
+#[test]
+fn forwarder_contract_upgrade_not_upgradeable_go() {
+    dharitri_wasm_debug::denali_go("denali/forwarder_contract_upgrade_not_upgradeable.scen.json");
+}
+
 #[test]
 fn forwarder_nft_create_go() {
     dharitri_wasm_debug::denali_go("denali/forwarder_nft_create.scen.json");
@@ -1,4 +1,4 @@
-use dharitri_wasm_debug::*;
+use dharitri_wasm_debug::{tx_mock::TxTraceFormat, world_mock::EndpointKind, *};
 
 fn world() -> BlockchainMock {
     let mut blockchain = BlockchainMock::new();
@@ -28,6 +28,12 @@ fn world() -> BlockchainMock {
     blockchain
 }
 
+fn world_sharded() -> BlockchainMock {
+    let mut blockchain = world();
+    blockchain.set_num_shards(2);
+    blockchain
+}
+
 #[test]
 fn forw_raw_async_accept_moax_rs() {
     dharitri_wasm_debug::denali_rs("denali/forw_raw_async_accept_moax.scen.json", world());
@@ -43,6 +49,11 @@ fn forw_raw_async_echo_rs() {
     dharitri_wasm_debug::denali_rs("denali/forw_raw_async_echo.scen.json", world());
 }
 
+#[test]
+fn forwarder_dct_system_sc_rs() {
+    dharitri_wasm_debug::denali_rs("denali/forwarder_dct_system_sc.scen.json", world());
+}
+
 #[test]
 fn forwarder_get_dct_local_roles_rs() {
     dharitri_wasm_debug::denali_rs("denali/forwarder_get_dct_local_roles.scen.json", world());
@@ -100,6 +111,11 @@ fn forw_raw_direct_moax_rs() {
     dharitri_wasm_debug::denali_rs("denali/forw_raw_direct_moax.scen.json", world());
 }
 
+#[test]
+fn forw_raw_direct_moax_non_payable_rs() {
+    dharitri_wasm_debug::denali_rs("denali/forw_raw_direct_moax_non_payable.scen.json", world());
+}
+
 #[test]
 fn forw_raw_direct_dct_rs() {
     dharitri_wasm_debug::denali_rs("denali/forw_raw_direct_dct.scen.json", world());
@@ -236,6 +252,53 @@ fn forwarder_call_sync_accept_moax_rs() {
     dharitri_wasm_debug::denali_rs("denali/forwarder_call_sync_accept_moax.scen.json", world());
 }
 
+#[test]
+fn forwarder_call_cross_shard_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali/forwarder_call_cross_shard.scen.json",
+        world_sharded(),
+    );
+}
+
+#[test]
+fn forwarder_call_promises_rs() {
+    dharitri_wasm_debug::denali_rs("denali/forwarder_call_promises.scen.json", world());
+}
+
+#[test]
+fn forwarder_callback_coverage_rs() {
+    let forwarder_coverage = |scenario: &str| {
+        let mut coverage = dharitri_wasm_debug::denali_rs_endpoint_coverage(scenario, world());
+        coverage
+            .contracts
+            .remove("file:forwarder/output/forwarder.wasm")
+            .unwrap()
+    };
+
+    let async_coverage = forwarder_coverage("denali/forwarder_dct_system_sc.scen.json");
+    let callback_stats = &async_coverage.endpoints["dct_issue_callback"];
+    assert_eq!(callback_stats.kind, EndpointKind::Callback);
+    assert_eq!(callback_stats.calls, 2);
+    assert!(!async_coverage.endpoints.contains_key("callBack"));
+    let uncalled_view_stats = &async_coverage.endpoints["getFungibleDctBalance"];
+    assert_eq!(uncalled_view_stats.kind, EndpointKind::View);
+    assert_eq!(uncalled_view_stats.calls, 0);
+
+    let promises_coverage = forwarder_coverage("denali/forwarder_call_promises.scen.json");
+    assert_eq!(
+        promises_coverage.endpoints["promise_success_callback"].kind,
+        EndpointKind::Callback
+    );
+    assert_eq!(
+        promises_coverage.endpoints["promise_success_callback"].calls,
+        1
+    );
+    assert_eq!(
+        promises_coverage.endpoints["promise_error_callback"].calls,
+        1
+    );
+}
+
 #[test]
 fn forwarder_call_sync_accept_dct_rs() {
     dharitri_wasm_debug::denali_rs("denali/forwarder_call_sync_accept_dct.scen.json", world());
@@ -249,6 +312,36 @@ fn forwarder_call_sync_accept_multi_transfer_rs() {
     );
 }
 
+#[test]
+fn forwarder_call_sync_accept_moax_traced_rs() {
+    let mut world = world();
+    world.enable_trace(TxTraceFormat::CallTree);
+    let traces = dharitri_wasm_debug::denali_rs_traces(
+        "denali/forwarder_call_sync_accept_moax.scen.json",
+        world,
+    );
+
+    let (_, trace) = traces.iter().find(|(step_id, _)| step_id == "1").unwrap();
+    assert_eq!(trace.frames.len(), 1);
+    let frame = &trace.frames[0];
+    assert_eq!(frame.endpoint, b"forward_sync_accept_funds".to_vec());
+    assert_eq!(frame.result_status, 0);
+    assert_eq!(frame.children.len(), 1);
+
+    let nested_frame = &frame.children[0];
+    assert_eq!(nested_frame.caller, frame.callee);
+    assert_eq!(nested_frame.endpoint, b"accept_funds_echo_payment".to_vec());
+    assert_eq!(nested_frame.moax_value, 1000u32.into());
+    assert_eq!(nested_frame.result_status, 0);
+
+    assert!(trace
+        .to_call_tree()
+        .contains("accept_funds_echo_payment <- "));
+    assert!(trace
+        .to_json()
+        .contains("\"endpoint\": \"accept_funds_echo_payment\""));
+}
+
 #[test]
 fn forwarder_call_sync_accept_nft_rs() {
     dharitri_wasm_debug::denali_rs("denali/forwarder_call_sync_accept_nft.scen.json", world());
@@ -386,6 +479,14 @@ fn forwarder_contract_upgrade_rs() {
     dharitri_wasm_debug::denali_rs("denali/forwarder_contract_upgrade.scen.json", world());
 }
 
+#[test]
+fn forwarder_contract_upgrade_not_upgradeable_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali/forwarder_contract_upgrade_not_upgradeable.scen.json",
+        world(),
+    );
+}
+
 #[test]
 fn forwarder_nft_create_rs() {
     dharitri_wasm_debug::denali_rs("denali/forwarder_nft_create.scen.json", world());
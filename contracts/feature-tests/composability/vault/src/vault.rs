@@ -15,6 +15,14 @@ pub trait Vault {
         opt_arg_to_echo
     }
 
+    #[upgrade]
+    fn upgrade(
+        &self,
+        #[var_args] opt_arg_to_echo: OptionalArg<ManagedBuffer>,
+    ) -> MultiResult2<&'static str, OptionalResult<ManagedBuffer>> {
+        ("upgraded", opt_arg_to_echo).into()
+    }
+
     #[payable("*")]
     #[endpoint]
     fn just_accept_funds(&self) {
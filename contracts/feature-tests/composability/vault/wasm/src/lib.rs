@@ -7,6 +7,7 @@
 dharitri_wasm_node::wasm_endpoints! {
     vault
     (
+        upgrade
         accept_funds
         accept_funds_echo_payment
         accept_funds_multi_transfer
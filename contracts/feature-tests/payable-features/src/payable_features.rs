@@ -139,4 +139,42 @@ pub trait PayableFeatures {
         let token = self.call_value().token();
         (payment, token).into()
     }
+
+    #[endpoint]
+    #[payable("PAYABLE-FEATURES-TOKEN", "OTHER-TOKEN")]
+    fn payable_token_list(
+        &self,
+        #[payment_multi] payments: ManagedVec<DctTokenPayment<Self::Api>>,
+    ) -> ManagedVec<DctTokenPayment<Self::Api>> {
+        payments
+    }
+
+    #[endpoint]
+    #[payable("MOAX", "PAYABLE-FEATURES-TOKEN")]
+    fn payable_token_list_with_moax(
+        &self,
+        #[payment] payment: BigUint,
+        #[payment_token] token: TokenIdentifier,
+    ) -> MultiResult2<BigUint, TokenIdentifier> {
+        (payment, token).into()
+    }
+
+    #[endpoint]
+    #[payable(token_getter = "accepted_tokens")]
+    fn payable_token_getter(
+        &self,
+        #[payment] payment: BigUint,
+        #[payment_token] token: TokenIdentifier,
+    ) -> MultiResult2<BigUint, TokenIdentifier> {
+        (payment, token).into()
+    }
+
+    #[endpoint]
+    fn accept_token(&self, token: TokenIdentifier) {
+        self.accepted_tokens().insert(token);
+    }
+
+    #[view(getAcceptedTokens)]
+    #[storage_mapper("acceptedTokens")]
+    fn accepted_tokens(&self) -> SetMapper<TokenIdentifier>;
 }
@@ -62,3 +62,13 @@ fn payable_token_3_go() {
 fn payable_token_4_go() {
     dharitri_wasm_debug::denali_go("denali/payable_token_4.scen.json");
 }
+
+#[test]
+fn payable_token_getter_go() {
+    dharitri_wasm_debug::denali_go("denali/payable_token_getter.scen.json");
+}
+
+#[test]
+fn payable_token_list_go() {
+    dharitri_wasm_debug::denali_go("denali/payable_token_list.scen.json");
+}
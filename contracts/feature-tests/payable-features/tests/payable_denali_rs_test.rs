@@ -79,3 +79,13 @@ fn payable_token_3_rs() {
 fn payable_token_4_rs() {
     dharitri_wasm_debug::denali_rs("denali/payable_token_4.scen.json", world());
 }
+
+#[test]
+fn payable_token_getter_rs() {
+    dharitri_wasm_debug::denali_rs("denali/payable_token_getter.scen.json", world());
+}
+
+#[test]
+fn payable_token_list_rs() {
+    dharitri_wasm_debug::denali_rs("denali/payable_token_list.scen.json", world());
+}
@@ -7,7 +7,9 @@
 dharitri_wasm_node::wasm_endpoints! {
     payable_features
     (
+        accept_token
         echo_call_value
+        getAcceptedTokens
         payable_any_1
         payable_any_2
         payable_any_3
@@ -20,6 +22,9 @@ dharitri_wasm_node::wasm_endpoints! {
         payable_token_2
         payable_token_3
         payable_token_4
+        payable_token_getter
+        payable_token_list
+        payable_token_list_with_moax
         payment_multiple
     )
 }
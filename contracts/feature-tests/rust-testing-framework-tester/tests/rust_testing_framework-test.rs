@@ -2,6 +2,7 @@ use adder::*;
 use forwarder::call_sync::*;
 use num_traits::ToPrimitive;
 
+use dharitri_wasm::contract_base::ContractAbiProvider;
 use dharitri_wasm::types::{
     BigInt, DctLocalRole, DctTokenPayment, DctTokenType, ManagedBuffer, ManagedVec,
 };
@@ -10,11 +11,12 @@ use dharitri_wasm_debug::{
     rust_biguint, testing_framework::*, tx_execution::execute_async_call_and_callback,
     tx_mock::TxInputDCT, DebugApi,
 };
-use rust_testing_framework_tester::{dummy_module::DummyModule, *};
+use rust_testing_framework_tester::{dummy_module::DummyModule, ProxyTrait as _, *};
 
 const TEST_OUTPUT_PATH: &'static str = "test.scen.json";
 const TEST_MULTIPLE_SC_OUTPUT_PATH: &'static str = "test_multiple_sc.scen.json";
 const TEST_DCT_OUTPUT_PATH: &'static str = "test_dct_generation.scen.json";
+const TEST_FUZZ_OUTPUT_PATH: &'static str = "test_fuzz_adder.scen.json";
 
 const SC_WASM_PATH: &'static str = "output/rust-testing-framework-tester.wasm";
 const ADDER_WASM_PATH: &'static str = "../../examples/adder/output/adder.wasm";
@@ -359,6 +361,181 @@ fn test_dct_payment_reverted() {
     wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(2_000));
 }
 
+#[test]
+fn test_dct_payment_frozen() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let caller_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let token_id = &b"COOL-123456"[..];
+
+    wrapper.set_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
+    wrapper.set_dct_frozen(&caller_addr, token_id, true);
+
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let _ = sc.receive_dct();
+                StateChange::Commit
+            },
+        )
+        .assert_error(10, "account is frozen for this dct token");
+
+    wrapper.check_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
+
+    wrapper.set_dct_frozen(&caller_addr, token_id, false);
+    wrapper.set_dct_frozen(sc_wrapper.address_ref(), token_id, true);
+
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let _ = sc.receive_dct();
+                StateChange::Commit
+            },
+        )
+        .assert_error(10, "account is frozen for this dct token");
+
+    wrapper.set_dct_frozen(sc_wrapper.address_ref(), token_id, false);
+
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let _ = sc.receive_dct();
+                StateChange::Commit
+            },
+        )
+        .assert_ok();
+
+    wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(1_000));
+}
+
+#[test]
+fn test_dct_payment_paused() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let caller_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let token_id = &b"COOL-123456"[..];
+
+    wrapper.set_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
+    wrapper.set_dct_paused(token_id, true);
+
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let _ = sc.receive_dct();
+                StateChange::Commit
+            },
+        )
+        .assert_error(10, "dct token is paused");
+
+    wrapper.check_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
+
+    wrapper.set_dct_paused(token_id, false);
+
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let _ = sc.receive_dct();
+                StateChange::Commit
+            },
+        )
+        .assert_ok();
+
+    wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(1_000));
+}
+
+#[test]
+fn test_dct_payment_limited_transfer() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let caller_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let token_id = &b"COOL-123456"[..];
+
+    wrapper.set_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
+    wrapper.set_dct_limited_transfer(token_id, true);
+
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let _ = sc.receive_dct();
+                StateChange::Commit
+            },
+        )
+        .assert_error(10, "action is not allowed");
+
+    wrapper.set_dct_local_roles(
+        sc_wrapper.address_ref(),
+        token_id,
+        &[DctLocalRole::Transfer],
+    );
+
+    wrapper
+        .execute_dct_transfer(
+            &caller_addr,
+            &sc_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let _ = sc.receive_dct();
+                StateChange::Commit
+            },
+        )
+        .assert_ok();
+
+    wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(1_000));
+}
+
 #[test]
 fn test_nft_balance() {
     let mut wrapper = BlockchainStateWrapper::new();
@@ -1332,3 +1509,323 @@ fn test_back_and_forth_transfers() {
         &third_token_amount,
     );
 }
+
+#[test]
+fn test_proxy_call() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let caller_addr = wrapper.create_user_account(&rust_biguint!(0));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    let tx_result = wrapper.execute_proxy_call(
+        &caller_addr,
+        &sc_wrapper,
+        |proxy: rust_testing_framework_tester::Proxy<DebugApi>| {
+            proxy.sum(managed_biguint!(1000), managed_biguint!(2000))
+        },
+        |result| {
+            assert_eq!(result, managed_biguint!(3000));
+        },
+    );
+    tx_result.assert_ok();
+    assert_eq!(tx_result.result_values, vec![vec![0x0b, 0xb8]]);
+}
+
+#[test]
+fn test_proxy_call_payment() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let caller_addr = wrapper.create_user_account(&rust_biguint!(1_000));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    wrapper
+        .execute_proxy_call(
+            &caller_addr,
+            &sc_wrapper,
+            |proxy: rust_testing_framework_tester::Proxy<DebugApi>| {
+                proxy
+                    .receive_moax()
+                    .with_moax_transfer(managed_biguint!(400))
+            },
+            |result| {
+                assert_eq!(result, managed_biguint!(400));
+            },
+        )
+        .assert_ok();
+
+    wrapper.check_moax_balance(&caller_addr, &rust_biguint!(600));
+    wrapper.check_moax_balance(sc_wrapper.address_ref(), &rust_biguint!(400));
+
+    // the payable check of the real endpoint is performed
+    wrapper
+        .execute_proxy_call(
+            &caller_addr,
+            &sc_wrapper,
+            |proxy: rust_testing_framework_tester::Proxy<DebugApi>| {
+                proxy
+                    .sum(managed_biguint!(1), managed_biguint!(2))
+                    .with_moax_transfer(managed_biguint!(100))
+            },
+            |_| panic!("the call should have failed"),
+        )
+        .assert_error(10, "function does not accept MOAX payment");
+
+    wrapper.check_moax_balance(&caller_addr, &rust_biguint!(600));
+}
+
+#[test]
+fn test_proxy_call_dct_payment() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+    let caller_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let token_id = &b"COOL-123456"[..];
+    wrapper.set_dct_balance(&caller_addr, token_id, &rust_biguint!(1_000));
+
+    wrapper
+        .execute_proxy_call(
+            &caller_addr,
+            &sc_wrapper,
+            |proxy: rust_testing_framework_tester::Proxy<DebugApi>| {
+                proxy.receive_dct().add_token_transfer(
+                    managed_token_id!(token_id),
+                    0,
+                    managed_biguint!(300),
+                )
+            },
+            |(actual_token_id, actual_payment)| {
+                assert_eq!(actual_token_id, managed_token_id!(token_id));
+                assert_eq!(actual_payment, managed_biguint!(300));
+            },
+        )
+        .assert_ok();
+
+    wrapper.check_dct_balance(&caller_addr, token_id, &rust_biguint!(700));
+    wrapper.check_dct_balance(sc_wrapper.address_ref(), token_id, &rust_biguint!(300));
+}
+
+#[test]
+fn test_trace() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let other_sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    wrapper.enable_trace();
+    wrapper
+        .execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+            sc.execute_on_dest_add_value(
+                managed_address!(&other_sc_wrapper.address_ref().clone()),
+                managed_biguint!(5),
+            );
+            StateChange::Commit
+        })
+        .assert_ok();
+
+    let trace = wrapper.take_trace();
+    assert_eq!(trace.frames.len(), 1);
+    let frame = &trace.frames[0];
+    assert_eq!(&frame.caller, &user_addr);
+    assert_eq!(frame.callee, sc_wrapper.address_ref().clone());
+    assert_eq!(frame.children.len(), 1);
+
+    let nested_frame = &frame.children[0];
+    assert_eq!(nested_frame.caller, sc_wrapper.address_ref().clone());
+    assert_eq!(nested_frame.callee, other_sc_wrapper.address_ref().clone());
+    assert_eq!(nested_frame.endpoint, b"addValue".to_vec());
+    assert_eq!(nested_frame.args, vec![vec![5u8]]);
+    assert_eq!(nested_frame.result_status, 0);
+    assert_eq!(nested_frame.storage_writes.len(), 2);
+    assert_eq!(nested_frame.storage_writes[0].key, b"totalValue".to_vec());
+    assert_eq!(nested_frame.storage_writes[0].value, vec![5u8]);
+
+    assert!(trace.to_call_tree().contains("addValue"));
+    assert!(trace.to_json().contains("\"endpoint\": \"addValue\""));
+
+    // only the calls since the last take are returned
+    wrapper
+        .execute_query(&other_sc_wrapper, |sc| {
+            let _ = sc.get_total_value();
+        })
+        .assert_ok();
+    assert_eq!(wrapper.take_trace().frames.len(), 1);
+
+    wrapper.disable_trace();
+    wrapper
+        .execute_query(&other_sc_wrapper, |sc| {
+            let _ = sc.get_total_value();
+        })
+        .assert_ok();
+    assert!(wrapper.take_trace().is_empty());
+}
+
+#[test]
+fn test_storage_diff() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let other_sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    let tx_result = wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+        sc.execute_on_dest_add_value(
+            managed_address!(&other_sc_wrapper.address_ref().clone()),
+            managed_biguint!(5),
+        );
+        StateChange::Commit
+    });
+    tx_result.assert_ok();
+
+    let storage_diff = &tx_result.storage_diff;
+    assert!(storage_diff
+        .written_keys(sc_wrapper.address_ref())
+        .is_empty());
+    assert_eq!(
+        storage_diff.written_keys(other_sc_wrapper.address_ref())[0],
+        &b"totalValue"[..]
+    );
+    assert_eq!(storage_diff.writes[0].old_value, Vec::<u8>::new());
+    assert_eq!(storage_diff.writes[0].new_value, vec![5u8]);
+    assert!(storage_diff
+        .read_keys(other_sc_wrapper.address_ref())
+        .contains(&&b"totalValue"[..]));
+    storage_diff.assert_no_unexpected_writes(
+        other_sc_wrapper.address_ref(),
+        &[b"totalValue", b"valuePerCaller"],
+    );
+    assert!(storage_diff
+        .to_string()
+        .contains("str:totalValue: 0x -> 0x05"));
+
+    // reverted changes are not part of the diff, only the reads are kept
+    let tx_result = wrapper.execute_tx(&user_addr, &other_sc_wrapper, &rust_zero, |sc| {
+        sc.add(managed_biguint!(1));
+        StateChange::Revert
+    });
+    tx_result.assert_ok();
+    assert!(tx_result.storage_diff.writes.is_empty());
+    assert_eq!(
+        tx_result
+            .storage_diff
+            .read_keys(other_sc_wrapper.address_ref())[0],
+        &b"totalValue"[..]
+    );
+}
+
+type AdderObjWrapper =
+    ContractObjWrapper<adder::ContractObj<DebugApi>, fn() -> adder::ContractObj<DebugApi>>;
+
+fn adder_fuzz_world() -> FuzzWorld<AdderObjWrapper> {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let callers = vec![
+        wrapper.create_user_account(&rust_zero),
+        wrapper.create_user_account(&rust_zero),
+    ];
+    let adder_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        adder::contract_obj::<DebugApi> as fn() -> adder::ContractObj<DebugApi>,
+        ADDER_WASM_PATH,
+    );
+
+    FuzzWorld {
+        wrapper,
+        contract_address: adder_wrapper.address_ref().clone(),
+        callers,
+        data: adder_wrapper,
+    }
+}
+
+fn adder_sum(world: &mut FuzzWorld<AdderObjWrapper>) -> i64 {
+    let mut sum = 0;
+    world
+        .wrapper
+        .execute_query(&world.data, |sc| {
+            sum = sc.sum().get().to_i64().unwrap();
+        })
+        .assert_ok();
+    sum
+}
+
+#[test]
+fn fuzz_adder_test() {
+    let _ = DebugApi::dummy();
+
+    let mut fuzzer = ContractFuzzer::new(
+        <adder::AbiProvider as ContractAbiProvider>::abi(),
+        adder_fuzz_world,
+    );
+    fuzzer.set_num_sequences(10);
+    fuzzer.set_sequence_len(10);
+    fuzzer.add_invariant("sum stays in range", |world| {
+        let sum = adder_sum(world);
+        if sum.abs() < 10_000_000 {
+            Ok(())
+        } else {
+            Err("sum out of range".to_string())
+        }
+    });
+
+    assert!(fuzzer.run().is_none());
+}
+
+#[test]
+fn fuzz_adder_shrink_test() {
+    let _ = DebugApi::dummy();
+
+    let mut fuzzer = ContractFuzzer::new(
+        <adder::AbiProvider as ContractAbiProvider>::abi(),
+        adder_fuzz_world,
+    );
+    fuzzer.add_invariant("sum below one million", |world| {
+        let sum = adder_sum(world);
+        if sum < 1_000_000 {
+            Ok(())
+        } else {
+            Err(format!("sum is {}", sum))
+        }
+    });
+
+    let failure = fuzzer.run().unwrap();
+    assert_eq!(failure.invariant, "sum below one million");
+    assert!(failure.steps.len() <= 2);
+    assert!(failure.steps.iter().all(|step| step.endpoint == "add"));
+    assert!(fuzzer.replay(&failure.steps).is_some());
+
+    fuzzer.export_denali(&failure.steps, TEST_FUZZ_OUTPUT_PATH);
+}
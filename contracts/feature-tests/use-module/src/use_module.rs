@@ -17,6 +17,9 @@ dharitri_wasm::imports!();
 /// - DctModule
 /// - GovernanceModule
 /// - PauseModule
+/// - PermissionsModule
+/// - StakingModule
+/// - VestingModule
 #[dharitri_wasm::contract]
 pub trait UseModule:
     internal_mod_a::InternalModuleA
@@ -31,9 +34,12 @@ pub trait UseModule:
     + dharitri_wasm_modules::governance::GovernanceModule
     + dharitri_wasm_modules::governance::governance_configurable::GovernanceConfigurablePropertiesModule
     + dharitri_wasm_module_pause::PauseModule
+    + dharitri_wasm_modules::permissions::PermissionsModule
+    + dharitri_wasm_modules::staking::StakingModule
+    + dharitri_wasm_modules::vesting::VestingModule
 {
     /// Validates that the "featureName" feature is on.
-    /// Uses the `feature_guard!` macro.
+    /// Uses `check_feature_on`, from the `FeaturesModule`.
     #[endpoint(checkFeatureGuard)]
     fn check_feature_guard(&self) {
         self.check_feature_on(b"featureName", true);
@@ -43,4 +49,22 @@ pub trait UseModule:
     fn check_pause(&self) -> SCResult<bool> {
         Ok(self.is_paused())
     }
+
+    #[when_not_paused]
+    #[endpoint(checkWhenNotPaused)]
+    fn check_when_not_paused(&self) {}
+
+    /// Validates that the "pausableFeature" feature is not paused.
+    #[endpoint(checkPausableFeature)]
+    fn check_pausable_feature(&self) {
+        self.require_feature_not_paused(b"pausableFeature");
+    }
+
+    #[only_role("ADMIN")]
+    #[endpoint(checkOnlyAdmin)]
+    fn check_only_admin(&self) {}
+
+    #[only_role("ADMIN", "MINTER")]
+    #[endpoint(checkOnlyAdminOrMinter)]
+    fn check_only_admin_or_minter(&self) {}
 }
@@ -28,11 +28,28 @@ fn use_module_pause_go() {
     dharitri_wasm_debug::denali_go("denali/use_module_pause.scen.json");
 }
 
+#[test]
+fn use_module_pause_granular_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_pause_granular.scen.json");
+}
+
+#[test]
+fn use_module_permissions_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_permissions.scen.json");
+}
+
+#[test]
+fn use_module_vesting_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_vesting.scen.json");
+}
+
 // Governance module tests
 
 #[test]
 fn cancel_defeated_proposal_go() {
-    dharitri_wasm_debug::denali_go("denali/use_module_governance/cancel_defeated_proposal.scen.json");
+    dharitri_wasm_debug::denali_go(
+        "denali/use_module_governance/cancel_defeated_proposal.scen.json",
+    );
 }
 
 #[test]
@@ -40,6 +57,16 @@ fn change_configuration_go() {
     dharitri_wasm_debug::denali_go("denali/use_module_governance/change_configuration.scen.json");
 }
 
+#[test]
+fn delegate_and_abstain_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_governance/delegate_and_abstain.scen.json");
+}
+
+#[test]
+fn early_execution_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_governance/early_execution.scen.json");
+}
+
 #[test]
 fn init_go() {
     dharitri_wasm_debug::denali_go("denali/use_module_governance/init.scen.json");
@@ -51,8 +78,16 @@ fn invalid_proposals_go() {
 }
 
 #[test]
-fn withdraw_governance_tokens_go() {
-    dharitri_wasm_debug::denali_go(
-        "denali/use_module_governance/withdraw_governance_tokens.scen.json",
-    );
+fn legacy_withdraw_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_governance/legacy_withdraw.scen.json");
+}
+
+#[test]
+fn quadratic_voting_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_governance/quadratic_voting.scen.json");
+}
+
+#[test]
+fn withdraw_voting_power_go() {
+    dharitri_wasm_debug::denali_go("denali/use_module_governance/withdraw_voting_power.scen.json");
 }
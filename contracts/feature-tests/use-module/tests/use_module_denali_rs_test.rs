@@ -64,6 +64,21 @@ fn use_module_pause_rs() {
     dharitri_wasm_debug::denali_rs("denali/use_module_pause.scen.json", world());
 }
 
+#[test]
+fn use_module_pause_granular_rs() {
+    dharitri_wasm_debug::denali_rs("denali/use_module_pause_granular.scen.json", world());
+}
+
+#[test]
+fn use_module_permissions_rs() {
+    dharitri_wasm_debug::denali_rs("denali/use_module_permissions.scen.json", world());
+}
+
+#[test]
+fn use_module_vesting_rs() {
+    dharitri_wasm_debug::denali_rs("denali/use_module_vesting.scen.json", world());
+}
+
 // Governance module tests
 
 #[test]
@@ -82,6 +97,22 @@ fn change_configuration_rs() {
     );
 }
 
+#[test]
+fn delegate_and_abstain_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali/use_module_governance/delegate_and_abstain.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn early_execution_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali/use_module_governance/early_execution.scen.json",
+        world(),
+    );
+}
+
 #[test]
 fn init_rs() {
     dharitri_wasm_debug::denali_rs("denali/use_module_governance/init.scen.json", world());
@@ -96,9 +127,25 @@ fn invalid_proposals_rs() {
 }
 
 #[test]
-fn withdraw_governance_tokens_rs() {
+fn legacy_withdraw_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali/use_module_governance/legacy_withdraw.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn quadratic_voting_rs() {
+    dharitri_wasm_debug::denali_rs(
+        "denali/use_module_governance/quadratic_voting.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn withdraw_voting_power_rs() {
     dharitri_wasm_debug::denali_rs(
-        "denali/use_module_governance/withdraw_governance_tokens.scen.json",
+        "denali/use_module_governance/withdraw_voting_power.scen.json",
         world(),
     );
 }
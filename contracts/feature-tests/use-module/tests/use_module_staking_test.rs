@@ -0,0 +1,374 @@
+use dharitri_wasm::types::Address;
+use dharitri_wasm_debug::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint, testing_framework::*,
+    DebugApi,
+};
+use dharitri_wasm_modules::staking::{ProxyTrait as _, StakingModule};
+
+const STAKING_TOKEN_ID: &[u8] = b"STAKE-123456";
+const REWARD_TOKEN_ID: &[u8] = b"REWARD-123456";
+const REWARD_PER_BLOCK: u64 = 100;
+const UNBONDING_PERIOD: u64 = 10;
+const WASM_PATH: &str = "output/use-module.wasm";
+
+struct StakingSetup<UseModuleObjBuilder>
+where
+    UseModuleObjBuilder: 'static + Copy + Fn() -> use_module::ContractObj<DebugApi>,
+{
+    pub blockchain_wrapper: BlockchainStateWrapper,
+    pub owner_address: Address,
+    pub first_user_address: Address,
+    pub second_user_address: Address,
+    pub sc_wrapper: ContractObjWrapper<use_module::ContractObj<DebugApi>, UseModuleObjBuilder>,
+}
+
+fn setup_staking<UseModuleObjBuilder>(
+    sc_builder: UseModuleObjBuilder,
+    reward_token_id: &[u8],
+    reward_reserve: u64,
+) -> StakingSetup<UseModuleObjBuilder>
+where
+    UseModuleObjBuilder: 'static + Copy + Fn() -> use_module::ContractObj<DebugApi>,
+{
+    let rust_zero = rust_biguint!(0u64);
+    let mut blockchain_wrapper = BlockchainStateWrapper::new();
+    let owner_address = blockchain_wrapper.create_user_account(&rust_zero);
+    let first_user_address = blockchain_wrapper.create_user_account(&rust_zero);
+    let second_user_address = blockchain_wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = blockchain_wrapper.create_sc_account(
+        &rust_zero,
+        Some(&owner_address),
+        sc_builder,
+        WASM_PATH,
+    );
+
+    blockchain_wrapper.set_dct_balance(&owner_address, reward_token_id, &rust_biguint!(100_000));
+    blockchain_wrapper.set_dct_balance(
+        &first_user_address,
+        STAKING_TOKEN_ID,
+        &rust_biguint!(1_000),
+    );
+    blockchain_wrapper.set_dct_balance(
+        &second_user_address,
+        STAKING_TOKEN_ID,
+        &rust_biguint!(1_000),
+    );
+
+    blockchain_wrapper
+        .execute_tx(&owner_address, &sc_wrapper, &rust_zero, |sc| {
+            sc.init_staking_module(
+                managed_token_id!(STAKING_TOKEN_ID),
+                managed_token_id!(reward_token_id),
+                managed_biguint!(REWARD_PER_BLOCK),
+                UNBONDING_PERIOD,
+            );
+            StateChange::Commit
+        })
+        .assert_ok();
+
+    blockchain_wrapper
+        .execute_dct_transfer(
+            &owner_address,
+            &sc_wrapper,
+            reward_token_id,
+            0,
+            &rust_biguint!(reward_reserve),
+            |sc| {
+                sc.deposit_rewards(
+                    managed_token_id!(reward_token_id),
+                    managed_biguint!(reward_reserve),
+                );
+                StateChange::Commit
+            },
+        )
+        .assert_ok();
+
+    StakingSetup {
+        blockchain_wrapper,
+        owner_address,
+        first_user_address,
+        second_user_address,
+        sc_wrapper,
+    }
+}
+
+impl<UseModuleObjBuilder> StakingSetup<UseModuleObjBuilder>
+where
+    UseModuleObjBuilder: 'static + Copy + Fn() -> use_module::ContractObj<DebugApi>,
+{
+    fn stake(&mut self, address: &Address, amount: u64) {
+        self.blockchain_wrapper
+            .execute_dct_transfer(
+                address,
+                &self.sc_wrapper,
+                STAKING_TOKEN_ID,
+                0,
+                &rust_biguint!(amount),
+                |sc| {
+                    sc.stake(
+                        managed_token_id!(STAKING_TOKEN_ID),
+                        managed_biguint!(amount),
+                    );
+                    StateChange::Commit
+                },
+            )
+            .assert_ok();
+    }
+
+    fn check_pending_rewards(&mut self, address: &Address, expected_rewards: u64) {
+        self.blockchain_wrapper
+            .execute_query(&self.sc_wrapper, |sc| {
+                let pending_rewards = sc.get_pending_rewards(managed_address!(address));
+                assert_eq!(pending_rewards, managed_biguint!(expected_rewards));
+            })
+            .assert_ok();
+    }
+
+    fn check_staked_amount(&mut self, address: &Address, expected_amount: u64) {
+        self.blockchain_wrapper
+            .execute_query(&self.sc_wrapper, |sc| {
+                let staked_amount = sc.get_staked_amount(managed_address!(address));
+                assert_eq!(staked_amount, managed_biguint!(expected_amount));
+            })
+            .assert_ok();
+    }
+}
+
+#[test]
+fn stake_and_claim_rewards_test() {
+    let mut setup = setup_staking(use_module::contract_obj, REWARD_TOKEN_ID, 10_000);
+    let first_user = setup.first_user_address.clone();
+    let second_user = setup.second_user_address.clone();
+
+    setup.stake(&first_user, 100);
+    setup.blockchain_wrapper.set_block_nonce(10);
+    setup.check_pending_rewards(&first_user, 1_000);
+
+    setup.stake(&second_user, 300);
+    setup.blockchain_wrapper.set_block_nonce(20);
+
+    // 10 blocks alone, then 10 blocks with a quarter of the stake
+    setup.check_pending_rewards(&first_user, 1_250);
+    setup.check_pending_rewards(&second_user, 750);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(&first_user, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            let rewards = sc.claim_rewards();
+            assert_eq!(rewards, managed_biguint!(1_250));
+            StateChange::Commit
+        })
+        .assert_ok();
+    setup
+        .blockchain_wrapper
+        .check_dct_balance(&first_user, REWARD_TOKEN_ID, &rust_biguint!(1_250));
+    setup.check_pending_rewards(&first_user, 0);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(&first_user, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.claim_rewards();
+            StateChange::Commit
+        })
+        .assert_user_error("No rewards to claim");
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(&first_user, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.compound_rewards();
+            StateChange::Commit
+        })
+        .assert_user_error("Rewards can only be compounded if paid in the staking token");
+
+    setup
+        .blockchain_wrapper
+        .execute_dct_transfer(
+            &first_user,
+            &setup.sc_wrapper,
+            STAKING_TOKEN_ID,
+            0,
+            &rust_biguint!(100),
+            |sc| {
+                sc.deposit_rewards(managed_token_id!(STAKING_TOKEN_ID), managed_biguint!(100));
+                StateChange::Commit
+            },
+        )
+        .assert_user_error("Only reward token accepted");
+}
+
+#[test]
+fn reward_per_block_change_test() {
+    let mut setup = setup_staking(use_module::contract_obj, REWARD_TOKEN_ID, 10_000);
+    let first_user = setup.first_user_address.clone();
+    let owner = setup.owner_address.clone();
+
+    setup.stake(&first_user, 100);
+    setup.blockchain_wrapper.set_block_nonce(10);
+
+    setup
+        .blockchain_wrapper
+        .execute_proxy_call(
+            &first_user,
+            &setup.sc_wrapper,
+            |proxy: use_module::Proxy<DebugApi>| proxy.set_reward_per_block(managed_biguint!(10)),
+            |_| {},
+        )
+        .assert_user_error("Endpoint can only be called by owner");
+    setup
+        .blockchain_wrapper
+        .execute_proxy_call(
+            &owner,
+            &setup.sc_wrapper,
+            |proxy: use_module::Proxy<DebugApi>| proxy.set_reward_per_block(managed_biguint!(10)),
+            |_| {},
+        )
+        .assert_ok();
+
+    setup.blockchain_wrapper.set_block_nonce(20);
+    setup.check_pending_rewards(&first_user, 1_100);
+}
+
+#[test]
+fn reward_reserve_test() {
+    let mut setup = setup_staking(use_module::contract_obj, REWARD_TOKEN_ID, 500);
+    let first_user = setup.first_user_address.clone();
+    let owner = setup.owner_address.clone();
+
+    setup.stake(&first_user, 100);
+    setup.blockchain_wrapper.set_block_nonce(10);
+    setup.check_pending_rewards(&first_user, 500);
+
+    // the blocks without rewards are not paid once the reserve is funded again
+    setup
+        .blockchain_wrapper
+        .execute_dct_transfer(
+            &owner,
+            &setup.sc_wrapper,
+            REWARD_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.deposit_rewards(managed_token_id!(REWARD_TOKEN_ID), managed_biguint!(1_000));
+                StateChange::Commit
+            },
+        )
+        .assert_ok();
+    setup.check_pending_rewards(&first_user, 500);
+
+    setup.blockchain_wrapper.set_block_nonce(15);
+    setup.check_pending_rewards(&first_user, 1_000);
+}
+
+#[test]
+fn unstake_and_withdraw_test() {
+    let mut setup = setup_staking(use_module::contract_obj, REWARD_TOKEN_ID, 10_000);
+    let first_user = setup.first_user_address.clone();
+
+    setup.stake(&first_user, 100);
+    setup.blockchain_wrapper.set_block_nonce(5);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(&first_user, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.unstake(managed_biguint!(101));
+            StateChange::Commit
+        })
+        .assert_user_error("Cannot unstake more than the staked amount");
+    setup
+        .blockchain_wrapper
+        .execute_tx(&first_user, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.unstake(managed_biguint!(50));
+            StateChange::Commit
+        })
+        .assert_ok();
+    setup.check_staked_amount(&first_user, 50);
+
+    // the unstaked tokens stop earning rewards right away
+    setup.blockchain_wrapper.set_block_nonce(10);
+    setup.check_pending_rewards(&first_user, 1_000);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(&first_user, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.withdraw_unbonded();
+            StateChange::Commit
+        })
+        .assert_user_error("Unbonding period has not ended");
+
+    setup.blockchain_wrapper.set_block_nonce(15);
+    setup
+        .blockchain_wrapper
+        .execute_tx(&first_user, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            let withdrawn_amount = sc.withdraw_unbonded();
+            assert_eq!(withdrawn_amount, managed_biguint!(50));
+            StateChange::Commit
+        })
+        .assert_ok();
+    setup
+        .blockchain_wrapper
+        .check_dct_balance(&first_user, STAKING_TOKEN_ID, &rust_biguint!(950));
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(&first_user, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.withdraw_unbonded();
+            StateChange::Commit
+        })
+        .assert_user_error("No unstaked tokens to withdraw");
+
+    // without an unbonding period, the tokens are sent back right away
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_unbonding_period(0);
+                StateChange::Commit
+            },
+        )
+        .assert_ok();
+    setup
+        .blockchain_wrapper
+        .execute_tx(&first_user, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.unstake(managed_biguint!(50));
+            StateChange::Commit
+        })
+        .assert_ok();
+    setup.check_staked_amount(&first_user, 0);
+    setup.check_pending_rewards(&first_user, 1_500);
+    setup.blockchain_wrapper.check_dct_balance(
+        &first_user,
+        STAKING_TOKEN_ID,
+        &rust_biguint!(1_000),
+    );
+}
+
+#[test]
+fn compound_rewards_test() {
+    let mut setup = setup_staking(use_module::contract_obj, STAKING_TOKEN_ID, 10_000);
+    let first_user = setup.first_user_address.clone();
+    let second_user = setup.second_user_address.clone();
+
+    setup.stake(&first_user, 100);
+    setup.stake(&second_user, 100);
+    setup.blockchain_wrapper.set_block_nonce(10);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(&first_user, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            let rewards = sc.compound_rewards();
+            assert_eq!(rewards, managed_biguint!(500));
+            assert_eq!(sc.total_staked().get(), managed_biguint!(700));
+            StateChange::Commit
+        })
+        .assert_ok();
+    setup.check_staked_amount(&first_user, 600);
+    setup.check_pending_rewards(&first_user, 0);
+
+    // the compounded rewards earn rewards as well
+    setup.blockchain_wrapper.set_block_nonce(17);
+    setup.check_pending_rewards(&first_user, 600);
+    setup.check_pending_rewards(&second_user, 600);
+}
@@ -7,6 +7,10 @@
 dharitri_wasm_node::wasm_endpoints! {
     use_module
     (
+        abstain
+        addPauseGuardian
+        addVestingGrant
+        addVestingGroup
         callBack
         call_derived_not_owner_only
         call_mod_a
@@ -17,38 +21,94 @@ dharitri_wasm_node::wasm_endpoints! {
         changeMaxActionsPerProposal
         changeMinTokenBalanceForProposing
         changeQuorum
+        changeVoteWeighting
         changeVotingDelayInBlocks
         changeVotingPeriodInBlocks
         checkFeatureGuard
+        checkOnlyAdmin
+        checkOnlyAdminOrMinter
+        checkPausableFeature
         checkPause
+        checkWhenNotPaused
+        claimRewards
+        claimVested
+        compoundRewards
+        delegateVotingPower
+        depositRewards
         depositTokensForAction
+        depositVotingPower
         dnsRegister
         downvote
         execute
+        getAddressRoles
+        getBeneficiaryGrants
+        getClaimableAmount
+        getDelegate
         getGovernanceTokenId
+        getGrantClaimableAmount
+        getGrantVestedAmount
         getLockTimeAfterVotingEndsInBlocks
         getMaxActionsPerProposal
         getMinTokenBalanceForProposing
+        getPauseGuardians
+        getPendingRewards
         getProposalActions
         getProposalDescription
         getProposalStatus
         getProposer
         getQuorum
+        getRewardPerBlock
+        getRewardReserve
+        getRewardTokenId
+        getStakedAmount
+        getStakingTokenId
+        getTotalAbstainVotes
         getTotalDownvotes
+        getTotalStaked
         getTotalVotes
+        getTotalVotingPowerAt
+        getUnbondingPeriod
+        getUnbondingStake
+        getVestingGrant
+        getVestingGroup
+        getVestingTokenId
+        getVoteWeighting
         getVotingDelayInBlocks
         getVotingPeriodInBlocks
+        getVotingPower
+        getVotingPowerAt
+        getVotingPowerDeposit
+        grantRole
+        hasRole
         initGovernanceModule
+        initStakingModule
+        initVestingModule
+        isEndpointPaused
+        isFeaturePaused
+        isPauseGuardian
         isPaused
         issueToken
         only_owner_mod_endpoint
         pause
+        pauseEndpoint
+        pauseFeature
         propose
         queue
+        removePauseGuardian
+        removeVestingGroup
+        revokeRole
+        revokeVestingGrant
         setFeatureFlag
         setLocalRoles
+        setRewardPerBlock
+        setUnbondingPeriod
+        stake
         unpause
+        unpauseEndpoint
+        unpauseFeature
+        unstake
         vote
-        withdrawGovernanceTokens
+        withdrawUnbonded
+        withdrawVotingPower
     )
 }
@@ -4,6 +4,10 @@ export CARGO_INCREMENTAL=0
 export RUSTFLAGS="-Zprofile -Ccodegen-units=1 -Copt-level=0 -Clink-dead-code -Coverflow-checks=off -Zpanic_abort_tests"
 export RUSTDOCFLAGS="-Cpanic=abort"
 
+# endpoints called by the denali_rs tests, summary in target/endpoint_coverage.txt
+export DENALI_ENDPOINT_COVERAGE="$(pwd)/target/endpoint_coverage.json"
+rm -f "$DENALI_ENDPOINT_COVERAGE"
+
 cargo build
 cargo test
 
@@ -15,6 +15,7 @@ pub struct Account {
     pub username: Option<BytesValue>,
     pub storage: BTreeMap<BytesKey, BytesValue>,
     pub code: Option<BytesValue>,
+    pub code_metadata: Option<BytesValue>,
     pub owner: Option<AddressValue>,
 }
 
@@ -50,6 +51,9 @@ impl InterpretableFrom<AccountRaw> for Account {
                 })
                 .collect(),
             code: from.code.map(|c| BytesValue::interpret_from(c, context)),
+            code_metadata: from
+                .code_metadata
+                .map(|c| BytesValue::interpret_from(c, context)),
             owner: from.owner.map(|v| AddressValue::interpret_from(v, context)),
         }
     }
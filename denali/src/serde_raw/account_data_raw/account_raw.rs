@@ -34,6 +34,10 @@ pub struct AccountRaw {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<ValueSubTree>,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_metadata: Option<ValueSubTree>,
+
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub owner: Option<ValueSubTree>,
@@ -0,0 +1,161 @@
+use syn::{Lit, Meta, NestedMeta};
+
+static ATTR_CODEC: &str = "codec";
+static ATTR_SKIP: &str = "skip";
+static ATTR_DEFAULT: &str = "default";
+static ATTR_WITH: &str = "with";
+static ATTR_VERSION: &str = "version";
+static ATTR_PREVIOUS_VERSIONS: &str = "previous_versions";
+static ATTR_UNTAGGED_V0: &str = "untagged_v0";
+
+/// Field-level options, given as `#[codec(...)]` attributes.
+#[derive(Default)]
+pub struct FieldCodecAttributes {
+    /// `#[codec(skip)]`: the field is never serialized, it is set to `Default::default()` when decoding.
+    pub skip: bool,
+
+    /// `#[codec(default)]`: if the input ends before this field, it is set to `Default::default()`.
+    /// Only allowed on trailing fields, and only applies when top-decoding.
+    /// When nested, the field is always decoded, since the input can continue with data that follows the object.
+    pub default: bool,
+
+    /// `#[codec(with = "module")]`: the field is serialized using the
+    /// `dep_encode`, `dep_encode_or_exit`, `dep_decode` and `dep_decode_or_exit` functions in that module.
+    pub with: Option<syn::Path>,
+}
+
+pub fn field_codec_attributes(field: &syn::Field) -> FieldCodecAttributes {
+    let mut result = FieldCodecAttributes::default();
+    for attr in field.attrs.iter() {
+        if !attr.path.is_ident(ATTR_CODEC) {
+            continue;
+        }
+        let meta_list = match attr.parse_meta() {
+            Ok(Meta::List(meta_list)) => meta_list,
+            _ => panic!("codec attribute should be of the form #[codec(...)]"),
+        };
+        for nested in meta_list.nested.iter() {
+            match nested {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident(ATTR_SKIP) => {
+                    result.skip = true;
+                },
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident(ATTR_DEFAULT) => {
+                    result.default = true;
+                },
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident(ATTR_WITH) =>
+                {
+                    if let Lit::Str(lit_str) = &name_value.lit {
+                        let path = lit_str
+                            .parse::<syn::Path>()
+                            .expect("codec with attribute should contain a module path");
+                        result.with = Some(path);
+                    } else {
+                        panic!("codec with attribute should be of the form #[codec(with = \"module\")]");
+                    }
+                },
+                _ => panic!("unknown codec attribute, expected one of: skip, default, with"),
+            }
+        }
+    }
+
+    assert!(
+        !(result.skip && (result.default || result.with.is_some())),
+        "codec skip attribute cannot be combined with other codec attributes"
+    );
+    result
+}
+
+/// Fields marked `#[codec(default)]` can only be followed by other such fields, or by skipped fields.
+/// Otherwise the decoder could not tell where the missing data ends.
+pub fn check_trailing_defaults(fields: &syn::Fields) {
+    let mut default_found = false;
+    for field in fields.iter() {
+        let codec_attributes = field_codec_attributes(field);
+        if codec_attributes.default {
+            default_found = true;
+        } else if default_found && !codec_attributes.skip {
+            panic!("fields marked #[codec(default)] can only be followed by other such fields");
+        }
+    }
+}
+
+/// Type-level options for versioned types, given as
+/// `#[codec(version = 2, previous_versions("StructV0", "StructV1"))]`.
+pub struct VersionAttributes {
+    /// The version tag written in front of the current layout.
+    pub version: u8,
+
+    /// The types describing the layouts of all previous versions, starting from version 0.
+    pub previous_versions: Vec<syn::Type>,
+
+    /// Version 0 was saved before the type was versioned, so its data carries no tag.
+    pub untagged_v0: bool,
+}
+
+pub fn version_attributes(attrs: &[syn::Attribute]) -> VersionAttributes {
+    let mut version = None;
+    let mut previous_versions = Vec::new();
+    let mut untagged_v0 = false;
+    for attr in attrs.iter() {
+        if !attr.path.is_ident(ATTR_CODEC) {
+            continue;
+        }
+        let meta_list = match attr.parse_meta() {
+            Ok(Meta::List(meta_list)) => meta_list,
+            _ => panic!("codec attribute should be of the form #[codec(...)]"),
+        };
+        for nested in meta_list.nested.iter() {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident(ATTR_VERSION) =>
+                {
+                    if let Lit::Int(lit_int) = &name_value.lit {
+                        let version_value = lit_int
+                            .base10_parse::<u8>()
+                            .expect("codec version should fit in a u8");
+                        version = Some(version_value);
+                    } else {
+                        panic!(
+                            "codec version attribute should be of the form #[codec(version = 1)]"
+                        );
+                    }
+                },
+                NestedMeta::Meta(Meta::List(list))
+                    if list.path.is_ident(ATTR_PREVIOUS_VERSIONS) =>
+                {
+                    for type_nested in list.nested.iter() {
+                        if let NestedMeta::Lit(Lit::Str(lit_str)) = type_nested {
+                            let ty = lit_str
+                                .parse::<syn::Type>()
+                                .expect("codec previous_versions should contain type names");
+                            previous_versions.push(ty);
+                        } else {
+                            panic!("codec previous_versions should be of the form #[codec(previous_versions(\"StructV0\", \"StructV1\"))]");
+                        }
+                    }
+                },
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident(ATTR_UNTAGGED_V0) => {
+                    untagged_v0 = true;
+                },
+                _ => panic!("unknown codec attribute, expected one of: version, previous_versions, untagged_v0"),
+            }
+        }
+    }
+
+    let version = version.expect("versioned types require a #[codec(version = ...)] attribute");
+    assert!(
+        previous_versions.len() == version as usize,
+        "codec previous_versions must list the layouts of all versions before {}, starting from version 0",
+        version
+    );
+    assert!(
+        !untagged_v0 || version > 0,
+        "codec untagged_v0 requires a previous version 0 layout"
+    );
+    VersionAttributes {
+        version,
+        previous_versions,
+        untagged_v0,
+    }
+}
@@ -1,56 +1,71 @@
 extern crate proc_macro;
 
+mod codec_attr;
 mod nested_de_derive;
 mod nested_en_derive;
 mod top_de_derive;
 mod top_en_derive;
 mod util;
+mod versioned_derive;
 
 use nested_de_derive::nested_decode_impl;
 use nested_en_derive::nested_encode_impl;
 use top_de_derive::{top_decode_impl, top_decode_or_default_impl};
 use top_en_derive::{top_encode_impl, top_encode_or_default_impl};
+use versioned_derive::versioned_codec_impl;
 
 use proc_macro::TokenStream;
 
-#[proc_macro_derive(NestedEncode)]
+#[proc_macro_derive(NestedEncode, attributes(codec))]
 pub fn nested_encode_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
 
     nested_encode_impl(&ast)
 }
 
-#[proc_macro_derive(TopEncode)]
+#[proc_macro_derive(TopEncode, attributes(codec))]
 pub fn top_encode_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
 
     top_encode_impl(&ast)
 }
 
-#[proc_macro_derive(TopEncodeOrDefault)]
+#[proc_macro_derive(TopEncodeOrDefault, attributes(codec))]
 pub fn top_encode_or_default_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
 
     top_encode_or_default_impl(&ast)
 }
 
-#[proc_macro_derive(NestedDecode)]
+#[proc_macro_derive(NestedDecode, attributes(codec))]
 pub fn nested_decode_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
 
     nested_decode_impl(&ast)
 }
 
-#[proc_macro_derive(TopDecode)]
+#[proc_macro_derive(TopDecode, attributes(codec))]
 pub fn top_decode_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
 
     top_decode_impl(&ast)
 }
 
-#[proc_macro_derive(TopDecodeOrDefault)]
+#[proc_macro_derive(TopDecodeOrDefault, attributes(codec))]
 pub fn top_decode_or_default_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
 
     top_decode_or_default_impl(&ast)
 }
+
+/// Derives all 4 codec traits for structs whose encoding starts with a version tag.
+///
+/// Requires a `#[codec(version = N, previous_versions("StructV0", ...))]` attribute,
+/// listing the layouts of all older versions, each convertible into the current one via `From`.
+/// Add `untagged_v0` if version 0 was saved before the type was versioned, without a tag.
+#[proc_macro_derive(VersionedCodec, attributes(codec))]
+pub fn versioned_codec_derive(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).unwrap();
+
+    versioned_codec_impl(&ast)
+}
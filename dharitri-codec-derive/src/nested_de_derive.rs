@@ -1,46 +1,92 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::util::*;
+use crate::{
+    codec_attr::{check_trailing_defaults, field_codec_attributes},
+    util::*,
+};
+
+/// Wraps the decoded value expression according to the field's codec attributes.
+/// Skipped fields get their default value.
+///
+/// `#[codec(default)]` fields missing from the input also get their default value, but only when top-decoding.
+/// A nested input can contain data that comes after the current object,
+/// so there is no way to tell whether the field is missing.
+fn field_decode_value(
+    field: &syn::Field,
+    input_value: &proc_macro2::TokenStream,
+    decode_value: proc_macro2::TokenStream,
+    top_level: bool,
+) -> proc_macro2::TokenStream {
+    let codec_attributes = field_codec_attributes(field);
+    let value = if codec_attributes.skip {
+        quote! {
+            core::default::Default::default()
+        }
+    } else if codec_attributes.default && top_level {
+        quote! {
+            if dharitri_codec::NestedDecodeInput::is_depleted(#input_value) {
+                core::default::Default::default()
+            } else {
+                #decode_value
+            }
+        }
+    } else {
+        decode_value
+    };
+
+    if let Some(ident) = &field.ident {
+        quote! {
+            #ident: #value
+        }
+    } else {
+        value
+    }
+}
 
 pub fn dep_decode_snippet(
     _index: usize,
     field: &syn::Field,
     input_value: &proc_macro2::TokenStream,
+    top_level: bool,
 ) -> proc_macro2::TokenStream {
     let ty = &field.ty;
-    if let Some(ident) = &field.ident {
+    let decode_value = if let Some(with) = &field_codec_attributes(field).with {
         quote! {
-            #ident: <#ty as dharitri_codec::NestedDecode>::dep_decode(#input_value)?
+            #with::dep_decode(#input_value)?
         }
     } else {
         quote! {
             <#ty as dharitri_codec::NestedDecode>::dep_decode(#input_value)?
         }
-    }
+    };
+    field_decode_value(field, input_value, decode_value, top_level)
 }
 
 pub fn dep_decode_or_exit_snippet(
     _index: usize,
     field: &syn::Field,
     input_value: &proc_macro2::TokenStream,
+    top_level: bool,
 ) -> proc_macro2::TokenStream {
     let ty = &field.ty;
-    if let Some(ident) = &field.ident {
+    let decode_value = if let Some(with) = &field_codec_attributes(field).with {
         quote! {
-            #ident: <#ty as dharitri_codec::NestedDecode>::dep_decode_or_exit(#input_value, c.clone(), exit)
+            #with::dep_decode_or_exit(#input_value, c.clone(), exit)
         }
     } else {
         quote! {
             <#ty as dharitri_codec::NestedDecode>::dep_decode_or_exit(#input_value, c.clone(), exit)
         }
-    }
+    };
+    field_decode_value(field, input_value, decode_value, top_level)
 }
 
 pub fn variant_dep_decode_snippets(
     name: &syn::Ident,
     data_enum: &syn::DataEnum,
     input_value: &proc_macro2::TokenStream,
+    top_level: bool,
 ) -> Vec<proc_macro2::TokenStream> {
     data_enum
 		.variants
@@ -49,8 +95,9 @@ pub fn variant_dep_decode_snippets(
 		.map(|(variant_index, variant)| {
 			let variant_index_u8 = variant_index as u8;
 			let variant_ident = &variant.ident;
+			check_trailing_defaults(&variant.fields);
 			let variant_field_snippets = fields_decl_syntax(&variant.fields, |index, field| {
-				dep_decode_snippet(index, field, input_value)
+				dep_decode_snippet(index, field, input_value, top_level)
 			});
 			quote! {
 				#variant_index_u8 => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
@@ -63,6 +110,7 @@ pub fn variant_dep_decode_or_exit_snippets(
     name: &syn::Ident,
     data_enum: &syn::DataEnum,
     input_value: &proc_macro2::TokenStream,
+    top_level: bool,
 ) -> Vec<proc_macro2::TokenStream> {
     data_enum
         .variants
@@ -72,7 +120,7 @@ pub fn variant_dep_decode_or_exit_snippets(
             let variant_index_u8 = variant_index as u8;
             let variant_ident = &variant.ident;
             let variant_field_snippets = fields_decl_syntax(&variant.fields, |index, field| {
-                dep_decode_or_exit_snippet(index, field, input_value)
+                dep_decode_or_exit_snippet(index, field, input_value, top_level)
             });
             quote! {
                 #variant_index_u8 => #name::#variant_ident #variant_field_snippets ,
@@ -86,13 +134,14 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
+            check_trailing_defaults(&data_struct.fields);
             let field_dep_decode_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_snippet(index, field, &quote! {input})
+                    dep_decode_snippet(index, field, &quote! {input}, false)
                 });
             let field_dep_encode_or_exit_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_or_exit_snippet(index, field, &quote! {input})
+                    dep_decode_or_exit_snippet(index, field, &quote! {input}, false)
                 });
             quote! {
                 impl #impl_generics dharitri_codec::NestedDecode for #name #ty_generics #where_clause {
@@ -118,9 +167,9 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
                 "enums with more than 256 variants not supported"
             );
             let variant_dep_decode_snippets =
-                variant_dep_decode_snippets(name, data_enum, &quote! {input});
+                variant_dep_decode_snippets(name, data_enum, &quote! {input}, false);
             let variant_dep_decode_or_exit_snippets =
-                variant_dep_decode_or_exit_snippets(name, data_enum, &quote! {input});
+                variant_dep_decode_or_exit_snippets(name, data_enum, &quote! {input}, false);
 
             quote! {
                 impl #impl_generics dharitri_codec::NestedDecode for #name #ty_generics #where_clause {
@@ -1,16 +1,40 @@
-use crate::util::*;
+use crate::{codec_attr::field_codec_attributes, util::*};
 use proc_macro::TokenStream;
 use quote::quote;
 
-pub fn dep_encode_snippet(value: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-    quote! {
-        dharitri_codec::NestedEncode::dep_encode(&#value, dest)?;
+pub fn dep_encode_snippet(
+    field: &syn::Field,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let codec_attributes = field_codec_attributes(field);
+    if codec_attributes.skip {
+        quote! {}
+    } else if let Some(with) = &codec_attributes.with {
+        quote! {
+            #with::dep_encode(&#value, dest)?;
+        }
+    } else {
+        quote! {
+            dharitri_codec::NestedEncode::dep_encode(&#value, dest)?;
+        }
     }
 }
 
-pub fn dep_encode_or_exit_snippet(value: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-    quote! {
-        dharitri_codec::NestedEncode::dep_encode_or_exit(&#value, dest, c.clone(), exit);
+pub fn dep_encode_or_exit_snippet(
+    field: &syn::Field,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let codec_attributes = field_codec_attributes(field);
+    if codec_attributes.skip {
+        quote! {}
+    } else if let Some(with) = &codec_attributes.with {
+        quote! {
+            #with::dep_encode_or_exit(&#value, dest, c.clone(), exit);
+        }
+    } else {
+        quote! {
+            dharitri_codec::NestedEncode::dep_encode_or_exit(&#value, dest, c.clone(), exit);
+        }
     }
 }
 
@@ -26,9 +50,9 @@ fn variant_dep_encode_snippets(
             let variant_index_u8 = variant_index as u8;
             let variant_ident = &variant.ident;
             let local_var_declarations =
-                fields_decl_syntax(&variant.fields, local_variable_for_field);
+                fields_decl_syntax(&variant.fields, local_pattern_for_field);
             let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                dep_encode_snippet(&local_variable_for_field(index, field))
+                dep_encode_snippet(field, &local_variable_for_field(index, field))
             });
             quote! {
                 #name::#variant_ident #local_var_declarations => {
@@ -52,9 +76,9 @@ fn variant_dep_encode_or_exit_snippets(
 			let variant_index_u8 = variant_index as u8;
 			let variant_ident = &variant.ident;
 			let local_var_declarations =
-				fields_decl_syntax(&variant.fields, local_variable_for_field);
+				fields_decl_syntax(&variant.fields, local_pattern_for_field);
 			let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-				dep_encode_or_exit_snippet(&local_variable_for_field(index, field))
+				dep_encode_or_exit_snippet(field, &local_variable_for_field(index, field))
 			});
 			quote! {
 				#name::#variant_ident #local_var_declarations => {
@@ -72,11 +96,11 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                dep_encode_snippet(field, &self_field_expr(index, field))
             });
             let field_dep_encode_or_exit_snippets =
                 fields_snippets(&data_struct.fields, |index, field| {
-                    dep_encode_or_exit_snippet(&self_field_expr(index, field))
+                    dep_encode_or_exit_snippet(field, &self_field_expr(index, field))
                 });
             quote! {
                 impl #impl_generics dharitri_codec::NestedEncode for #name #ty_generics #where_clause {
@@ -1,7 +1,7 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::{nested_de_derive::*, util::*};
+use crate::{codec_attr::check_trailing_defaults, nested_de_derive::*, util::*};
 
 fn fieldless_enum_match_arm_result_ok(
     name: &syn::Ident,
@@ -78,13 +78,14 @@ fn top_decode_method_bodies(
     let name = &ast.ident;
     match &ast.data {
         syn::Data::Struct(data_struct) => {
+            check_trailing_defaults(&data_struct.fields);
             let field_dep_decode_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_snippet(index, field, &quote! {&mut nested_buffer})
+                    dep_decode_snippet(index, field, &quote! {&mut nested_buffer}, true)
                 });
             let field_dep_encode_or_exit_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_or_exit_snippet(index, field, &quote! {&mut nested_buffer})
+                    dep_decode_or_exit_snippet(index, field, &quote! {&mut nested_buffer}, true)
                 });
 
             let top_decode_body = quote! {
@@ -129,12 +130,17 @@ fn top_decode_method_bodies(
                 };
                 (top_decode_body, top_decode_or_exit_body)
             } else {
-                let variant_dep_decode_snippets =
-                    variant_dep_decode_snippets(name, data_enum, &quote! {&mut nested_buffer});
+                let variant_dep_decode_snippets = variant_dep_decode_snippets(
+                    name,
+                    data_enum,
+                    &quote! {&mut nested_buffer},
+                    true,
+                );
                 let variant_dep_decode_or_exit_snippets = variant_dep_decode_or_exit_snippets(
                     name,
                     data_enum,
                     &quote! {&mut nested_buffer},
+                    true,
                 );
 
                 let top_decode_body = quote! {
@@ -26,9 +26,9 @@ pub fn variant_top_encode_snippets(
             } else {
                 // dep-encode to buffer first
                 let local_var_declarations =
-                    fields_decl_syntax(&variant.fields, local_variable_for_field);
+                    fields_decl_syntax(&variant.fields, local_pattern_for_field);
                 let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                    dep_encode_snippet(&local_variable_for_field(index, field))
+                    dep_encode_snippet(field, &local_variable_for_field(index, field))
                 });
                 quote! {
                     #name::#variant_ident #local_var_declarations => {
@@ -65,9 +65,9 @@ pub fn variant_top_encode_or_exit_snippets(
 			} else {
 				// dep-encode to buffer first
 				let local_var_declarations =
-					fields_decl_syntax(&variant.fields, local_variable_for_field);
+					fields_decl_syntax(&variant.fields, local_pattern_for_field);
 				let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-					dep_encode_or_exit_snippet(&local_variable_for_field(index, field))
+					dep_encode_or_exit_snippet(field, &local_variable_for_field(index, field))
 				});
 				quote! {
 					#name::#variant_ident #local_var_declarations => {
@@ -91,11 +91,11 @@ fn top_encode_method_bodies(
     match &ast.data {
         syn::Data::Struct(data_struct) => {
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                dep_encode_snippet(field, &self_field_expr(index, field))
             });
             let field_dep_encode_or_exit_snippets =
                 fields_snippets(&data_struct.fields, |index, field| {
-                    dep_encode_or_exit_snippet(&self_field_expr(index, field))
+                    dep_encode_or_exit_snippet(field, &self_field_expr(index, field))
                 });
             let top_encode_body = quote! {
                 let mut buffer = output.start_nested_encode();
@@ -1,5 +1,7 @@
 use quote::quote;
 
+use crate::codec_attr::field_codec_attributes;
+
 pub fn is_fieldless_enum(data_enum: &syn::DataEnum) -> bool {
     data_enum
         .variants
@@ -34,6 +36,23 @@ pub fn local_variable_for_field(index: usize, field: &syn::Field) -> proc_macro2
     }
 }
 
+/// Same as `local_variable_for_field`, but skipped fields are matched with a wildcard,
+/// to avoid unused variables in the generated encoder.
+pub fn local_pattern_for_field(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
+    if !field_codec_attributes(field).skip {
+        return local_variable_for_field(index, field);
+    }
+    if let Some(ident) = &field.ident {
+        quote! {
+            #ident: _
+        }
+    } else {
+        quote! {
+            _
+        }
+    }
+}
+
 pub fn fields_snippets<F>(fields: &syn::Fields, field_mapper: F) -> Vec<proc_macro2::TokenStream>
 where
     F: Fn(usize, &syn::Field) -> proc_macro2::TokenStream,
@@ -0,0 +1,264 @@
+use proc_macro::TokenStream;
+use quote::quote;
+
+use crate::{
+    codec_attr::{check_trailing_defaults, version_attributes, VersionAttributes},
+    nested_de_derive::{dep_decode_or_exit_snippet, dep_decode_snippet},
+    nested_en_derive::{dep_encode_or_exit_snippet, dep_encode_snippet},
+    util::*,
+};
+
+/// Generates all 4 codec traits for a struct whose encoding starts with a version tag.
+///
+/// Encoding always writes the current version and layout.
+/// Decoding also accepts the tags of all previous versions,
+/// in which case it decodes the corresponding previous layout and converts it via `From`.
+/// Top-level decoding fills in trailing `#[codec(default)]` fields missing from the current layout.
+///
+/// With `untagged_v0`, version 0 has no tag. Top-level inputs that do not decode as tagged data
+/// are decoded whole as the version 0 layout instead. Nested inputs cannot be rewound, so they only accept tagged data.
+pub fn versioned_codec_impl(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
+    let data_struct = if let syn::Data::Struct(data_struct) = &ast.data {
+        data_struct
+    } else {
+        panic!("only structs can be versioned");
+    };
+    let version_attributes = version_attributes(ast.attrs.as_slice());
+    let current_version = version_attributes.version;
+    check_trailing_defaults(&data_struct.fields);
+
+    let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
+        dep_encode_snippet(field, &self_field_expr(index, field))
+    });
+    let field_dep_encode_or_exit_snippets = fields_snippets(&data_struct.fields, |index, field| {
+        dep_encode_or_exit_snippet(field, &self_field_expr(index, field))
+    });
+    let field_dep_decode_snippets = fields_decl_syntax(&data_struct.fields, |index, field| {
+        dep_decode_snippet(index, field, &quote! {input}, false)
+    });
+    let field_dep_decode_or_exit_snippets =
+        fields_decl_syntax(&data_struct.fields, |index, field| {
+            dep_decode_or_exit_snippet(index, field, &quote! {input}, false)
+        });
+    // at top level, trailing `#[codec(default)]` fields may be missing from the input
+    let field_top_decode_snippets = fields_decl_syntax(&data_struct.fields, |index, field| {
+        dep_decode_snippet(index, field, &quote! {&mut nested_buffer}, true)
+    });
+    let field_top_decode_or_exit_snippets =
+        fields_decl_syntax(&data_struct.fields, |index, field| {
+            dep_decode_or_exit_snippet(index, field, &quote! {&mut nested_buffer}, true)
+        });
+
+    let previous_version_arms =
+        previous_version_arms_snippets(name, ty_generics, &version_attributes, &quote! {input});
+    let previous_version_or_exit_arms = previous_version_or_exit_arms_snippets(
+        name,
+        ty_generics,
+        &version_attributes,
+        &quote! {input},
+    );
+    let previous_version_top_arms = previous_version_arms_snippets(
+        name,
+        ty_generics,
+        &version_attributes,
+        &quote! {&mut nested_buffer},
+    );
+    let previous_version_top_or_exit_arms = previous_version_or_exit_arms_snippets(
+        name,
+        ty_generics,
+        &version_attributes,
+        &quote! {&mut nested_buffer},
+    );
+
+    let top_decode_impl = if version_attributes.untagged_v0 {
+        let v0_ty = &version_attributes.previous_versions[0];
+        quote! {
+            impl #impl_generics dharitri_codec::TopDecode for #name #ty_generics #where_clause {
+                fn top_decode<I: dharitri_codec::TopDecodeInput>(top_input: I) -> core::result::Result<Self, dharitri_codec::DecodeError> {
+                    let bytes = dharitri_codec::TopDecodeInput::into_boxed_slice_u8(top_input);
+                    let tagged_result = (|| -> core::result::Result<Self, dharitri_codec::DecodeError> {
+                        let mut nested_buffer: &[u8] = &bytes[..];
+                        let result = match <u8 as dharitri_codec::NestedDecode>::dep_decode(&mut nested_buffer)? {
+                            #current_version => #name #field_top_decode_snippets,
+                            #(#previous_version_top_arms)*
+                            _ => return core::result::Result::Err(dharitri_codec::DecodeError::UNSUPPORTED_VERSION),
+                        };
+                        if !dharitri_codec::NestedDecodeInput::is_depleted(&nested_buffer) {
+                            return core::result::Result::Err(dharitri_codec::DecodeError::INPUT_TOO_LONG);
+                        }
+                        core::result::Result::Ok(result)
+                    })();
+                    match tagged_result {
+                        core::result::Result::Ok(result) => core::result::Result::Ok(result),
+                        core::result::Result::Err(tagged_err) => {
+                            // data saved before versioning has no tag
+                            match dharitri_codec::top_decode_from_nested::<#v0_ty, _>(&bytes[..]) {
+                                core::result::Result::Ok(v0) => core::result::Result::Ok(
+                                    <#name #ty_generics as core::convert::From<#v0_ty>>::from(v0),
+                                ),
+                                core::result::Result::Err(_) => core::result::Result::Err(tagged_err),
+                            }
+                        },
+                    }
+                }
+
+                fn top_decode_or_exit<I: dharitri_codec::TopDecodeInput, ExitCtx: Clone>(
+                    top_input: I,
+                    c: ExitCtx,
+                    exit: fn(ExitCtx, dharitri_codec::DecodeError) -> !,
+                ) -> Self {
+                    match <Self as dharitri_codec::TopDecode>::top_decode(top_input) {
+                        core::result::Result::Ok(result) => result,
+                        core::result::Result::Err(e) => exit(c, e),
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics dharitri_codec::TopDecode for #name #ty_generics #where_clause {
+                fn top_decode<I: dharitri_codec::TopDecodeInput>(top_input: I) -> core::result::Result<Self, dharitri_codec::DecodeError> {
+                    let mut nested_buffer = top_input.into_nested_buffer();
+                    let result = match <u8 as dharitri_codec::NestedDecode>::dep_decode(&mut nested_buffer)? {
+                        #current_version => #name #field_top_decode_snippets,
+                        #(#previous_version_top_arms)*
+                        _ => return core::result::Result::Err(dharitri_codec::DecodeError::UNSUPPORTED_VERSION),
+                    };
+                    if !dharitri_codec::NestedDecodeInput::is_depleted(&nested_buffer) {
+                        return core::result::Result::Err(dharitri_codec::DecodeError::INPUT_TOO_LONG);
+                    }
+                    core::result::Result::Ok(result)
+                }
+
+                fn top_decode_or_exit<I: dharitri_codec::TopDecodeInput, ExitCtx: Clone>(
+                    top_input: I,
+                    c: ExitCtx,
+                    exit: fn(ExitCtx, dharitri_codec::DecodeError) -> !,
+                ) -> Self {
+                    let mut nested_buffer = top_input.into_nested_buffer();
+                    let result = match <u8 as dharitri_codec::NestedDecode>::dep_decode_or_exit(&mut nested_buffer, c.clone(), exit) {
+                        #current_version => #name #field_top_decode_or_exit_snippets,
+                        #(#previous_version_top_or_exit_arms)*
+                        _ => exit(c, dharitri_codec::DecodeError::UNSUPPORTED_VERSION),
+                    };
+                    if !dharitri_codec::NestedDecodeInput::is_depleted(&nested_buffer) {
+                        exit(c, dharitri_codec::DecodeError::INPUT_TOO_LONG);
+                    }
+                    result
+                }
+            }
+        }
+    };
+
+    let gen = quote! {
+        impl #impl_generics dharitri_codec::NestedEncode for #name #ty_generics #where_clause {
+            fn dep_encode<O: dharitri_codec::NestedEncodeOutput>(&self, dest: &mut O) -> core::result::Result<(), dharitri_codec::EncodeError> {
+                dharitri_codec::NestedEncode::dep_encode(&#current_version, dest)?;
+                #(#field_dep_encode_snippets)*
+                core::result::Result::Ok(())
+            }
+
+            fn dep_encode_or_exit<O: dharitri_codec::NestedEncodeOutput, ExitCtx: Clone>(
+                &self,
+                dest: &mut O,
+                c: ExitCtx,
+                exit: fn(ExitCtx, dharitri_codec::EncodeError) -> !,
+            ) {
+                dharitri_codec::NestedEncode::dep_encode_or_exit(&#current_version, dest, c.clone(), exit);
+                #(#field_dep_encode_or_exit_snippets)*
+            }
+        }
+
+        impl #impl_generics dharitri_codec::TopEncode for #name #ty_generics #where_clause {
+            fn top_encode<O: dharitri_codec::TopEncodeOutput>(&self, output: O) -> core::result::Result<(), dharitri_codec::EncodeError> {
+                dharitri_codec::top_encode_from_nested(self, output)
+            }
+
+            fn top_encode_or_exit<O: dharitri_codec::TopEncodeOutput, ExitCtx: Clone>(
+                &self,
+                output: O,
+                c: ExitCtx,
+                exit: fn(ExitCtx, dharitri_codec::EncodeError) -> !,
+            ) {
+                dharitri_codec::top_encode_from_nested_or_exit(self, output, c, exit);
+            }
+        }
+
+        impl #impl_generics dharitri_codec::NestedDecode for #name #ty_generics #where_clause {
+            fn dep_decode<I: dharitri_codec::NestedDecodeInput>(input: &mut I) -> core::result::Result<Self, dharitri_codec::DecodeError> {
+                let result = match <u8 as dharitri_codec::NestedDecode>::dep_decode(input)? {
+                    #current_version => #name #field_dep_decode_snippets,
+                    #(#previous_version_arms)*
+                    _ => return core::result::Result::Err(dharitri_codec::DecodeError::UNSUPPORTED_VERSION),
+                };
+                core::result::Result::Ok(result)
+            }
+
+            fn dep_decode_or_exit<I: dharitri_codec::NestedDecodeInput, ExitCtx: Clone>(
+                input: &mut I,
+                c: ExitCtx,
+                exit: fn(ExitCtx, dharitri_codec::DecodeError) -> !,
+            ) -> Self {
+                match <u8 as dharitri_codec::NestedDecode>::dep_decode_or_exit(input, c.clone(), exit) {
+                    #current_version => #name #field_dep_decode_or_exit_snippets,
+                    #(#previous_version_or_exit_arms)*
+                    _ => exit(c, dharitri_codec::DecodeError::UNSUPPORTED_VERSION),
+                }
+            }
+        }
+
+        #top_decode_impl
+    };
+
+    gen.into()
+}
+
+/// Match arms decoding the layouts of all previous versions from `input_value`, keyed by their version tag.
+fn previous_version_arms_snippets(
+    name: &syn::Ident,
+    ty_generics: &syn::TypeGenerics,
+    version_attributes: &VersionAttributes,
+    input_value: &proc_macro2::TokenStream,
+) -> Vec<proc_macro2::TokenStream> {
+    tagged_previous_versions(version_attributes)
+        .map(|(version, previous_ty)| {
+            quote! {
+                #version => <#name #ty_generics as core::convert::From<#previous_ty>>::from(
+                    <#previous_ty as dharitri_codec::NestedDecode>::dep_decode(#input_value)?,
+                ),
+            }
+        })
+        .collect()
+}
+
+fn previous_version_or_exit_arms_snippets(
+    name: &syn::Ident,
+    ty_generics: &syn::TypeGenerics,
+    version_attributes: &VersionAttributes,
+    input_value: &proc_macro2::TokenStream,
+) -> Vec<proc_macro2::TokenStream> {
+    tagged_previous_versions(version_attributes)
+        .map(|(version, previous_ty)| {
+            quote! {
+                #version => <#name #ty_generics as core::convert::From<#previous_ty>>::from(
+                    <#previous_ty as dharitri_codec::NestedDecode>::dep_decode_or_exit(#input_value, c.clone(), exit),
+                ),
+            }
+        })
+        .collect()
+}
+
+/// The previous versions decoded by their tag, i.e. all of them except an untagged version 0.
+fn tagged_previous_versions(
+    version_attributes: &VersionAttributes,
+) -> impl Iterator<Item = (u8, &syn::Type)> {
+    let skip = if version_attributes.untagged_v0 { 1 } else { 0 };
+    version_attributes
+        .previous_versions
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(version, previous_ty)| (version as u8, previous_ty))
+}
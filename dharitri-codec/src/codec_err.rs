@@ -69,6 +69,7 @@ impl DecodeError {
     pub const ARRAY_DECODE_ERROR: DecodeError = DecodeError("array decode error");
     pub const UTF8_DECODE_ERROR: DecodeError = DecodeError("utf-8 decode error");
     pub const CAPACITY_EXCEEDED_ERROR: DecodeError = DecodeError("capacity exceeded");
+    pub const UNSUPPORTED_VERSION: DecodeError = DecodeError("unsupported version");
 }
 
 #[cfg(test)]
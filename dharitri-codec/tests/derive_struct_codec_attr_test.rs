@@ -0,0 +1,214 @@
+extern crate dharitri_codec_derive;
+use dharitri_codec_derive::*;
+
+use dharitri_codec::{
+    test_util::{check_dep_encode_decode, check_top_encode, check_top_encode_decode},
+    top_decode_from_nested, DecodeError, TopDecode,
+};
+
+/// Encodes a `u64` on 4 bytes, for fields that are known to be small.
+mod u64_as_u32 {
+    use dharitri_codec::{
+        DecodeError, EncodeError, NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeOutput,
+    };
+
+    pub fn dep_encode<O: NestedEncodeOutput>(value: &u64, dest: &mut O) -> Result<(), EncodeError> {
+        (*value as u32).dep_encode(dest)
+    }
+
+    pub fn dep_encode_or_exit<O: NestedEncodeOutput, ExitCtx: Clone>(
+        value: &u64,
+        dest: &mut O,
+        c: ExitCtx,
+        exit: fn(ExitCtx, EncodeError) -> !,
+    ) {
+        (*value as u32).dep_encode_or_exit(dest, c, exit);
+    }
+
+    pub fn dep_decode<I: NestedDecodeInput>(input: &mut I) -> Result<u64, DecodeError> {
+        u32::dep_decode(input).map(|value| value as u64)
+    }
+
+    pub fn dep_decode_or_exit<I: NestedDecodeInput, ExitCtx: Clone>(
+        input: &mut I,
+        c: ExitCtx,
+        exit: fn(ExitCtx, DecodeError) -> !,
+    ) -> u64 {
+        u32::dep_decode_or_exit(input, c, exit) as u64
+    }
+}
+
+/// Does not implement any of the codec traits.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Cache {
+    pub hits: usize,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct StructWithAttributes {
+    pub int: u16,
+    #[codec(skip)]
+    pub cache: Cache,
+    #[codec(with = "u64_as_u32")]
+    pub small: u64,
+    #[codec(default)]
+    pub added_later: u32,
+    #[codec(default)]
+    pub added_even_later: Vec<u8>,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct TupleStructWithAttributes(pub u8, #[codec(skip)] pub Cache, #[codec(default)] pub u16);
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct StructWithNestedAttributes {
+    pub inner: TupleStructWithAttributes,
+    pub after: u16,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub enum EnumWithAttributes {
+    Empty,
+    Fields {
+        #[codec(skip)]
+        cache: Cache,
+        #[codec(with = "u64_as_u32")]
+        small: u64,
+    },
+}
+
+#[test]
+fn struct_codec_attr_full_test() {
+    let s = StructWithAttributes {
+        int: 0x42,
+        cache: Cache::default(),
+        small: 0x1234,
+        added_later: 5,
+        added_even_later: vec![6],
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* int */ 0, 0x42,
+        /* small */ 0, 0, 0x12, 0x34,
+        /* added_later */ 0, 0, 0, 5,
+        /* added_even_later */ 0, 0, 0, 1, 6,
+    ];
+
+    check_top_encode_decode(s.clone(), bytes);
+    check_dep_encode_decode(s, bytes);
+}
+
+#[test]
+fn struct_codec_attr_skip_test() {
+    let s = StructWithAttributes {
+        int: 0x42,
+        cache: Cache { hits: 7 },
+        small: 0x1234,
+        added_later: 5,
+        added_even_later: vec![],
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* int */ 0, 0x42,
+        /* small */ 0, 0, 0x12, 0x34,
+        /* added_later */ 0, 0, 0, 5,
+        /* added_even_later */ 0, 0, 0, 0,
+    ];
+
+    assert_eq!(check_top_encode(&s).as_slice(), bytes);
+    let decoded = StructWithAttributes::top_decode(&bytes[..]).unwrap();
+    assert_eq!(decoded.cache, Cache::default());
+    assert_eq!(
+        decoded,
+        StructWithAttributes {
+            cache: Cache::default(),
+            ..s
+        }
+    );
+}
+
+#[test]
+fn struct_codec_attr_default_test() {
+    let expected = StructWithAttributes {
+        int: 0x42,
+        cache: Cache::default(),
+        small: 0x1234,
+        added_later: 0,
+        added_even_later: vec![],
+    };
+
+    // data saved before the last 2 fields were added
+    let old_bytes = &[0, 0x42, 0, 0, 0x12, 0x34];
+    assert_eq!(
+        StructWithAttributes::top_decode(&old_bytes[..]),
+        Ok(expected.clone())
+    );
+
+    // data saved before the last field was added
+    let old_bytes = &[0, 0x42, 0, 0, 0x12, 0x34, 0, 0, 0, 5];
+    assert_eq!(
+        StructWithAttributes::top_decode(&old_bytes[..]),
+        Ok(StructWithAttributes {
+            added_later: 5,
+            ..expected
+        })
+    );
+
+    // fields without the attribute are still mandatory
+    let truncated_bytes = &[0, 0x42, 0, 0];
+    assert_eq!(
+        StructWithAttributes::top_decode(&truncated_bytes[..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+}
+
+#[test]
+fn tuple_struct_codec_attr_test() {
+    let s = TupleStructWithAttributes(1, Cache::default(), 0x0203);
+    check_top_encode_decode(s.clone(), &[1, 2, 3]);
+    check_dep_encode_decode(s, &[1, 2, 3]);
+
+    assert_eq!(
+        TupleStructWithAttributes::top_decode(&[1][..]),
+        Ok(TupleStructWithAttributes(1, Cache::default(), 0))
+    );
+
+    // defaults only apply to top-level decoding, nested the field is always expected
+    let decoded: Result<TupleStructWithAttributes, DecodeError> = top_decode_from_nested(&[1][..]);
+    assert_eq!(decoded, Err(DecodeError::INPUT_TOO_SHORT));
+}
+
+#[test]
+fn nested_struct_codec_attr_test() {
+    let s = StructWithNestedAttributes {
+        inner: TupleStructWithAttributes(1, Cache::default(), 0x0203),
+        after: 0x0405,
+    };
+    check_top_encode_decode(s.clone(), &[1, 2, 3, 4, 5]);
+    check_dep_encode_decode(s, &[1, 2, 3, 4, 5]);
+
+    // the default field must not take the bytes of the field that follows it
+    let inner_without_default = &[1, 4, 5];
+    assert_eq!(
+        StructWithNestedAttributes::top_decode(&inner_without_default[..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+
+    let list = vec![
+        TupleStructWithAttributes(1, Cache::default(), 0x0203),
+        TupleStructWithAttributes(4, Cache::default(), 0),
+    ];
+    check_top_encode_decode(list, &[1, 2, 3, 4, 0, 0]);
+}
+
+#[test]
+fn enum_codec_attr_test() {
+    let e = EnumWithAttributes::Fields {
+        cache: Cache::default(),
+        small: 0x1234,
+    };
+    check_top_encode_decode(e.clone(), &[1, 0, 0, 0x12, 0x34]);
+    check_dep_encode_decode(e, &[1, 0, 0, 0x12, 0x34]);
+}
@@ -0,0 +1,257 @@
+extern crate dharitri_codec_derive;
+use dharitri_codec_derive::*;
+
+use dharitri_codec::{
+    test_util::{check_dep_encode_decode, check_top_encode_decode},
+    DecodeError, NestedDecode, TopDecode,
+};
+
+/// Layout of the first version: only a counter.
+#[derive(NestedDecode)]
+pub struct CounterV0 {
+    pub count: u32,
+}
+
+/// Layout of the second version: the counter was widened and an owner added.
+#[derive(NestedDecode)]
+pub struct CounterV1 {
+    pub count: u64,
+    pub owner: Vec<u8>,
+}
+
+#[derive(VersionedCodec, PartialEq, Clone, Debug)]
+#[codec(version = 2, previous_versions("CounterV0", "CounterV1"))]
+pub struct Counter {
+    pub count: u64,
+    pub owner: Vec<u8>,
+    pub paused: bool,
+}
+
+impl From<CounterV0> for Counter {
+    fn from(v0: CounterV0) -> Self {
+        Counter {
+            count: v0.count as u64,
+            owner: Vec::new(),
+            paused: false,
+        }
+    }
+}
+
+impl From<CounterV1> for Counter {
+    fn from(v1: CounterV1) -> Self {
+        Counter {
+            count: v1.count,
+            owner: v1.owner,
+            paused: false,
+        }
+    }
+}
+
+/// The current layout gained a trailing field, which is missing from values saved before it existed.
+#[derive(VersionedCodec, PartialEq, Clone, Debug)]
+#[codec(version = 1, previous_versions("CounterV0"))]
+pub struct CounterWithLimit {
+    pub count: u32,
+    #[codec(default)]
+    pub limit: u32,
+}
+
+impl From<CounterV0> for CounterWithLimit {
+    fn from(v0: CounterV0) -> Self {
+        CounterWithLimit {
+            count: v0.count,
+            limit: 0,
+        }
+    }
+}
+
+/// Layout saved before the type was versioned, without a version tag.
+#[derive(NestedDecode)]
+pub struct SettingsV0 {
+    pub limit: u32,
+}
+
+#[derive(VersionedCodec, PartialEq, Clone, Debug)]
+#[codec(version = 1, previous_versions("SettingsV0"), untagged_v0)]
+pub struct Settings {
+    pub limit: u64,
+    pub enabled: bool,
+}
+
+impl From<SettingsV0> for Settings {
+    fn from(v0: SettingsV0) -> Self {
+        Settings {
+            limit: v0.limit as u64,
+            enabled: false,
+        }
+    }
+}
+
+fn panic_exit(_: (), error: DecodeError) -> ! {
+    panic!("{}", String::from_utf8_lossy(error.message_bytes()))
+}
+
+#[test]
+fn versioned_current_test() {
+    let counter = Counter {
+        count: 5,
+        owner: b"abc".to_vec(),
+        paused: true,
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* version */ 2,
+        /* count */ 0, 0, 0, 0, 0, 0, 0, 5,
+        /* owner */ 0, 0, 0, 3, b'a', b'b', b'c',
+        /* paused */ 1,
+    ];
+
+    check_top_encode_decode(counter.clone(), bytes);
+    check_dep_encode_decode(counter, bytes);
+}
+
+#[test]
+fn versioned_upgrade_from_v0_test() {
+    #[rustfmt::skip]
+    let v0_bytes = &[
+        /* version */ 0,
+        /* count */ 0, 0, 0, 7,
+    ];
+
+    let expected = Counter {
+        count: 7,
+        owner: Vec::new(),
+        paused: false,
+    };
+    assert_eq!(Counter::top_decode(&v0_bytes[..]), Ok(expected.clone()));
+    assert_eq!(Counter::dep_decode(&mut &v0_bytes[..]), Ok(expected));
+}
+
+#[test]
+fn versioned_upgrade_from_v1_test() {
+    #[rustfmt::skip]
+    let v1_bytes = &[
+        /* version */ 1,
+        /* count */ 0, 0, 0, 0, 0, 0, 0, 8,
+        /* owner */ 0, 0, 0, 1, b'x',
+    ];
+
+    let expected = Counter {
+        count: 8,
+        owner: b"x".to_vec(),
+        paused: false,
+    };
+    assert_eq!(Counter::top_decode(&v1_bytes[..]), Ok(expected));
+}
+
+#[test]
+fn versioned_upgrade_nested_test() {
+    // a list saved over time contains items of all versions
+    #[rustfmt::skip]
+    let bytes = &[
+        /* version */ 0, /* count */ 0, 0, 0, 1,
+        /* version */ 1, /* count */ 0, 0, 0, 0, 0, 0, 0, 2, /* owner */ 0, 0, 0, 0,
+        /* version */ 2, /* count */ 0, 0, 0, 0, 0, 0, 0, 3, /* owner */ 0, 0, 0, 0, /* paused */ 1,
+    ];
+
+    let counters = Vec::<Counter>::top_decode(&bytes[..]).unwrap();
+    let counts: Vec<u64> = counters.iter().map(|counter| counter.count).collect();
+    assert_eq!(counts, vec![1, 2, 3]);
+    assert!(counters[2].paused);
+}
+
+#[test]
+fn versioned_errors_test() {
+    assert_eq!(
+        Counter::top_decode(&[3, 0, 0, 0, 1][..]),
+        Err(DecodeError::UNSUPPORTED_VERSION)
+    );
+    assert_eq!(
+        Counter::top_decode(&[0, 0, 0, 0, 1, 0][..]),
+        Err(DecodeError::INPUT_TOO_LONG)
+    );
+    assert_eq!(
+        Counter::top_decode(&[][..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+}
+
+#[test]
+fn versioned_default_field_test() {
+    #[rustfmt::skip]
+    let bytes = &[
+        /* version */ 1,
+        /* count */ 0, 0, 0, 4,
+        /* limit */ 0, 0, 0, 9,
+    ];
+    let counter = CounterWithLimit { count: 4, limit: 9 };
+    check_top_encode_decode(counter.clone(), bytes);
+    check_dep_encode_decode(counter, bytes);
+
+    // trailing default fields can only be missing at top level
+    #[rustfmt::skip]
+    let bytes_without_limit = &[
+        /* version */ 1,
+        /* count */ 0, 0, 0, 4,
+    ];
+    assert_eq!(
+        CounterWithLimit::top_decode(&bytes_without_limit[..]),
+        Ok(CounterWithLimit { count: 4, limit: 0 })
+    );
+    assert_eq!(
+        CounterWithLimit::dep_decode(&mut &bytes_without_limit[..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+}
+
+#[test]
+fn versioned_untagged_v0_test() {
+    #[rustfmt::skip]
+    let bytes = &[
+        /* version */ 1,
+        /* limit */ 0, 0, 0, 0, 0, 0, 0, 5,
+        /* enabled */ 1,
+    ];
+    let settings = Settings {
+        limit: 5,
+        enabled: true,
+    };
+    check_top_encode_decode(settings.clone(), bytes);
+    check_dep_encode_decode(settings, bytes);
+
+    let legacy_settings = Settings {
+        limit: 9,
+        enabled: false,
+    };
+    let v0_bytes = &[/* limit */ 0, 0, 0, 9];
+    assert_eq!(
+        Settings::top_decode(&v0_bytes[..]),
+        Ok(legacy_settings.clone())
+    );
+    assert_eq!(
+        Settings::top_decode_or_exit(&v0_bytes[..], (), panic_exit),
+        legacy_settings
+    );
+
+    // starts with a valid tag, but does not fit the tagged layout
+    let v0_bytes_with_tag_prefix = &[/* limit */ 1, 0, 0, 0];
+    assert_eq!(
+        Settings::top_decode(&v0_bytes_with_tag_prefix[..]),
+        Ok(Settings {
+            limit: 0x01000000,
+            enabled: false,
+        })
+    );
+
+    // nested untagged data cannot be told apart
+    assert_eq!(
+        Settings::dep_decode(&mut &v0_bytes[..]),
+        Err(DecodeError::UNSUPPORTED_VERSION)
+    );
+    // if neither layout fits, the tagged error is returned
+    assert_eq!(
+        Settings::top_decode(&[5][..]),
+        Err(DecodeError::UNSUPPORTED_VERSION)
+    );
+}
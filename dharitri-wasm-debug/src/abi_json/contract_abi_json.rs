@@ -13,6 +13,8 @@ pub struct ContractAbiJson {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constructor: Option<ConstructorAbiJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upgrade_constructor: Option<ConstructorAbiJson>,
     pub endpoints: Vec<EndpointAbiJson>,
     pub has_callback: bool,
     pub types: BTreeMap<String, TypeDescriptionJson>,
@@ -25,6 +27,10 @@ impl From<&ContractAbi> for ContractAbiJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             constructor: abi.constructors.get(0).map(ConstructorAbiJson::from),
+            upgrade_constructor: abi
+                .upgrade_constructors
+                .get(0)
+                .map(ConstructorAbiJson::from),
             endpoints: abi.endpoints.iter().map(EndpointAbiJson::from).collect(),
             has_callback: abi.has_callback,
             types: BTreeMap::new(),
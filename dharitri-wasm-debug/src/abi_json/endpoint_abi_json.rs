@@ -88,6 +88,9 @@ pub struct EndpointAbiJson {
     #[serde(rename = "onlyOwner")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub only_owner: Option<bool>,
+    #[serde(rename = "onlyRoles")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub only_roles: Vec<String>,
     pub mutability: EndpointMutabilityAbiJson,
     #[serde(rename = "payableInTokens")]
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -102,6 +105,7 @@ impl From<&EndpointAbi> for EndpointAbiJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             only_owner: if abi.only_owner { Some(true) } else { None },
+            only_roles: abi.only_roles.iter().map(|r| r.to_string()).collect(),
             mutability: match abi.mutability {
                 EndpointMutabilityAbi::Mutable => EndpointMutabilityAbiJson::Mutable,
                 EndpointMutabilityAbi::Readonly => EndpointMutabilityAbiJson::Readonly,
@@ -3,7 +3,7 @@ use crate::{
     DebugApi,
 };
 use dharitri_wasm::{
-    api::{BlockchainApi, BlockchainApiImpl, Handle, ManagedBufferApi, ManagedTypeApi},
+    api::{BlockchainApi, BlockchainApiImpl, Handle, ManagedTypeApi},
     types::{
         Address, BigUint, DctLocalRole, DctLocalRoleFlags, DctTokenData, DctTokenType,
         ManagedAddress, ManagedBuffer, ManagedType, ManagedVec, TokenIdentifier, H256,
@@ -36,8 +36,8 @@ impl BlockchainApiImpl for DebugApi {
         })
     }
 
-    fn get_shard_of_address_legacy(&self, _address: &Address) -> u32 {
-        panic!("get_shard_of_address not implemented")
+    fn get_shard_of_address_legacy(&self, address: &Address) -> u32 {
+        self.blockchain_ref().get_shard_of_address(address)
     }
 
     fn is_smart_contract_legacy(&self, address: &Address) -> bool {
@@ -45,22 +45,25 @@ impl BlockchainApiImpl for DebugApi {
     }
 
     fn get_balance_legacy(&self, address: &Address) -> Handle {
-        assert!(
-            address == &self.get_sc_address_legacy(),
-            "get balance not yet implemented for accounts other than the contract itself"
-        );
-        let moax_balance = self.with_contract_account(|account| account.moax_balance.clone());
+        let moax_balance = self
+            .blockchain_cache()
+            .with_account_or_default(address, |account| account.moax_balance.clone());
         self.insert_new_big_uint(moax_balance)
     }
 
+    /// Only covers the state before the current transaction.
     fn get_state_root_hash_legacy(&self) -> H256 {
-        panic!("get_state_root_hash_legacy not yet implemented")
+        self.blockchain_ref().state_root_hash()
     }
 
     fn get_tx_hash_legacy(&self) -> H256 {
         self.input_ref().tx_hash.clone()
     }
 
+    fn get_callback_closure<M: ManagedTypeApi>(&self) -> ManagedBuffer<M> {
+        ManagedBuffer::new_from_bytes(self.input_ref().promise_callback_closure_data.as_slice())
+    }
+
     fn get_gas_left(&self) -> u64 {
         self.input_ref().gas_limit
     }
@@ -116,17 +119,13 @@ impl BlockchainApiImpl for DebugApi {
         address: &ManagedAddress<M>,
         token: &TokenIdentifier<M>,
     ) -> u64 {
-        assert!(
-            self.mb_eq(address.get_raw_handle(), self.get_sc_address_handle()),
-            "get_current_dct_nft_nonce not yet implemented for accounts other than the contract itself"
-        );
-
-        self.with_contract_account(|account| {
-            account
-                .dct
-                .get_by_identifier_or_default(token.to_dct_identifier().as_slice())
-                .last_nonce
-        })
+        self.blockchain_cache()
+            .with_account_or_default(&address.to_address(), |account| {
+                account
+                    .dct
+                    .get_by_identifier_or_default(token.to_dct_identifier().as_slice())
+                    .last_nonce
+            })
     }
 
     fn get_dct_balance<M: ManagedTypeApi>(
@@ -135,16 +134,13 @@ impl BlockchainApiImpl for DebugApi {
         token: &TokenIdentifier<M>,
         nonce: u64,
     ) -> BigUint<M> {
-        assert!(
-            self.mb_eq(address.get_raw_handle(), self.get_sc_address_handle()),
-            "get_dct_balance not yet implemented for accounts other than the contract itself"
-        );
-
-        let dct_balance = self.with_contract_account(|account| {
-            account
-                .dct
-                .get_dct_balance(token.to_dct_identifier().as_slice(), nonce)
-        });
+        let dct_balance =
+            self.blockchain_cache()
+                .with_account_or_default(&address.to_address(), |account| {
+                    account
+                        .dct
+                        .get_dct_balance(token.to_dct_identifier().as_slice(), nonce)
+                });
         BigUint::from_raw_handle(self.insert_new_big_uint(dct_balance))
     }
 
@@ -203,7 +199,7 @@ impl DebugApi {
         };
 
         let mut uris = ManagedVec::new();
-        if let Some(uri) = &instance.metadata.uri {
+        for uri in &instance.metadata.uris {
             uris.push(ManagedBuffer::new_from_bytes(uri.as_slice()));
         }
 
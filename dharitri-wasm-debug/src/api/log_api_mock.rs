@@ -5,7 +5,10 @@ use dharitri_wasm::{
     },
 };
 
-use crate::{tx_mock::TxLog, DebugApi};
+use crate::{
+    tx_mock::{TxContextStack, TxLog},
+    DebugApi,
+};
 
 impl LogApi for DebugApi {
     type LogApiImpl = DebugApi;
@@ -33,8 +36,7 @@ impl LogApiImpl for DebugApi {
             current_index += arg_len;
         }
 
-        let mut tx_result = self.result_borrow_mut();
-        tx_result.result_logs.push(TxLog {
+        self.push_log(TxLog {
             address: self.input_ref().to.clone(),
             endpoint: self.input_ref().func_name.clone(),
             topics,
@@ -45,8 +47,7 @@ impl LogApiImpl for DebugApi {
     fn write_legacy_log(&self, topics: &[[u8; 32]], data: &[u8]) {
         let topics_vec = topics.iter().map(|array| array.to_vec()).collect();
 
-        let mut tx_result = self.result_borrow_mut();
-        tx_result.result_logs.push(TxLog {
+        self.push_log(TxLog {
             address: self.input_ref().to.clone(),
             endpoint: self.input_ref().func_name.clone(),
             topics: topics_vec,
@@ -61,3 +62,10 @@ impl LogApiImpl for DebugApi {
         self.write_event_log(&topics_arg_buffer, data.to_boxed_bytes().as_slice());
     }
 }
+
+impl DebugApi {
+    fn push_log(&self, log: TxLog) {
+        TxContextStack::static_trace(|frame| frame.logs.push(log.clone()));
+        self.result_borrow_mut().result_logs.push(log);
+    }
+}
@@ -1,6 +1,11 @@
 use crate::{
-    tx_execution::{deploy_contract, execute_builtin_function_or_default},
-    tx_mock::{AsyncCallTxData, BlockchainUpdate, TxCache, TxInput, TxPanic, TxResult},
+    tx_execution::{
+        check_direct_transfer_payable, deploy_contract, execute_builtin_function_or_default,
+    },
+    tx_mock::{
+        AsyncCallPromiseTxData, AsyncCallTxData, BlockchainUpdate, TxCache, TxInput, TxPanic,
+        TxResult,
+    },
     DebugApi,
 };
 use dharitri_wasm::{
@@ -52,6 +57,13 @@ impl DebugApi {
         func_name: Vec<u8>,
         args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
+        if self.is_cross_shard_call(&to) {
+            std::panic::panic_any(TxPanic {
+                status: 10,
+                message: b"sync execution request is not in the same shard".to_vec(),
+            });
+        }
+
         let contract_address = &self.input_ref().to;
         let tx_hash = self.get_tx_hash_legacy();
         let tx_input = TxInput {
@@ -64,6 +76,7 @@ impl DebugApi {
             gas_limit: 1000,
             gas_price: 0,
             tx_hash,
+            ..Default::default()
         };
 
         let tx_cache = TxCache::new(self.blockchain_cache_rc());
@@ -81,9 +94,56 @@ impl DebugApi {
         }
     }
 
+    /// Transfer-execute calls within the same shard are performed right away, like sync calls.
+    /// The ones to other shards are only sent, without any results for the caller.
+    ///
+    /// The builtin functions that transfer NFTs are called on the sender,
+    /// which is why the recipient is given separately.
+    fn perform_transfer_execute(
+        &self,
+        recipient: &Address,
+        to: Address,
+        moax_value: num_bigint::BigUint,
+        func_name: Vec<u8>,
+        args: Vec<Vec<u8>>,
+    ) {
+        if self.is_cross_shard_call(recipient) {
+            let call = AsyncCallTxData {
+                from: self.input_ref().to.clone(),
+                to,
+                call_value: moax_value,
+                endpoint_name: func_name,
+                arguments: args,
+                tx_hash: self.get_tx_hash_legacy(),
+            };
+            self.send_cross_shard_call_value(&call);
+            self.result_borrow_mut()
+                .result_calls
+                .cross_shard_transfer_execute
+                .push(call);
+        } else {
+            let _ = self.perform_execute_on_dest_context(to, moax_value, func_name, args);
+        }
+    }
+
+    fn is_cross_shard_call(&self, recipient: &Address) -> bool {
+        self.blockchain_ref()
+            .is_cross_shard(&self.input_ref().to, recipient)
+    }
+
+    /// The MOAX sent with a cross-shard call leaves the caller right away,
+    /// and reaches the recipient once the call is delivered.
+    fn send_cross_shard_call_value(&self, call: &AsyncCallTxData) {
+        if self.is_cross_shard_call(&call.to) {
+            self.blockchain_cache()
+                .subtract_moax_balance(&call.from, &call.call_value);
+        }
+    }
+
     fn perform_deploy(
         &self,
         contract_code: Vec<u8>,
+        code_metadata: CodeMetadata,
         moax_value: num_bigint::BigUint,
         args: Vec<Vec<u8>>,
     ) -> (Address, Vec<Vec<u8>>) {
@@ -99,12 +159,13 @@ impl DebugApi {
             gas_limit: 1000,
             gas_price: 0,
             tx_hash,
+            ..Default::default()
         };
 
         let tx_cache = TxCache::new(self.blockchain_cache_rc());
         tx_cache.increase_acount_nonce(contract_address);
         let (tx_result, blockchain_updates, new_address) =
-            deploy_contract(tx_input, contract_code, tx_cache);
+            deploy_contract(tx_input, contract_code, code_metadata, tx_cache);
 
         if tx_result.result_status == 0 {
             (
@@ -121,6 +182,7 @@ impl DebugApi {
     }
 
     fn perform_async_call(&self, call: AsyncCallTxData) -> ! {
+        self.send_cross_shard_call_value(&call);
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.extract_result();
         tx_result.result_calls.async_call = Some(call);
@@ -189,10 +251,32 @@ impl SendApiImpl for DebugApi {
         }
 
         let contract_address = &self.input_ref().to;
+        let recipient = &to.to_address();
+        if self.is_cross_shard_call(recipient) {
+            self.perform_transfer_execute(
+                recipient,
+                recipient.clone(),
+                amount_value,
+                Vec::new(),
+                Vec::new(),
+            );
+            return;
+        }
+
+        if !amount_value.is_zero() {
+            if let Err(message) =
+                check_direct_transfer_payable(self.blockchain_cache(), contract_address, recipient)
+            {
+                std::panic::panic_any(TxPanic {
+                    status: 10,
+                    message: message.into_bytes(),
+                });
+            }
+        }
+
         self.blockchain_cache()
             .subtract_moax_balance(contract_address, &amount_value);
 
-        let recipient = &to.to_address();
         self.blockchain_cache()
             .increase_moax_balance(recipient, &amount_value);
     }
@@ -208,8 +292,9 @@ impl SendApiImpl for DebugApi {
         let moax_value = self.big_uint_handle_to_value(amount.get_raw_handle());
         let recipient = to.to_address();
 
-        let _ = self.perform_execute_on_dest_context(
-            recipient,
+        self.perform_transfer_execute(
+            &recipient,
+            recipient.clone(),
             moax_value,
             endpoint_name.to_boxed_bytes().into_vec(),
             arg_buffer.to_raw_args_vec(),
@@ -234,8 +319,9 @@ impl SendApiImpl for DebugApi {
         let mut args = vec![token_bytes, amount_bytes];
         Self::append_endpoint_name_and_args(&mut args, endpoint_name, arg_buffer);
 
-        let _ = self.perform_execute_on_dest_context(
-            recipient,
+        self.perform_transfer_execute(
+            &recipient,
+            recipient.clone(),
             num_bigint::BigUint::zero(),
             DCT_TRANSFER_FUNC_NAME.to_vec(),
             args,
@@ -270,7 +356,8 @@ impl SendApiImpl for DebugApi {
 
         Self::append_endpoint_name_and_args(&mut args, endpoint_name, arg_buffer);
 
-        let _ = self.perform_execute_on_dest_context(
+        self.perform_transfer_execute(
+            &recipient,
             contract_address,
             num_bigint::BigUint::zero(),
             DCT_NFT_TRANSFER_FUNC_NAME.to_vec(),
@@ -314,7 +401,8 @@ impl SendApiImpl for DebugApi {
             );
         }
 
-        let _ = self.perform_execute_on_dest_context(
+        self.perform_transfer_execute(
+            &recipient,
             contract_address,
             num_bigint::BigUint::zero(),
             DCT_MULTI_TRANSFER_FUNC_NAME.to_vec(),
@@ -346,18 +434,55 @@ impl SendApiImpl for DebugApi {
         self.perform_async_call(call)
     }
 
+    fn create_async_call_raw<M: ManagedTypeApi>(
+        &self,
+        to: &ManagedAddress<M>,
+        amount: &BigUint<M>,
+        endpoint_name: &ManagedBuffer<M>,
+        arg_buffer: &ManagedArgBuffer<M>,
+        success_callback_closure: &ManagedBuffer<M>,
+        error_callback_closure: &ManagedBuffer<M>,
+        gas: u64,
+        extra_gas_for_callback: u64,
+    ) {
+        let amount_value = self.big_uint_handle_to_value(amount.get_raw_handle());
+        let contract_address = self.input_ref().to.clone();
+        let recipient = to.to_address();
+        let tx_hash = self.get_tx_hash_legacy();
+        let promise = AsyncCallPromiseTxData {
+            call: AsyncCallTxData {
+                from: contract_address,
+                to: recipient,
+                call_value: amount_value,
+                endpoint_name: endpoint_name.to_boxed_bytes().into_vec(),
+                arguments: arg_buffer.to_raw_args_vec(),
+                tx_hash,
+            },
+            success_callback_closure: success_callback_closure.to_boxed_bytes().into_vec(),
+            error_callback_closure: error_callback_closure.to_boxed_bytes().into_vec(),
+            gas_limit: gas,
+            extra_gas_for_callback,
+        };
+        self.send_cross_shard_call_value(&promise.call);
+        self.result_borrow_mut().result_calls.promises.push(promise);
+    }
+
     fn deploy_contract<M: ManagedTypeApi>(
         &self,
         _gas: u64,
         amount: &BigUint<M>,
         code: &ManagedBuffer<M>,
-        _code_metadata: CodeMetadata,
+        code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<M>,
     ) -> (ManagedAddress<M>, ManagedVec<M, ManagedBuffer<M>>) {
         let moax_value = self.big_uint_handle_to_value(amount.get_raw_handle());
         let contract_code = code.to_boxed_bytes().into_vec();
-        let (new_address, result) =
-            self.perform_deploy(contract_code, moax_value, arg_buffer.to_raw_args_vec());
+        let (new_address, result) = self.perform_deploy(
+            contract_code,
+            code_metadata,
+            moax_value,
+            arg_buffer.to_raw_args_vec(),
+        );
 
         (ManagedAddress::from(new_address), ManagedVec::from(result))
     }
@@ -367,13 +492,14 @@ impl SendApiImpl for DebugApi {
         _gas: u64,
         amount: &BigUint<M>,
         source_contract_address: &ManagedAddress<M>,
-        _code_metadata: CodeMetadata,
+        code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<M>,
     ) -> (ManagedAddress<M>, ManagedVec<M, ManagedBuffer<M>>) {
         let moax_value = self.big_uint_handle_to_value(amount.get_raw_handle());
         let source_contract_code = self.get_contract_code(&source_contract_address.to_address());
         let (new_address, result) = self.perform_deploy(
             source_contract_code,
+            code_metadata,
             moax_value,
             arg_buffer.to_raw_args_vec(),
         );
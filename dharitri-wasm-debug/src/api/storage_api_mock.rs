@@ -1,4 +1,7 @@
-use crate::{tx_mock::TxPanic, DebugApi};
+use crate::{
+    tx_mock::{TxContextStack, TxPanic, TxTraceStorageAccess},
+    DebugApi,
+};
 use alloc::vec::Vec;
 use dharitri_wasm::{
     api::{
@@ -24,10 +27,13 @@ impl StorageReadApiImpl for DebugApi {
     }
 
     fn storage_load_vec_u8(&self, key: &[u8]) -> Vec<u8> {
-        self.with_contract_account(|account| match account.storage.get(&key.to_vec()) {
-            None => Vec::with_capacity(0),
-            Some(value) => value.clone(),
-        })
+        let value =
+            self.with_contract_account(|account| match account.storage.get(&key.to_vec()) {
+                None => Vec::with_capacity(0),
+                Some(value) => value.clone(),
+            });
+        self.record_storage_read(&self.input_ref().to, key, &value);
+        value
     }
 
     fn storage_load_big_uint_raw(&self, key: &[u8]) -> Handle {
@@ -75,15 +81,27 @@ impl StorageReadApiImpl for DebugApi {
         }
     }
 
+    /// Contracts that are not readable only expose their storage to themselves,
+    /// everyone else reads empty values.
     fn storage_load_from_address(&self, address_handle: Handle, key_handle: Handle) -> Handle {
         let address = Address::from_slice(self.mb_to_boxed_bytes(address_handle).as_slice());
         let key_bytes = self.mb_to_boxed_bytes(key_handle);
-        self.with_account(&address, |account| {
-            match account.storage.get(key_bytes.as_slice()) {
-                None => self.mb_new_from_bytes(&[]),
-                Some(value) => self.mb_new_from_bytes(value.as_slice()),
+        let is_own_storage = address == self.input_ref().to;
+        let value = self.with_account(&address, |account| {
+            if !is_own_storage
+                && account.contract_path.is_some()
+                && !account.code_metadata.is_readable()
+            {
+                return Vec::new();
             }
-        })
+            account
+                .storage
+                .get(key_bytes.as_slice())
+                .cloned()
+                .unwrap_or_default()
+        });
+        self.record_storage_read(&address, key_bytes.as_slice(), &value);
+        self.mb_new_from_bytes(value.as_slice())
     }
 }
 
@@ -108,6 +126,7 @@ impl StorageWriteApiImpl for DebugApi {
         self.with_contract_account_mut(|account| {
             account.storage.insert(key.to_vec(), value.to_vec());
         });
+        trace_storage_access(&self.input_ref().to, key, value, true);
     }
 
     fn storage_store_big_uint_raw(&self, key: &[u8], handle: i32) {
@@ -141,3 +160,28 @@ impl StorageWriteApiImpl for DebugApi {
         }
     }
 }
+
+impl DebugApi {
+    /// Reads are kept in the tx result, for the storage diff. Writes are computed from the final state instead.
+    fn record_storage_read(&self, address: &Address, key: &[u8], value: &[u8]) {
+        self.result_borrow_mut()
+            .storage_diff
+            .add_read(address, key, value);
+        trace_storage_access(address, key, value, false);
+    }
+}
+
+fn trace_storage_access(address: &Address, key: &[u8], value: &[u8], is_write: bool) {
+    TxContextStack::static_trace(|frame| {
+        let access = TxTraceStorageAccess {
+            address: address.clone(),
+            key: key.to_vec(),
+            value: value.to_vec(),
+        };
+        if is_write {
+            frame.storage_writes.push(access);
+        } else {
+            frame.storage_reads.push(access);
+        }
+    });
+}
@@ -1,15 +1,20 @@
-use crate::DebugApi;
+use crate::{world_mock::EndpointKind, DebugApi};
 
 use super::*;
 
 use alloc::{boxed::Box, vec::Vec};
-use dharitri_wasm::contract_base::CallableContract;
+use dharitri_wasm::{
+    abi::{ContractAbi, EndpointMutabilityAbi},
+    contract_base::CallableContract,
+};
 use std::{collections::HashMap, fmt};
 
 pub type ContractCallFactory = Box<dyn Fn(DebugApi) -> Box<dyn CallableContract>>;
 
 pub struct ContractMap {
     contract_objs: HashMap<Vec<u8>, Box<dyn CallableContract>>,
+    contract_names: HashMap<Vec<u8>, String>,
+    contract_abis: HashMap<Vec<u8>, ContractAbi>,
 }
 
 impl fmt::Debug for ContractMap {
@@ -22,6 +27,8 @@ impl ContractMap {
     pub fn new() -> Self {
         ContractMap {
             contract_objs: HashMap::new(),
+            contract_names: HashMap::new(),
+            contract_abis: HashMap::new(),
         }
     }
 
@@ -49,6 +56,89 @@ impl ContractMap {
     pub fn contains_contract(&self, contract_bytes: &[u8]) -> bool {
         self.contract_objs.contains_key(contract_bytes)
     }
+
+    pub fn has_upgrade_method(&self, contract_bytes: &[u8]) -> bool {
+        if let Some(contract_obj) = self.contract_objs.get(contract_bytes) {
+            contract_obj.has_upgrade_method()
+        } else {
+            false
+        }
+    }
+
+    /// The name shows up in reports, the contract bytes themselves are not readable.
+    pub fn set_contract_name(&mut self, contract_bytes: Vec<u8>, name: String) {
+        self.contract_names.insert(contract_bytes, name);
+    }
+
+    pub fn contract_name(&self, contract_bytes: &[u8]) -> String {
+        self.contract_names
+            .get(contract_bytes)
+            .cloned()
+            .unwrap_or_else(|| String::from_utf8_lossy(contract_bytes).into_owned())
+    }
+
+    pub fn set_contract_abi(&mut self, contract_bytes: Vec<u8>, abi: ContractAbi) {
+        self.contract_abis.insert(contract_bytes, abi);
+    }
+
+    pub fn contract_abi(&self, contract_bytes: &[u8]) -> Option<&ContractAbi> {
+        self.contract_abis.get(contract_bytes)
+    }
+
+    /// All the contracts registered, with their name and their ABI, if it was registered as well.
+    pub fn registered_contracts(&self) -> Vec<(String, Option<&ContractAbi>)> {
+        self.contract_objs
+            .keys()
+            .map(|contract_bytes| {
+                (
+                    self.contract_name(contract_bytes),
+                    self.contract_abi(contract_bytes),
+                )
+            })
+            .collect()
+    }
+
+    /// Without an ABI, everything other than the constructor, upgrade and callback is taken to be an endpoint.
+    pub fn endpoint_kind(&self, contract_bytes: &[u8], endpoint_name: &str) -> EndpointKind {
+        if let Some(abi) = self.contract_abi(contract_bytes) {
+            if let Some(kind) = endpoint_kind_from_abi(abi, endpoint_name) {
+                return kind;
+            }
+        }
+        match endpoint_name {
+            "init" => EndpointKind::Constructor,
+            "upgrade" => EndpointKind::Upgrade,
+            "callBack" => EndpointKind::Callback,
+            _ => EndpointKind::Endpoint,
+        }
+    }
+}
+
+/// Lists all the endpoints declared in the ABI, with their kind.
+/// Callbacks are left out, the ABI does not name them.
+pub fn abi_endpoint_kinds(abi: &ContractAbi) -> Vec<(&'static str, EndpointKind)> {
+    let mut result = Vec::new();
+    for constructor in &abi.constructors {
+        result.push((constructor.name, EndpointKind::Constructor));
+    }
+    for upgrade_constructor in &abi.upgrade_constructors {
+        result.push((upgrade_constructor.name, EndpointKind::Upgrade));
+    }
+    for endpoint in &abi.endpoints {
+        let kind = match endpoint.mutability {
+            EndpointMutabilityAbi::Mutable => EndpointKind::Endpoint,
+            EndpointMutabilityAbi::Readonly | EndpointMutabilityAbi::Pure => EndpointKind::View,
+        };
+        result.push((endpoint.name, kind));
+    }
+    result
+}
+
+fn endpoint_kind_from_abi(abi: &ContractAbi, endpoint_name: &str) -> Option<EndpointKind> {
+    abi_endpoint_kinds(abi)
+        .into_iter()
+        .find(|(name, _)| *name == endpoint_name)
+        .map(|(_, kind)| kind)
 }
 
 fn unknown_contract_panic(contract_identifier: &[u8]) -> ! {
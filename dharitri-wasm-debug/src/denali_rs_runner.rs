@@ -1,18 +1,56 @@
 #![allow(unused_variables)] // for now
 
-use crate::{denali_step, world_mock::BlockchainMock};
+use crate::{
+    denali_step,
+    tx_execution::process_cross_shard_calls,
+    tx_mock::{TxContextStack, TxTrace},
+    world_mock::{BlockchainMock, EndpointCoverage},
+};
 
 use denali::model::Step;
 use std::{path::Path, rc::Rc};
 
+/// If set, each run adds its endpoint coverage to the JSON file at this path.
+/// Best given as an absolute path, since the tests of each crate run in their own directory.
+pub const ENDPOINT_COVERAGE_ENV_VAR: &str = "DENALI_ENDPOINT_COVERAGE";
+
 /// Runs denali test using the Rust infrastructure and the debug mode.
 /// Uses a contract map to replace the references to the wasm bytecode
 /// with the contracts running in debug mode.
 pub fn denali_rs<P: AsRef<Path>>(relative_path: P, world: BlockchainMock) {
+    let state = run_denali_rs(relative_path, world);
+    if let Some(coverage_path) = std::env::var_os(ENDPOINT_COVERAGE_ENV_VAR) {
+        state.endpoint_coverage().merge_into_file(coverage_path);
+    }
+}
+
+/// Same as `denali_rs`, but returns the endpoint coverage of the run, instead of saving it.
+pub fn denali_rs_endpoint_coverage<P: AsRef<Path>>(
+    relative_path: P,
+    world: BlockchainMock,
+) -> EndpointCoverage {
+    run_denali_rs(relative_path, world).endpoint_coverage()
+}
+
+/// Same as `denali_rs`, but returns the traces of the run, each with the id of its step.
+/// Tracing needs to be enabled beforehand, see `BlockchainMock::enable_trace`.
+pub fn denali_rs_traces<P: AsRef<Path>>(
+    relative_path: P,
+    world: BlockchainMock,
+) -> Vec<(String, TxTrace)> {
+    run_denali_rs(relative_path, world).tx_traces.take()
+}
+
+fn run_denali_rs<P: AsRef<Path>>(relative_path: P, world: BlockchainMock) -> Rc<BlockchainMock> {
     let mut absolute_path = world.current_dir.clone();
     absolute_path.push(relative_path);
     let mut state = Rc::new(world);
+    if state.trace_format.is_some() {
+        TxContextStack::static_start_trace();
+    }
     parse_execute_denali_steps(absolute_path.as_ref(), &mut state);
+    TxContextStack::static_stop_trace();
+    state
 }
 
 fn parse_execute_denali_steps(steps_path: &Path, state: &mut Rc<BlockchainMock>) {
@@ -32,13 +70,17 @@ fn parse_execute_denali_steps(steps_path: &Path, state: &mut Rc<BlockchainMock>)
                 block_hashes,
                 previous_block_info,
                 current_block_info,
-            } => denali_step::set_state::execute(
-                Rc::get_mut(state).unwrap(),
-                accounts,
-                new_addresses,
-                previous_block_info,
-                current_block_info,
-            ),
+            } => {
+                denali_step::set_state::execute(
+                    Rc::get_mut(state).unwrap(),
+                    accounts,
+                    new_addresses,
+                    previous_block_info,
+                    current_block_info,
+                );
+                process_cross_shard_calls(state);
+                denali_step::print_tx_trace(state, "setState");
+            },
             Step::ScCall {
                 tx_id,
                 comment,
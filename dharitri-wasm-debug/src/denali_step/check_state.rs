@@ -46,6 +46,15 @@ pub fn execute(accounts: &denali::model::CheckAccounts, state: &mut BlockchainMo
                 std::str::from_utf8(actual_code.as_slice()).unwrap()
             );
 
+            let actual_code_metadata = account.code_metadata.to_byte_array().to_vec();
+            assert!(
+                expected_account.code_metadata.check(&actual_code_metadata),
+                "bad account code metadata. Address: {}. Want: {}. Have: {}",
+                expected_address,
+                expected_account.code_metadata,
+                verbose_hex(actual_code_metadata.as_slice())
+            );
+
             if let CheckStorage::Equal(eq) = &expected_account.storage {
                 let default_value = &Vec::new();
                 for (expected_key, expected_value) in eq.storages.iter() {
@@ -298,7 +307,13 @@ pub fn check_token_instance(
         ))
     }
 
-    let actual_uri = actual_value.metadata.uri.clone().unwrap_or_default();
+    // Denali only knows about a single URI per instance
+    let actual_uri = actual_value
+        .metadata
+        .uris
+        .first()
+        .cloned()
+        .unwrap_or_default();
     if !expected_value.uri.check(&actual_uri) {
         errors.push(format!(
             "bad dct uri. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
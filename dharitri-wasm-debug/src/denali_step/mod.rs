@@ -3,7 +3,9 @@ pub mod sc_call;
 pub mod sc_deploy;
 pub mod sc_query;
 pub mod set_state;
+mod trace_output;
 pub mod transfer;
 mod tx_output_check;
 
+pub(crate) use trace_output::*;
 use tx_output_check::*;
@@ -8,7 +8,7 @@ use crate::{
     world_mock::BlockchainMock,
 };
 
-use super::check_tx_output;
+use super::{check_tx_output, print_tx_trace};
 
 pub fn execute(
     state: &mut Rc<BlockchainMock>,
@@ -30,8 +30,10 @@ pub fn execute(
         gas_limit: tx.gas_limit.value,
         gas_price: tx.gas_price.value,
         tx_hash: generate_tx_hash_dummy(tx_id),
+        ..Default::default()
     };
     let tx_result = sc_call_with_async_and_callback(tx_input, state, true);
+    print_tx_trace(state, tx_id);
     if let Some(tx_expect) = expect {
         check_tx_output(tx_id, tx_expect, &tx_result);
     }
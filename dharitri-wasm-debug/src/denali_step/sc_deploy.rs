@@ -9,7 +9,7 @@ use crate::{
     world_mock::BlockchainMock,
 };
 
-use super::check_tx_output;
+use super::{check_tx_output, print_tx_trace};
 
 pub fn execute(
     state: &mut Rc<BlockchainMock>,
@@ -31,8 +31,10 @@ pub fn execute(
         gas_limit: tx.gas_limit.value,
         gas_price: tx.gas_price.value,
         tx_hash: generate_tx_hash_dummy(tx_id),
+        ..Default::default()
     };
     let tx_result = sc_create(tx_input, &tx.contract_code.value, state);
+    print_tx_trace(state, tx_id);
     if let Some(tx_expect) = expect {
         check_tx_output(tx_id, tx_expect, &tx_result);
     }
@@ -9,7 +9,7 @@ use crate::{
     world_mock::BlockchainMock,
 };
 
-use super::check_tx_output;
+use super::{check_tx_output, print_tx_trace};
 
 pub fn execute(state: Rc<BlockchainMock>, tx_id: &str, tx: &TxQuery, expect: &Option<TxExpect>) {
     let tx_input = TxInput {
@@ -26,9 +26,11 @@ pub fn execute(state: Rc<BlockchainMock>, tx_id: &str, tx: &TxQuery, expect: &Op
         gas_limit: u64::MAX,
         gas_price: 0u64,
         tx_hash: generate_tx_hash_dummy(tx_id),
+        ..Default::default()
     };
 
-    let tx_result = sc_query(tx_input, state);
+    let tx_result = sc_query(tx_input, state.clone());
+    print_tx_trace(&state, tx_id);
     assert!(
         tx_result.result_status != 0 || tx_result.result_calls.is_empty(),
         "Can't query a view function that performs an async call"
@@ -1,6 +1,9 @@
 use std::collections::BTreeMap;
 
-use dharitri_wasm::types::Address;
+use dharitri_wasm::{
+    dharitri_codec::TopDecode,
+    types::{Address, CodeMetadata},
+};
 use denali::model::{Account, AddressKey, BlockInfo, NewAddress};
 use num_bigint::BigUint;
 
@@ -62,6 +65,7 @@ pub fn execute(
                 .owner
                 .as_ref()
                 .map(|address_value| address_value.value.into()),
+            code_metadata: convert_denali_code_metadata(account),
         });
     }
     for new_address in new_addresses.iter() {
@@ -83,6 +87,16 @@ pub fn execute(
     }
 }
 
+fn convert_denali_code_metadata(account: &Account) -> CodeMetadata {
+    match &account.code_metadata {
+        Some(bytes_value) => {
+            CodeMetadata::top_decode(bytes_value.value.as_slice()).expect("invalid code metadata")
+        },
+        None if account.code.is_some() => AccountData::default_contract_code_metadata(),
+        None => CodeMetadata::DEFAULT,
+    }
+}
+
 fn convert_denali_dct_to_world_mock(
     token_identifier: &[u8],
     denali_dct: &denali::model::Dct,
@@ -161,13 +175,18 @@ fn convert_denali_dct_instance_to_world_mock(
                 .map(|royalties| royalties.value)
                 .unwrap_or_default(),
             hash: denali_dct.hash.as_ref().map(|hash| hash.value.clone()),
-            uri: denali_dct.uri.as_ref().map(|uri| uri.value.clone()),
+            uris: denali_dct
+                .uri
+                .as_ref()
+                .map(|uri| vec![uri.value.clone()])
+                .unwrap_or_default(),
             attributes: denali_dct
                 .attributes
                 .as_ref()
                 .map(|attributes| attributes.value.clone())
                 .unwrap_or_default(),
         },
+        frozen: false,
     }
 }
 
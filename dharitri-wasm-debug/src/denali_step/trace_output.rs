@@ -0,0 +1,16 @@
+use crate::{tx_mock::TxContextStack, world_mock::BlockchainMock};
+
+/// Prints whatever got executed since the last step, if tracing is enabled.
+/// The trace is also kept in the state, so tests can check it.
+pub(crate) fn print_tx_trace(state: &BlockchainMock, step_id: &str) {
+    if let Some(format) = state.trace_format {
+        let trace = TxContextStack::static_take_trace();
+        if !trace.is_empty() {
+            println!("Trace of {}:\n{}", step_id, trace.render(format));
+            state
+                .tx_traces
+                .borrow_mut()
+                .push((step_id.to_string(), trace));
+        }
+    }
+}
@@ -19,6 +19,7 @@ pub fn execute(state: &mut Rc<BlockchainMock>, tx_transfer: &TxTransfer) {
         gas_limit: tx_transfer.gas_limit.value,
         gas_price: tx_transfer.gas_price.value,
         tx_hash: H256::zero(),
+        ..Default::default()
     };
     sc_call(tx_input, state, true).assert_ok();
 }
@@ -21,7 +21,9 @@ pub use managed_test_util::*;
 pub use denali_step::*;
 
 pub use denali_go_runner::denali_go;
-pub use denali_rs_runner::denali_rs;
+pub use denali_rs_runner::{
+    denali_rs, denali_rs_endpoint_coverage, denali_rs_traces, ENDPOINT_COVERAGE_ENV_VAR,
+};
 pub use tx_mock::DebugApi;
 pub use world_mock::BlockchainMock;
 
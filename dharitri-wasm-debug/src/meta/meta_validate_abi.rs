@@ -1,5 +1,7 @@
 use dharitri_wasm::abi::ContractAbi;
 
+const UPGRADE_ENDPOINT_NAME: &str = "upgrade";
+
 fn validate_abi_constructor(abi: &ContractAbi) -> Result<(), &'static str> {
     match abi.constructors.len() {
         0 => Err("Missing constructor. Add a method annotated with `#[init]`."),
@@ -8,7 +10,23 @@ fn validate_abi_constructor(abi: &ContractAbi) -> Result<(), &'static str> {
     }
 }
 
+fn validate_abi_upgrade_constructor(abi: &ContractAbi) -> Result<(), &'static str> {
+    if abi.upgrade_constructors.len() > 1 {
+        return Err("More than one upgrade method present. At most one method annotated with `#[upgrade]` is allowed.");
+    }
+    if !abi.upgrade_constructors.is_empty()
+        && abi
+            .endpoints
+            .iter()
+            .any(|endpoint| endpoint.name == UPGRADE_ENDPOINT_NAME)
+    {
+        return Err("Endpoint name 'upgrade' clashes with the method annotated with `#[upgrade]`.");
+    }
+    Ok(())
+}
+
 pub fn validate_abi(abi: &ContractAbi) -> Result<(), &'static str> {
     validate_abi_constructor(abi)?;
+    validate_abi_upgrade_constructor(abi)?;
     Ok(())
 }
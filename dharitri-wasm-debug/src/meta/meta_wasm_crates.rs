@@ -63,6 +63,9 @@ fn write_wasm_src_lib(contract_metadata: &ContractMetadata) {
     if contract_metadata.abi.has_callback {
         mandatory_endpoints.push("callBack".to_string());
     }
+    if !contract_metadata.abi.upgrade_constructors.is_empty() {
+        mandatory_endpoints.push("upgrade".to_string());
+    }
     let all_endpoint_names = mandatory_endpoints.iter().chain(endpoint_names.iter());
 
     write_endpoints_macro(
@@ -1,15 +1,22 @@
 use std::{collections::HashMap, path::PathBuf, rc::Rc, str::FromStr};
 
 use dharitri_wasm::{
-    contract_base::{CallableContract, ContractBase},
+    contract_base::{CallableContract, ContractBase, ProxyObjBase},
     dharitri_codec::{TopDecode, TopEncode},
-    types::{Address, DctLocalRole, H256},
+    types::{
+        Address, CodeMetadata, ContractCall, DctLocalRole, ManagedAddress, ManagedBuffer,
+        ManagedVec, H256,
+    },
+    ArgId, DynArg, ManagedResultArgLoader,
 };
 
 use crate::{
     rust_biguint,
-    tx_execution::interpret_panic_as_tx_result,
-    tx_mock::{TxCache, TxContext, TxContextStack, TxInput, TxInputDCT, TxResult},
+    tx_execution::{
+        check_dct_transfer_allowed, create_system_sc_account_if_necessary, default_execution,
+        interpret_panic_as_tx_result, process_cross_shard_calls, system_sc_address, SystemScToken,
+    },
+    tx_mock::{TxCache, TxContext, TxContextStack, TxInput, TxInputDCT, TxResult, TxTrace},
     world_mock::{AccountData, AccountDct, DctInstanceMetadata},
     BlockchainMock, DebugApi,
 };
@@ -235,6 +242,11 @@ impl BlockchainStateWrapper {
         sc_identifier: Option<Vec<u8>>,
         sc_denali_path_expr: Option<Vec<u8>>,
     ) {
+        let code_metadata = if sc_identifier.is_some() {
+            AccountData::default_contract_code_metadata()
+        } else {
+            CodeMetadata::DEFAULT
+        };
         let acc_data = AccountData {
             address: address.clone(),
             nonce: 0,
@@ -244,6 +256,7 @@ impl BlockchainStateWrapper {
             username: Vec::new(),
             contract_path: sc_identifier,
             contract_owner: owner.cloned(),
+            code_metadata,
         };
         self.denali_generator
             .set_account(&acc_data, sc_denali_path_expr);
@@ -333,7 +346,7 @@ impl BlockchainStateWrapper {
                         royalties,
                         name: name.unwrap_or_default().to_vec(),
                         hash: hash.map(|h| h.to_vec()),
-                        uri: uri.map(|u| u.to_vec()),
+                        uris: uri.map(|u| vec![u.to_vec()]).unwrap_or_default(),
                     },
                 );
 
@@ -370,6 +383,60 @@ impl BlockchainStateWrapper {
         }
     }
 
+    pub fn set_dct_frozen(&mut self, address: &Address, token_id: &[u8], frozen: bool) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        match b_mock_ref.accounts.get_mut(address) {
+            Some(acc) => {
+                acc.dct.set_frozen(token_id.to_vec(), frozen);
+
+                self.add_denali_set_account(address);
+            },
+            None => panic!(
+                "set_dct_frozen: Account {:?} does not exist",
+                address_to_hex(address)
+            ),
+        }
+    }
+
+    /// Pausing is a global token setting, kept by the DCT system SC mock.
+    pub fn set_dct_paused(&mut self, token_id: &[u8], paused: bool) {
+        self.with_system_sc_token(token_id, |token| token.set_paused(paused));
+    }
+
+    /// Only addresses with the `DctLocalRole::Transfer` role
+    /// can send or receive tokens with limited transfers.
+    pub fn set_dct_limited_transfer(&mut self, token_id: &[u8], limited_transfer: bool) {
+        self.with_system_sc_token(token_id, |token| {
+            token.set_limited_transfer(limited_transfer)
+        });
+    }
+
+    /// Contracts created by the wrapper can be upgraded, read and paid by anyone,
+    /// unless restricted here.
+    pub fn set_code_metadata(&mut self, address: &Address, code_metadata: CodeMetadata) {
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        match b_mock_ref.accounts.get_mut(address) {
+            Some(acc) => {
+                acc.code_metadata = code_metadata;
+
+                self.add_denali_set_account(address);
+            },
+            None => panic!(
+                "set_code_metadata: Account {:?} does not exist",
+                address_to_hex(address)
+            ),
+        }
+    }
+
+    fn with_system_sc_token<F: FnOnce(&SystemScToken)>(&mut self, token_id: &[u8], f: F) {
+        let tx_cache = TxCache::new(self.rc_b_mock.clone());
+        create_system_sc_account_if_necessary(&tx_cache);
+        f(&SystemScToken::new(&tx_cache, token_id));
+        self.rc_b_mock.commit_tx_cache(tx_cache);
+
+        self.add_denali_set_account(&system_sc_address());
+    }
+
     pub fn set_block_epoch(&mut self, block_epoch: u64) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
         b_mock_ref.current_block_info.block_epoch = block_epoch;
@@ -383,6 +450,7 @@ impl BlockchainStateWrapper {
     pub fn set_block_nonce(&mut self, block_nonce: u64) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
         b_mock_ref.current_block_info.block_nonce = block_nonce;
+        process_cross_shard_calls(&mut self.rc_b_mock);
 
         self.denali_generator.set_block_info(
             &self.rc_b_mock.current_block_info,
@@ -581,6 +649,60 @@ impl BlockchainStateWrapper {
         )
     }
 
+    /// Sends the call built by a generated proxy through the mock VM.
+    ///
+    /// Unlike `execute_tx`, the arguments are serialized, the endpoint is dispatched
+    /// by name, payable checks are performed, and the result is deserialized,
+    /// same as for a real transaction.
+    ///
+    /// If the transaction succeeds, `result_fn` receives the typed result.
+    /// It runs in a managed environment, so managed types can be inspected there.
+    pub fn execute_proxy_call<CB, ContractObjBuilder, Proxy, R, ProxyFn, ResultFn>(
+        &mut self,
+        caller: &Address,
+        sc_wrapper: &ContractObjWrapper<CB, ContractObjBuilder>,
+        proxy_fn: ProxyFn,
+        result_fn: ResultFn,
+    ) -> TxResult
+    where
+        CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+        ContractObjBuilder: 'static + Copy + Fn() -> CB,
+        Proxy: ProxyObjBase<Api = DebugApi>,
+        R: DynArg,
+        ProxyFn: FnOnce(Proxy) -> ContractCall<DebugApi, R>,
+        ResultFn: FnOnce(R),
+    {
+        let _ = DebugApi::dummy();
+        let proxy =
+            Proxy::new_proxy_obj().contract(ManagedAddress::from_address(sc_wrapper.address_ref()));
+        let tx_input = build_tx_input_from_contract_call(caller, proxy_fn(proxy));
+        let _ = TxContextStack::static_pop();
+
+        let tx_cache = TxCache::new(self.rc_b_mock.clone());
+        let (mut tx_result, blockchain_updates) = default_execution(tx_input, tx_cache);
+        if tx_result.result_status != 0 {
+            return tx_result;
+        }
+
+        tx_result.storage_diff.writes = blockchain_updates.storage_writes(&self.rc_b_mock);
+        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        blockchain_updates.apply(b_mock_ref);
+
+        self.execute_in_managed_environment(|| {
+            let mut raw_results = ManagedVec::<DebugApi, ManagedBuffer<DebugApi>>::new();
+            for result_value in tx_result.result_values.iter() {
+                raw_results.push(ManagedBuffer::new_from_bytes(result_value.as_slice()));
+            }
+            let mut loader = ManagedResultArgLoader::new(raw_results);
+            result_fn(R::dyn_load(
+                &mut loader,
+                ArgId::from(&b"proxy call result"[..]),
+            ));
+        });
+
+        tx_result
+    }
+
     // deduplicates code for execution
     fn execute_tx_any<CB, ContractObjBuilder, TxFn: FnOnce(CB) -> StateChange>(
         &mut self,
@@ -604,6 +726,9 @@ impl BlockchainStateWrapper {
         }
 
         for dct in &dct_payments {
+            if let Err(message) = check_dct_transfer_allowed(&tx_cache, caller, sc_address, dct) {
+                return TxResult::from_vm_error(message);
+            }
             if dct.value > rust_zero {
                 let metadata = tx_cache.subtract_dct_balance(
                     caller,
@@ -623,24 +748,28 @@ impl BlockchainStateWrapper {
 
         let tx_input = build_tx_input(caller, sc_address, moax_payment, dct_payments);
         let tx_context_rc = Rc::new(TxContext::new(tx_input, tx_cache));
-        TxContextStack::static_push(tx_context_rc);
+        TxContextStack::static_push_traced(tx_context_rc);
 
         let sc = (sc_wrapper.obj_builder)();
         let result_state_change =
             std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| tx_fn(sc)));
 
-        let api_after_exec = Rc::try_unwrap(TxContextStack::static_pop()).unwrap();
+        let (commit, mut tx_result) = match result_state_change {
+            Ok(StateChange::Commit) => (true, TxResult::empty()),
+            Ok(StateChange::Revert) => (false, TxResult::empty()),
+            Err(panic_any) => (false, interpret_panic_as_tx_result(panic_any)),
+        };
+
+        let api_after_exec = Rc::try_unwrap(TxContextStack::static_pop_traced(&tx_result)).unwrap();
+        tx_result.storage_diff = api_after_exec.extract_result().storage_diff;
         let updates = api_after_exec.into_blockchain_updates();
 
-        let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-        match result_state_change {
-            Ok(StateChange::Commit) => {
-                updates.apply(b_mock_ref);
-                TxResult::empty()
-            },
-            Ok(StateChange::Revert) => TxResult::empty(),
-            Err(panic_any) => interpret_panic_as_tx_result(panic_any),
+        if commit {
+            tx_result.storage_diff.writes = updates.storage_writes(&self.rc_b_mock);
+            let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+            updates.apply(b_mock_ref);
         }
+        tx_result
     }
 
     pub fn execute_in_managed_environment<Func: FnOnce()>(&self, f: Func) {
@@ -648,6 +777,22 @@ impl BlockchainStateWrapper {
         f();
         let _ = TxContextStack::static_pop();
     }
+
+    /// Starts recording every contract execution, including the nested ones.
+    /// Retrieve the recorded calls with `take_trace`.
+    pub fn enable_trace(&mut self) {
+        TxContextStack::static_start_trace();
+    }
+
+    pub fn disable_trace(&mut self) {
+        TxContextStack::static_stop_trace();
+    }
+
+    /// The calls executed since tracing was enabled, or since the last call to this method.
+    /// Render them with `to_call_tree` or `to_json`.
+    pub fn take_trace(&mut self) -> TxTrace {
+        TxContextStack::static_take_trace()
+    }
 }
 
 impl BlockchainStateWrapper {
@@ -714,9 +859,44 @@ fn build_tx_input(
         gas_limit: u64::MAX,
         gas_price: 0,
         tx_hash: H256::zero(),
+        ..Default::default()
     }
 }
 
+fn build_tx_input_from_contract_call<R>(
+    caller: &Address,
+    contract_call: ContractCall<DebugApi, R>,
+) -> TxInput {
+    let moax_payment = contract_call.get_moax_payment();
+    let mut moax_value = num_bigint::BigUint::from_bytes_be(moax_payment.to_bytes_be().as_slice());
+    let mut dct_values = Vec::new();
+    for payment in contract_call.get_dct_payments().iter() {
+        let amount = num_bigint::BigUint::from_bytes_be(payment.amount.to_bytes_be().as_slice());
+        if payment.token_identifier.is_moax() {
+            moax_value += amount;
+        } else {
+            dct_values.push(TxInputDCT {
+                token_identifier: payment.token_identifier.to_dct_identifier().into_vec(),
+                nonce: payment.token_nonce,
+                value: amount,
+            });
+        }
+    }
+
+    let mut tx_input = build_tx_input(
+        caller,
+        &contract_call.get_to().to_address(),
+        &moax_value,
+        dct_values,
+    );
+    tx_input.func_name = contract_call
+        .get_endpoint_name()
+        .to_boxed_bytes()
+        .into_vec();
+    tx_input.args = contract_call.get_arg_buffer().to_raw_args_vec();
+    tx_input
+}
+
 fn address_to_hex(address: &Address) -> String {
     hex::encode(address.as_bytes())
 }
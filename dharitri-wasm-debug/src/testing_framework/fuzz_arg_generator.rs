@@ -0,0 +1,365 @@
+use dharitri_wasm::{
+    abi::{EndpointAbi, TypeContents, TypeDescriptionContainerImpl},
+    types::{Address, TokenIdentifier},
+};
+use num_bigint::{BigInt, BigUint};
+use rand::{seq::SliceRandom, Rng};
+use rand_seeder::SipRng;
+
+use crate::DebugApi;
+
+/// Collections and variadic arguments get at most this many items.
+const MAX_ITEMS: usize = 3;
+
+/// Byte arrays and strings get at most this many bytes.
+const MAX_BYTES: usize = 16;
+
+const BYTE_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+const MOAX_TOKEN_ID: &[u8] = TokenIdentifier::<DebugApi>::MOAX_REPRESENTATION;
+
+/// Generates random serialized arguments from the type names in the contract ABI.
+///
+/// Values are biased towards the edge cases (zero, one, the maximum of the type)
+/// and towards the addresses and tokens known to the test, since those are the ones
+/// that get past the first checks of most endpoints.
+pub(crate) struct FuzzArgGenerator<'a> {
+    pub rng: &'a mut SipRng,
+    pub type_descriptions: &'a TypeDescriptionContainerImpl,
+    pub addresses: &'a [Address],
+    pub token_ids: &'a [Vec<u8>],
+    pub max_amount: &'a BigUint,
+}
+
+impl<'a> FuzzArgGenerator<'a> {
+    pub fn endpoint_args(&mut self, endpoint: &EndpointAbi) -> Vec<Vec<u8>> {
+        let mut args = Vec::new();
+        for input in &endpoint.inputs {
+            self.multi_args(&input.type_name, &mut args);
+        }
+        args
+    }
+
+    pub fn amount(&mut self) -> BigUint {
+        match self.rng.gen_range(0..6) {
+            0 => BigUint::from(0u32),
+            1 => BigUint::from(1u32),
+            2 => self.max_amount.clone(),
+            _ => self.biguint_below(self.max_amount),
+        }
+    }
+
+    fn biguint_below(&mut self, bound: &BigUint) -> BigUint {
+        if bound == &BigUint::from(0u32) {
+            return BigUint::from(0u32);
+        }
+        let mut bytes = bound.to_bytes_be();
+        self.rng.fill(&mut bytes[..]);
+        BigUint::from_bytes_be(&bytes) % bound
+    }
+
+    /// Multi-value types take up any number of arguments, everything else exactly one.
+    fn multi_args(&mut self, type_name: &str, args: &mut Vec<Vec<u8>>) {
+        let (base, type_args) = split_type_name(type_name);
+        match base {
+            "variadic" => {
+                for _ in 0..self.rng.gen_range(0..=MAX_ITEMS) {
+                    self.multi_args(type_args[0], args);
+                }
+            },
+            "counted-variadic" => {
+                let count = self.rng.gen_range(0..=MAX_ITEMS);
+                args.push(top_encode_unsigned(count as u64));
+                for _ in 0..count {
+                    self.multi_args(type_args[0], args);
+                }
+            },
+            "optional" => {
+                if self.rng.gen_bool(0.5) {
+                    self.multi_args(type_args[0], args);
+                }
+            },
+            "multi" => {
+                for type_arg in type_args {
+                    self.multi_args(type_arg, args);
+                }
+            },
+            "ignore" => {},
+            _ => args.push(self.top_encoded(type_name)),
+        }
+    }
+
+    fn top_encoded(&mut self, type_name: &str) -> Vec<u8> {
+        let (base, type_args) = split_type_name(type_name);
+        if let Some(num_bits) = unsigned_bits(base) {
+            return top_encode_unsigned(self.unsigned(num_bits));
+        }
+        if let Some(num_bits) = signed_bits(base) {
+            return top_encode_signed(self.signed(num_bits));
+        }
+        match base {
+            "bool" => {
+                if self.rng.gen_bool(0.5) {
+                    vec![1]
+                } else {
+                    Vec::new()
+                }
+            },
+            "BigUint" => self
+                .amount()
+                .to_bytes_be()
+                .into_iter()
+                .skip_while(|b| *b == 0)
+                .collect(),
+            "BigInt" => {
+                let magnitude = BigInt::from(self.amount());
+                let value = if self.rng.gen_bool(0.5) {
+                    -magnitude
+                } else {
+                    magnitude
+                };
+                top_encode_bigint(&value)
+            },
+            "Address" | "H256" | "TokenIdentifier" | "bytes" | "utf-8 string" => {
+                self.raw_bytes(base)
+            },
+            "List" => {
+                let mut result = Vec::new();
+                for _ in 0..self.rng.gen_range(0..=MAX_ITEMS) {
+                    self.nested_encoded(type_args[0], &mut result);
+                }
+                result
+            },
+            "Option" => {
+                let mut result = Vec::new();
+                if self.rng.gen_bool(0.5) {
+                    result.push(1);
+                    self.nested_encoded(type_args[0], &mut result);
+                }
+                result
+            },
+            _ => {
+                if let Some(variant_index) = self.fieldless_enum_variant(type_name) {
+                    return top_encode_unsigned(variant_index as u64);
+                }
+                let mut result = Vec::new();
+                self.nested_encoded(type_name, &mut result);
+                result
+            },
+        }
+    }
+
+    fn nested_encoded(&mut self, type_name: &str, dest: &mut Vec<u8>) {
+        let (base, type_args) = split_type_name(type_name);
+        if let Some(num_bits) = unsigned_bits(base) {
+            let bytes = self.unsigned(num_bits).to_be_bytes();
+            dest.extend_from_slice(&bytes[8 - num_bits / 8..]);
+            return;
+        }
+        if let Some(num_bits) = signed_bits(base) {
+            let bytes = self.signed(num_bits).to_be_bytes();
+            dest.extend_from_slice(&bytes[8 - num_bits / 8..]);
+            return;
+        }
+        match base {
+            "bool" => dest.push(self.rng.gen_range(0..=1)),
+            "Address" | "H256" => dest.extend_from_slice(&self.raw_bytes(base)),
+            "BigUint" | "BigInt" | "TokenIdentifier" | "bytes" | "utf-8 string" => {
+                let bytes = self.top_encoded(base);
+                push_length(bytes.len(), dest);
+                dest.extend_from_slice(&bytes);
+            },
+            "List" => {
+                let count = self.rng.gen_range(0..=MAX_ITEMS);
+                push_length(count, dest);
+                for _ in 0..count {
+                    self.nested_encoded(type_args[0], dest);
+                }
+            },
+            "Option" => {
+                if self.rng.gen_bool(0.5) {
+                    dest.push(1);
+                    self.nested_encoded(type_args[0], dest);
+                } else {
+                    dest.push(0);
+                }
+            },
+            "tuple" => {
+                for type_arg in type_args {
+                    self.nested_encoded(type_arg, dest);
+                }
+            },
+            _ if base.starts_with("array") => {
+                let len: usize = base["array".len()..].parse().unwrap_or(0);
+                for _ in 0..len {
+                    self.nested_encoded(type_args[0], dest);
+                }
+            },
+            _ => self.nested_encoded_described(type_name, dest),
+        }
+    }
+
+    /// Structs and enums, as described in the ABI. Anything else gets random bytes.
+    fn nested_encoded_described(&mut self, type_name: &str, dest: &mut Vec<u8>) {
+        let contents = self
+            .type_descriptions
+            .0
+            .get(type_name)
+            .map(|description| description.contents.clone());
+        match contents {
+            Some(TypeContents::Struct(fields)) => {
+                for field in fields {
+                    self.nested_encoded(&field.field_type, dest);
+                }
+            },
+            Some(TypeContents::Enum(variants)) if !variants.is_empty() => {
+                let variant = variants.choose(self.rng).unwrap();
+                dest.push(variant.discriminant as u8);
+                for field in &variant.fields {
+                    self.nested_encoded(&field.field_type, dest);
+                }
+            },
+            _ => {
+                let bytes = self.raw_bytes("bytes");
+                push_length(bytes.len(), dest);
+                dest.extend_from_slice(&bytes);
+            },
+        }
+    }
+
+    /// Enums without fields are top-encoded as their discriminant.
+    fn fieldless_enum_variant(&mut self, type_name: &str) -> Option<usize> {
+        match &self.type_descriptions.0.get(type_name)?.contents {
+            TypeContents::Enum(variants)
+                if !variants.is_empty() && variants.iter().all(|v| v.fields.is_empty()) =>
+            {
+                Some(variants.choose(self.rng).unwrap().discriminant)
+            },
+            _ => None,
+        }
+    }
+
+    fn raw_bytes(&mut self, base: &str) -> Vec<u8> {
+        match base {
+            "Address" if !self.addresses.is_empty() && self.rng.gen_bool(0.9) => {
+                self.addresses.choose(self.rng).unwrap().as_bytes().to_vec()
+            },
+            "Address" | "H256" => {
+                let mut bytes = vec![0u8; 32];
+                self.rng.fill(&mut bytes[..]);
+                bytes
+            },
+            "TokenIdentifier" => match self.rng.gen_range(0..4) {
+                0 => MOAX_TOKEN_ID.to_vec(),
+                1 => b"FUZZ-123456".to_vec(),
+                _ => self
+                    .token_ids
+                    .choose(self.rng)
+                    .cloned()
+                    .unwrap_or_else(|| MOAX_TOKEN_ID.to_vec()),
+            },
+            _ => {
+                let len = self.rng.gen_range(0..=MAX_BYTES);
+                (0..len)
+                    .map(|_| *BYTE_CHARS.choose(self.rng).unwrap())
+                    .collect()
+            },
+        }
+    }
+
+    fn unsigned(&mut self, num_bits: usize) -> u64 {
+        let max = u64::MAX >> (64 - num_bits);
+        match self.rng.gen_range(0..5) {
+            0 => 0,
+            1 => 1,
+            2 => max,
+            3 => self.rng.gen_range(0..=max.min(100)),
+            _ => self.rng.gen_range(0..=max),
+        }
+    }
+
+    fn signed(&mut self, num_bits: usize) -> i64 {
+        let max = i64::MAX >> (64 - num_bits);
+        let min = -max - 1;
+        match self.rng.gen_range(0..6) {
+            0 => 0,
+            1 => 1,
+            2 => -1,
+            3 => max,
+            4 => min,
+            _ => self.rng.gen_range(min..=max),
+        }
+    }
+}
+
+/// `List<tuple<u32,bytes>>` is split into `List` and `[tuple<u32,bytes>]`.
+fn split_type_name(type_name: &str) -> (&str, Vec<&str>) {
+    let type_name = type_name.trim();
+    let open = match type_name.find('<') {
+        Some(open) if type_name.ends_with('>') => open,
+        _ => return (type_name, Vec::new()),
+    };
+    let inner = &type_name[open + 1..type_name.len() - 1];
+
+    let mut type_args = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                type_args.push(inner[start..i].trim());
+                start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    type_args.push(inner[start..].trim());
+    (&type_name[..open], type_args)
+}
+
+fn unsigned_bits(base: &str) -> Option<usize> {
+    match base {
+        "u8" => Some(8),
+        "u16" => Some(16),
+        "u32" | "usize" => Some(32),
+        "u64" => Some(64),
+        _ => None,
+    }
+}
+
+fn signed_bits(base: &str) -> Option<usize> {
+    match base {
+        "i8" => Some(8),
+        "i16" => Some(16),
+        "i32" | "isize" => Some(32),
+        "i64" => Some(64),
+        _ => None,
+    }
+}
+
+fn top_encode_unsigned(value: u64) -> Vec<u8> {
+    value
+        .to_be_bytes()
+        .iter()
+        .copied()
+        .skip_while(|b| *b == 0)
+        .collect()
+}
+
+fn top_encode_signed(value: i64) -> Vec<u8> {
+    top_encode_bigint(&BigInt::from(value))
+}
+
+fn top_encode_bigint(value: &BigInt) -> Vec<u8> {
+    if value == &BigInt::from(0) {
+        Vec::new()
+    } else {
+        value.to_signed_bytes_be()
+    }
+}
+
+fn push_length(len: usize, dest: &mut Vec<u8>) {
+    dest.extend_from_slice(&(len as u32).to_be_bytes());
+}
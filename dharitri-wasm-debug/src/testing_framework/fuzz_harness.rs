@@ -0,0 +1,454 @@
+use std::fmt;
+
+use dharitri_wasm::{
+    abi::{ContractAbi, EndpointAbi},
+    types::Address,
+};
+use num_bigint::BigUint;
+use rand::{seq::SliceRandom, Rng};
+use rand_seeder::{SipHasher, SipRng};
+
+use crate::{
+    rust_biguint,
+    tx_execution::sc_call_with_async_and_callback,
+    tx_mock::{generate_tx_hash_dummy, TxInput, TxInputDCT, TxResult, UNHANDLED_PANIC_MESSAGE},
+    verbose_hex,
+};
+
+use super::{
+    fuzz_arg_generator::FuzzArgGenerator, BlockchainStateWrapper, ScCallDenali, TxExpectDenali,
+};
+
+/// The state each fuzzed call sequence starts from. Created anew for every sequence.
+pub struct FuzzWorld<T> {
+    pub wrapper: BlockchainStateWrapper,
+    /// The contract whose endpoints get called.
+    pub contract_address: Address,
+    /// Each call is sent by one of these accounts, along with payments from their balance.
+    pub callers: Vec<Address>,
+    /// Anything else the invariants need, e.g. the contract wrapper.
+    pub data: T,
+}
+
+/// One call in a fuzzed sequence. The arguments are already serialized.
+#[derive(Clone, Debug)]
+pub struct FuzzStep {
+    pub caller: Address,
+    pub endpoint: String,
+    pub args: Vec<Vec<u8>>,
+    pub moax_value: BigUint,
+    pub dct_values: Vec<TxInputDCT>,
+}
+
+impl fmt::Display for FuzzStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args: Vec<String> = self.args.iter().map(|arg| verbose_hex(arg)).collect();
+        write!(
+            f,
+            "{} from {} args [{}]",
+            self.endpoint,
+            verbose_hex(self.caller.as_bytes()),
+            args.join(", ")
+        )?;
+        if self.moax_value != rust_biguint!(0) {
+            write!(f, " moax {}", self.moax_value)?;
+        }
+        for dct in &self.dct_values {
+            write!(
+                f,
+                " dct {} nonce {} amount {}",
+                String::from_utf8_lossy(&dct.token_identifier),
+                dct.nonce,
+                dct.value
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A call sequence after which an invariant no longer holds, already shrunk.
+#[derive(Clone, Debug)]
+pub struct FuzzFailure {
+    pub invariant: String,
+    pub message: String,
+    pub steps: Vec<FuzzStep>,
+}
+
+impl fmt::Display for FuzzFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Invariant \"{}\" broken after {} call(s): {}",
+            self.invariant,
+            self.steps.len(),
+            self.message
+        )?;
+        for (index, step) in self.steps.iter().enumerate() {
+            writeln!(f, "  {}: {}", index, step)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checked after each call. Returns an error message if the invariant is broken.
+pub type FuzzInvariant<T> = Box<dyn Fn(&mut FuzzWorld<T>) -> Result<(), String>>;
+
+enum ReplayOutcome {
+    Passed,
+    Failed(FuzzFailure),
+    /// A caller cannot afford one of the payments, can happen when shrinking.
+    Invalid,
+}
+
+/// Calls the endpoints of a contract in random sequences, with random arguments, callers and payments,
+/// and checks the invariants after each call.
+///
+/// The arguments are generated from the type names in the contract ABI.
+/// The run is deterministic for a given seed.
+/// Failing sequences are shrunk before being reported:
+/// first calls are removed, then arguments are replaced with empty values and payments dropped,
+/// for as long as the same invariant still breaks.
+pub struct ContractFuzzer<T> {
+    abi: ContractAbi,
+    setup: Box<dyn Fn() -> FuzzWorld<T>>,
+    invariants: Vec<(String, FuzzInvariant<T>)>,
+    seed: u64,
+    num_sequences: usize,
+    sequence_len: usize,
+    payment_tokens: Vec<(Vec<u8>, u64)>,
+    max_amount: BigUint,
+    excluded_endpoints: Vec<String>,
+    allow_unhandled_panics: bool,
+}
+
+impl<T> ContractFuzzer<T> {
+    pub fn new<F: Fn() -> FuzzWorld<T> + 'static>(abi: ContractAbi, setup: F) -> Self {
+        ContractFuzzer {
+            abi,
+            setup: Box::new(setup),
+            invariants: Vec::new(),
+            seed: 0,
+            num_sequences: 100,
+            sequence_len: 20,
+            payment_tokens: Vec::new(),
+            max_amount: rust_biguint!(1_000_000),
+            excluded_endpoints: Vec::new(),
+            allow_unhandled_panics: false,
+        }
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    pub fn set_num_sequences(&mut self, num_sequences: usize) {
+        self.num_sequences = num_sequences;
+    }
+
+    pub fn set_sequence_len(&mut self, sequence_len: usize) {
+        self.sequence_len = sequence_len;
+    }
+
+    /// Payable endpoints get paid in MOAX or in one of these tokens, if the callers hold any.
+    pub fn add_payment_token(&mut self, token_id: &[u8], nonce: u64) {
+        self.payment_tokens.push((token_id.to_vec(), nonce));
+    }
+
+    /// Upper bound for the generated `BigUint` arguments and payments.
+    pub fn set_max_amount(&mut self, max_amount: &BigUint) {
+        self.max_amount = max_amount.clone();
+    }
+
+    pub fn exclude_endpoint(&mut self, endpoint_name: &str) {
+        self.excluded_endpoints.push(endpoint_name.to_string());
+    }
+
+    pub fn add_invariant<F>(&mut self, name: &str, invariant: F)
+    where
+        F: Fn(&mut FuzzWorld<T>) -> Result<(), String> + 'static,
+    {
+        self.invariants
+            .push((name.to_string(), Box::new(invariant)));
+    }
+
+    /// By default, Rust panics in the contract code count as failures, same as a broken invariant.
+    pub fn allow_unhandled_panics(&mut self) {
+        self.allow_unhandled_panics = true;
+    }
+
+    /// Runs all the sequences, stops at the first failure and returns it, shrunk.
+    pub fn run(&self) -> Option<FuzzFailure> {
+        let endpoints = self.fuzzed_endpoints();
+        assert!(!endpoints.is_empty(), "no endpoints to fuzz");
+
+        let mut rng = SipHasher::from(self.seed).into_rng();
+        for _ in 0..self.num_sequences {
+            if let Some(failure) = self.run_random_sequence(&mut rng, &endpoints) {
+                return Some(self.shrink(failure));
+            }
+        }
+        None
+    }
+
+    /// Runs the given sequence from a fresh world, e.g. one saved from an earlier failure.
+    pub fn replay(&self, steps: &[FuzzStep]) -> Option<FuzzFailure> {
+        match self.replay_outcome(steps) {
+            ReplayOutcome::Failed(failure) => Some(failure),
+            ReplayOutcome::Passed | ReplayOutcome::Invalid => None,
+        }
+    }
+
+    /// Saves the sequence as a Denali scenario, starting with the state created by the setup.
+    /// The file name is relative to the `denali` folder, same as for `write_denali_output`.
+    pub fn export_denali(&self, steps: &[FuzzStep], file_name: &str) {
+        let mut world = (self.setup)();
+        for (index, step) in steps.iter().enumerate() {
+            let tx_result = execute_step(&mut world, step, index);
+
+            let mut sc_call =
+                ScCallDenali::new(&step.caller, &world.contract_address, &step.endpoint);
+            sc_call.add_moax_value(&step.moax_value);
+            for dct in &step.dct_values {
+                sc_call.add_dct_transfer(&dct.token_identifier, dct.nonce, &dct.value);
+            }
+            sc_call.arguments = step.args.clone();
+            let mut expect = TxExpectDenali::new(tx_result.result_status);
+            expect.set_message(&tx_result.result_message);
+            world.wrapper.add_denali_sc_call(sc_call, Some(expect));
+        }
+        world
+            .wrapper
+            .add_denali_check_account(&world.contract_address);
+        world.wrapper.write_denali_output(file_name);
+    }
+
+    fn fuzzed_endpoints(&self) -> Vec<&EndpointAbi> {
+        self.abi
+            .endpoints
+            .iter()
+            .filter(|endpoint| {
+                !self
+                    .excluded_endpoints
+                    .iter()
+                    .any(|excluded| excluded == endpoint.name)
+            })
+            .collect()
+    }
+
+    fn run_random_sequence(
+        &self,
+        rng: &mut SipRng,
+        endpoints: &[&EndpointAbi],
+    ) -> Option<FuzzFailure> {
+        let mut world = (self.setup)();
+        let mut addresses = world.callers.clone();
+        addresses.push(world.contract_address.clone());
+        let token_ids: Vec<Vec<u8>> = self
+            .payment_tokens
+            .iter()
+            .map(|(token_id, _)| token_id.clone())
+            .collect();
+
+        let mut steps = Vec::new();
+        for index in 0..self.sequence_len {
+            let endpoint = *endpoints.choose(rng).unwrap();
+            let caller = world.callers.choose(rng).unwrap().clone();
+            let mut generator = FuzzArgGenerator {
+                rng,
+                type_descriptions: &self.abi.type_descriptions,
+                addresses: &addresses,
+                token_ids: &token_ids,
+                max_amount: &self.max_amount,
+            };
+            let args = generator.endpoint_args(endpoint);
+            let (moax_value, dct_values) =
+                self.random_payment(&mut generator, &world, &caller, endpoint);
+
+            let step = FuzzStep {
+                caller,
+                endpoint: endpoint.name.to_string(),
+                args,
+                moax_value,
+                dct_values,
+            };
+            let tx_result = execute_step(&mut world, &step, index);
+            steps.push(step);
+
+            if let Some((invariant, message)) = self.check(&mut world, &tx_result) {
+                return Some(FuzzFailure {
+                    invariant,
+                    message,
+                    steps,
+                });
+            }
+        }
+        None
+    }
+
+    /// Only payable endpoints get paid, and never more than the caller holds.
+    fn random_payment(
+        &self,
+        generator: &mut FuzzArgGenerator,
+        world: &FuzzWorld<T>,
+        caller: &Address,
+        endpoint: &EndpointAbi,
+    ) -> (BigUint, Vec<TxInputDCT>) {
+        let accepts = |token_id: &[u8]| {
+            endpoint
+                .payable_in_tokens
+                .iter()
+                .any(|payable| *payable == "*" || payable.as_bytes() == token_id)
+        };
+
+        let mut options: Vec<Option<&(Vec<u8>, u64)>> = vec![None];
+        if accepts(b"MOAX") {
+            options.push(None);
+        }
+        for token in &self.payment_tokens {
+            if accepts(&token.0) {
+                options.push(Some(token));
+            }
+        }
+        if options.len() == 1 || generator.rng.gen_bool(0.3) {
+            return (rust_biguint!(0), Vec::new());
+        }
+
+        let amount = generator.amount();
+        match options[generator.rng.gen_range(1..options.len())] {
+            None => {
+                let balance = world.wrapper.get_moax_balance(caller);
+                (amount.min(balance), Vec::new())
+            },
+            Some((token_id, nonce)) => {
+                let balance = world.wrapper.get_dct_balance(caller, token_id, *nonce);
+                let dct = TxInputDCT {
+                    token_identifier: token_id.clone(),
+                    nonce: *nonce,
+                    value: amount.min(balance),
+                };
+                (rust_biguint!(0), vec![dct])
+            },
+        }
+    }
+
+    /// Returns the name of the first broken invariant and its message.
+    fn check(&self, world: &mut FuzzWorld<T>, tx_result: &TxResult) -> Option<(String, String)> {
+        if !self.allow_unhandled_panics && tx_result.result_message == UNHANDLED_PANIC_MESSAGE {
+            return Some((
+                "no unhandled panics".to_string(),
+                format!(
+                    "status {}: {}",
+                    tx_result.result_status, tx_result.result_message
+                ),
+            ));
+        }
+        for (name, invariant) in &self.invariants {
+            if let Err(message) = invariant(world) {
+                return Some((name.clone(), message));
+            }
+        }
+        None
+    }
+
+    fn replay_outcome(&self, steps: &[FuzzStep]) -> ReplayOutcome {
+        let mut world = (self.setup)();
+        for (index, step) in steps.iter().enumerate() {
+            if !can_afford(&world, step) {
+                return ReplayOutcome::Invalid;
+            }
+            let tx_result = execute_step(&mut world, step, index);
+            if let Some((invariant, message)) = self.check(&mut world, &tx_result) {
+                return ReplayOutcome::Failed(FuzzFailure {
+                    invariant,
+                    message,
+                    steps: steps[..=index].to_vec(),
+                });
+            }
+        }
+        ReplayOutcome::Passed
+    }
+
+    /// Accepts a simplified sequence only if it breaks the same invariant.
+    fn try_simplified(&self, failure: &FuzzFailure, steps: &[FuzzStep]) -> Option<FuzzFailure> {
+        match self.replay_outcome(steps) {
+            ReplayOutcome::Failed(new_failure) if new_failure.invariant == failure.invariant => {
+                Some(new_failure)
+            },
+            _ => None,
+        }
+    }
+
+    fn shrink(&self, mut failure: FuzzFailure) -> FuzzFailure {
+        let mut progress = true;
+        while progress {
+            progress = false;
+
+            let mut index = 0;
+            while index < failure.steps.len() {
+                let mut steps = failure.steps.clone();
+                steps.remove(index);
+                if let Some(smaller) = self.try_simplified(&failure, &steps) {
+                    failure = smaller;
+                    progress = true;
+                } else {
+                    index += 1;
+                }
+            }
+
+            for step_index in 0..failure.steps.len() {
+                let step = &failure.steps[step_index];
+                if step.moax_value != rust_biguint!(0) || !step.dct_values.is_empty() {
+                    let mut steps = failure.steps.clone();
+                    steps[step_index].moax_value = rust_biguint!(0);
+                    steps[step_index].dct_values.clear();
+                    if let Some(smaller) = self.try_simplified(&failure, &steps) {
+                        failure = smaller;
+                        progress = true;
+                    }
+                }
+
+                for arg_index in 0..failure.steps[step_index].args.len() {
+                    if failure.steps[step_index].args[arg_index].is_empty() {
+                        continue;
+                    }
+                    let mut steps = failure.steps.clone();
+                    steps[step_index].args[arg_index].clear();
+                    if let Some(smaller) = self.try_simplified(&failure, &steps) {
+                        failure = smaller;
+                        progress = true;
+                    }
+                }
+            }
+        }
+        failure
+    }
+}
+
+fn can_afford<T>(world: &FuzzWorld<T>, step: &FuzzStep) -> bool {
+    if world.wrapper.get_moax_balance(&step.caller) < step.moax_value {
+        return false;
+    }
+    step.dct_values.iter().all(|dct| {
+        world
+            .wrapper
+            .get_dct_balance(&step.caller, &dct.token_identifier, dct.nonce)
+            >= dct.value
+    })
+}
+
+/// Same as a Denali `scCall` step, so that the exported scenario behaves the same.
+fn execute_step<T>(world: &mut FuzzWorld<T>, step: &FuzzStep, index: usize) -> TxResult {
+    let tx_input = TxInput {
+        from: step.caller.clone(),
+        to: world.contract_address.clone(),
+        moax_value: step.moax_value.clone(),
+        dct_values: step.dct_values.clone(),
+        func_name: step.endpoint.as_bytes().to_vec(),
+        args: step.args.clone(),
+        gas_limit: u64::MAX,
+        gas_price: 0,
+        tx_hash: generate_tx_hash_dummy(&index.to_string()),
+        ..Default::default()
+    };
+    sc_call_with_async_and_callback(tx_input, world.wrapper.get_mut_state(), true)
+}
@@ -1,5 +1,7 @@
 mod address_factory;
 mod contract_obj_wrapper;
+mod fuzz_arg_generator;
+mod fuzz_harness;
 mod helper_macros;
 mod denali_generator;
 mod raw_converter;
@@ -7,6 +9,7 @@ mod tx_denali;
 
 use address_factory::*;
 pub use contract_obj_wrapper::*;
+pub use fuzz_harness::*;
 pub use helper_macros::*;
 use denali_generator::*;
 pub use tx_denali::*;
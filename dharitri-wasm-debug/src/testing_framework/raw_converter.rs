@@ -19,6 +19,10 @@ pub(crate) fn account_as_raw(acc: &AccountData) -> AccountRaw {
         .contract_path
         .clone()
         .map(|c| ValueSubTree::Str(String::from_utf8(c).unwrap()));
+    let code_metadata = acc
+        .contract_path
+        .as_ref()
+        .map(|_| bytes_as_raw(&acc.code_metadata.to_byte_array()));
 
     let mut all_dct_raw = BTreeMap::new();
     for (token_id, dct_data) in acc.dct.iter() {
@@ -39,6 +43,7 @@ pub(crate) fn account_as_raw(acc: &AccountData) -> AccountRaw {
     AccountRaw {
         balance: balance_raw,
         code: code_raw,
+        code_metadata,
         comment: None,
         dct: all_dct_raw,
         nonce: Some(u64_as_raw(acc.nonce)),
@@ -70,7 +75,7 @@ pub(crate) fn dct_data_as_raw(dct: &DctData) -> DctRaw {
             hash: inst.metadata.hash.as_ref().map(|h| bytes_as_raw(h)),
             nonce: Some(u64_as_raw(inst.nonce)),
             royalties: Some(u64_as_raw(inst.metadata.royalties)),
-            uri: inst.metadata.uri.as_ref().map(|u| bytes_as_raw(u)),
+            uri: inst.metadata.uris.first().map(|u| bytes_as_raw(u)),
         };
 
         instances_raw.push(inst_raw);
@@ -237,6 +242,7 @@ pub(crate) fn account_as_check_state_raw(acc: &AccountData) -> CheckAccountsRaw
         owner: CheckBytesValueRaw::Star, // TODO: Add owner check?
         storage: CheckStorageRaw::Equal(check_storage_raw),
         code: CheckBytesValueRaw::Star,
+        code_metadata: CheckBytesValueRaw::Unspecified,
         async_call_data: CheckBytesValueRaw::Unspecified,
         comment: None,
         username: CheckBytesValueRaw::Unspecified,
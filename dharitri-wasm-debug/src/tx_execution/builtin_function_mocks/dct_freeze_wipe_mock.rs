@@ -0,0 +1,123 @@
+use dharitri_wasm::{
+    api::{DCT_FREEZE_FUNC_NAME, DCT_UNFREEZE_FUNC_NAME, DCT_WIPE_FUNC_NAME},
+    dharitri_codec::TopDecode,
+    types::Address,
+};
+
+use crate::{
+    tx_execution::is_system_sc_address,
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    world_mock::DctInstances,
+};
+
+use super::dct_nft_update_attributes_mock::NFT_NOT_FOUND_ERR_MSG;
+
+pub fn execute_dct_freeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    execute_freeze_or_wipe(
+        DCT_FREEZE_FUNC_NAME,
+        tx_input,
+        tx_cache,
+        &|tx_cache, address, token_identifier, nonce| {
+            freeze_dct(tx_cache, address, token_identifier, nonce, true)
+        },
+    )
+}
+
+pub fn execute_dct_unfreeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    execute_freeze_or_wipe(
+        DCT_UNFREEZE_FUNC_NAME,
+        tx_input,
+        tx_cache,
+        &|tx_cache, address, token_identifier, nonce| {
+            freeze_dct(tx_cache, address, token_identifier, nonce, false)
+        },
+    )
+}
+
+pub fn execute_dct_wipe(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    execute_freeze_or_wipe(DCT_WIPE_FUNC_NAME, tx_input, tx_cache, &wipe_dct)
+}
+
+/// Args: token identifier, optionally followed by a nonce, to only target a single NFT.
+///
+/// These builtin functions act on the destination account and can only be called by the DCT system SC.
+fn execute_freeze_or_wipe(
+    func_name: &[u8],
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    f: &dyn Fn(&TxCache, &Address, &[u8], u64) -> Result<(), String>,
+) -> (TxResult, BlockchainUpdate) {
+    let func_name = String::from_utf8_lossy(func_name);
+    if !is_system_sc_address(&tx_input.from) {
+        let err_result = TxResult::from_vm_error(format!(
+            "{} can only be called by the DCT system SC",
+            func_name
+        ));
+        return (err_result, BlockchainUpdate::empty());
+    }
+    if tx_input.args.is_empty() || tx_input.args.len() > 2 {
+        let err_result = TxResult::from_vm_error(format!("{} expects 1 or 2 arguments", func_name));
+        return (err_result, BlockchainUpdate::empty());
+    }
+
+    let token_identifier = tx_input.args[0].as_slice();
+    let nonce = tx_input
+        .args
+        .get(1)
+        .map(|nonce_arg| u64::top_decode(nonce_arg.as_slice()).unwrap())
+        .unwrap_or_default();
+
+    match f(&tx_cache, &tx_input.to, token_identifier, nonce) {
+        Ok(()) => (TxResult::empty(), tx_cache.into_blockchain_updates()),
+        Err(message) => (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    }
+}
+
+/// Nonce 0 freezes or unfreezes the whole token for the account, any other nonce only that NFT.
+pub fn freeze_dct(
+    tx_cache: &TxCache,
+    address: &Address,
+    token_identifier: &[u8],
+    nonce: u64,
+    frozen: bool,
+) -> Result<(), String> {
+    let found = tx_cache.with_account_mut(address, |account| {
+        if nonce == 0 {
+            account.dct.set_frozen(token_identifier.to_vec(), frozen);
+            true
+        } else {
+            account.dct.set_nft_frozen(token_identifier, nonce, frozen)
+        }
+    });
+    if !found {
+        return Err(NFT_NOT_FOUND_ERR_MSG.to_string());
+    }
+    Ok(())
+}
+
+/// Nonce 0 wipes the entire balance of the token, any other nonce only that NFT.
+/// Only frozen tokens can be wiped.
+pub fn wipe_dct(
+    tx_cache: &TxCache,
+    address: &Address,
+    token_identifier: &[u8],
+    nonce: u64,
+) -> Result<(), String> {
+    let frozen = tx_cache.with_account(address, |account| {
+        account.dct.is_frozen(token_identifier, nonce)
+    });
+    if !frozen {
+        return Err("cannot wipe because the account is not frozen for this token".to_string());
+    }
+
+    tx_cache.with_account_mut(address, |account| {
+        if let Some(dct_data) = account.dct.get_mut_by_identifier(token_identifier) {
+            if nonce == 0 {
+                dct_data.instances = DctInstances::new();
+            } else {
+                dct_data.instances.remove_instance(nonce);
+            }
+        }
+    });
+    Ok(())
+}
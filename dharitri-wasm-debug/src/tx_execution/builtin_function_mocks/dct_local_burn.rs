@@ -27,6 +27,7 @@ pub fn execute_local_burn(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Bl
         result_values: Vec::new(),
         result_logs: vec![dct_nft_create_log],
         result_calls: TxResultCalls::empty(),
+        ..TxResult::empty()
     };
 
     (tx_result, tx_cache.into_blockchain_updates())
@@ -48,6 +48,7 @@ pub fn execute_nft_add_quantity(
         result_values: Vec::new(),
         result_logs: vec![dct_nft_create_log],
         result_calls: TxResultCalls::empty(),
+        ..TxResult::empty()
     };
 
     (tx_result, tx_cache.into_blockchain_updates())
@@ -28,7 +28,7 @@ pub fn execute_dct_nft_create(
     let royalties = u64::top_decode(tx_input.args[3].as_slice()).unwrap();
     let hash = tx_input.args[4].clone();
     let attributes = tx_input.args[5].clone();
-    let uri = tx_input.args[6].clone();
+    let uris = tx_input.args[6..].to_vec();
 
     let new_nonce = tx_cache.with_account_mut(&tx_input.to, |account| {
         let dct_data = account
@@ -44,9 +44,10 @@ pub fn execute_dct_nft_create(
                 creator: Some(tx_input.from.clone()),
                 royalties,
                 hash: Some(hash),
-                uri: Some(uri),
+                uris,
                 attributes,
             },
+            frozen: false,
         });
 
         dct_data.last_nonce
@@ -70,6 +71,7 @@ pub fn execute_dct_nft_create(
         result_values: vec![top_encode_to_vec_u8(&new_nonce).unwrap()],
         result_logs: vec![dct_nft_create_log],
         result_calls: TxResultCalls::empty(),
+        ..TxResult::empty()
     };
 
     (tx_result, tx_cache.into_blockchain_updates())
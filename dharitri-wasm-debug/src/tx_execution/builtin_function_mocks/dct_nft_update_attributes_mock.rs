@@ -0,0 +1,55 @@
+use dharitri_wasm::{api::DCT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME, dharitri_codec::TopDecode};
+
+use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult, TxResultCalls};
+
+pub(super) const NFT_NOT_FOUND_ERR_MSG: &str = "the account does not hold this NFT";
+
+pub fn execute_nft_update_attributes(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() != 3 {
+        let err_result =
+            TxResult::from_vm_error("DCTNFTUpdateAttributes expects 3 arguments".to_string());
+        return (err_result, BlockchainUpdate::empty());
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let nonce = u64::top_decode(tx_input.args[1].as_slice()).unwrap();
+    let attributes = tx_input.args[2].clone();
+
+    let found = tx_cache.with_account_mut(&tx_input.to, |account| {
+        let opt_instance = account
+            .dct
+            .get_mut_by_identifier(&token_identifier)
+            .and_then(|dct_data| dct_data.instances.get_mut_by_nonce(nonce));
+        if let Some(instance) = opt_instance {
+            instance.metadata.attributes = attributes.clone();
+            true
+        } else {
+            false
+        }
+    });
+    if !found {
+        let err_result = TxResult::from_vm_error(NFT_NOT_FOUND_ERR_MSG.to_string());
+        return (err_result, BlockchainUpdate::empty());
+    }
+
+    let dct_nft_update_attributes_log = TxLog {
+        address: tx_input.from,
+        endpoint: DCT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME.to_vec(),
+        topics: vec![token_identifier, tx_input.args[1].clone()],
+        data: attributes,
+    };
+
+    let tx_result = TxResult {
+        result_status: 0,
+        result_message: String::new(),
+        result_values: Vec::new(),
+        result_logs: vec![dct_nft_update_attributes_log],
+        result_calls: TxResultCalls::empty(),
+        ..TxResult::empty()
+    };
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
@@ -1,14 +1,19 @@
 mod builtin_func_exec;
 mod change_owner_mock;
+mod dct_freeze_wipe_mock;
 mod dct_local_burn;
 mod dct_local_mint;
 mod dct_multi_transfer_mock;
 mod dct_nft_add_quantity_mock;
+mod dct_nft_add_uri_mock;
 mod dct_nft_burn_mock;
 mod dct_nft_create_mock;
 mod dct_nft_transfer_mock;
+mod dct_nft_update_attributes_mock;
 mod dct_transfer_mock;
+mod save_key_value_mock;
 mod set_username_mock;
 mod upgrade_contract;
 
 pub use builtin_func_exec::execute_builtin_function_or_default;
+pub use dct_freeze_wipe_mock::{freeze_dct, wipe_dct};
@@ -0,0 +1,41 @@
+use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult};
+
+const DHARITRI_RESERVED_PREFIX: &[u8] = b"DHARITRI";
+
+/// Args: key-value pairs, written directly to the storage of the caller's own account.
+pub fn execute_save_key_value(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if tx_input.from != tx_input.to {
+        return (
+            TxResult::from_vm_error("operation in account not permitted".to_string()),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let pairs = tx_input.args.chunks_exact(2);
+    if tx_input.args.is_empty() || !pairs.remainder().is_empty() {
+        return (
+            TxResult::from_vm_error("SaveKeyValue expects key-value pairs".to_string()),
+            BlockchainUpdate::empty(),
+        );
+    }
+    if pairs
+        .clone()
+        .any(|pair| pair[0].starts_with(DHARITRI_RESERVED_PREFIX))
+    {
+        return (
+            TxResult::from_vm_error("operation in account not permitted".to_string()),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    tx_cache.with_account_mut(&tx_input.to, |account| {
+        for pair in pairs {
+            account.storage.insert(pair[0].clone(), pair[1].clone());
+        }
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
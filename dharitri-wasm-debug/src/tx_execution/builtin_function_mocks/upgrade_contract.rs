@@ -1,3 +1,5 @@
+use dharitri_wasm::{dharitri_codec::TopDecode, types::CodeMetadata};
+
 use crate::{
     tx_execution::default_execution,
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
@@ -15,9 +17,25 @@ pub fn execute_upgrade_contract(
     }
 
     let new_code = tx_input.args[0].clone();
+    let new_code_metadata = match CodeMetadata::top_decode(tx_input.args[1].as_slice()) {
+        Ok(code_metadata) => code_metadata,
+        Err(_) => {
+            return (
+                TxResult::from_vm_error("invalid code metadata".to_string()),
+                BlockchainUpdate::empty(),
+            )
+        },
+    };
 
-    // tx_input.args[1] is the code metadata, we ignore for now
-    // TODO: model code metadata in Denali
+    let is_upgradeable = tx_cache.with_account(&tx_input.to, |account| {
+        account.code_metadata.is_upgradeable()
+    });
+    if !is_upgradeable {
+        return (
+            TxResult::from_vm_error("upgrade not allowed".to_string()),
+            BlockchainUpdate::empty(),
+        );
+    }
 
     let args = if tx_input.args.len() > 2 {
         tx_input.args[2..].to_vec()
@@ -25,8 +43,21 @@ pub fn execute_upgrade_contract(
         Vec::new()
     };
 
+    // contracts without an `#[upgrade]` method get their constructor called instead,
+    // a regular endpoint that happens to be named `upgrade` is not called
+    let func_name = if tx_cache
+        .blockchain_ref()
+        .contract_map
+        .has_upgrade_method(&new_code)
+    {
+        b"upgrade".to_vec()
+    } else {
+        b"init".to_vec()
+    };
+
     tx_cache.with_account_mut(&tx_input.to, |account| {
         account.contract_path = Some(new_code);
+        account.code_metadata = new_code_metadata;
     });
 
     let exec_input = TxInput {
@@ -34,11 +65,12 @@ pub fn execute_upgrade_contract(
         to: tx_input.to,
         moax_value: tx_input.moax_value,
         dct_values: Vec::new(),
-        func_name: b"init".to_vec(),
+        func_name,
         args,
         gas_limit: tx_input.gas_limit,
         gas_price: tx_input.gas_price,
         tx_hash: tx_input.tx_hash,
+        ..Default::default()
     };
 
     default_execution(exec_input, tx_cache)
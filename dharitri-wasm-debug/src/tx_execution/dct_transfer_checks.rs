@@ -0,0 +1,66 @@
+use dharitri_wasm::types::{Address, DctLocalRole};
+
+use crate::tx_mock::{TxCache, TxInputDCT};
+
+use super::{is_system_sc_address, SystemScToken};
+
+const FROZEN_ERR_MSG: &str = "account is frozen for this dct token";
+const PAUSED_ERR_MSG: &str = "dct token is paused";
+const ACTION_NOT_ALLOWED_ERR_MSG: &str = "action is not allowed";
+
+/// Checks the token settings that can block a DCT transfer:
+/// - neither the sender nor the receiver can be frozen for the token, or for the transferred NFT;
+/// - the token cannot be paused;
+/// - tokens with limited transfers need the sender or the receiver to have the transfer role.
+///
+/// Tokens sent back by the system SC, e.g. the initial supply of a new token, are not checked.
+pub fn check_dct_transfer_allowed(
+    tx_cache: &TxCache,
+    from: &Address,
+    to: &Address,
+    dct_transfer: &TxInputDCT,
+) -> Result<(), String> {
+    if is_system_sc_address(from) {
+        return Ok(());
+    }
+
+    let token_identifier = dct_transfer.token_identifier.as_slice();
+    let nonce = dct_transfer.nonce;
+    if is_frozen(tx_cache, from, token_identifier, nonce)
+        || is_frozen(tx_cache, to, token_identifier, nonce)
+    {
+        return Err(FROZEN_ERR_MSG.to_string());
+    }
+
+    let token = SystemScToken::new(tx_cache, token_identifier);
+    if token.is_paused() {
+        return Err(PAUSED_ERR_MSG.to_string());
+    }
+    if token.is_limited_transfer()
+        && !has_transfer_role(tx_cache, from, token_identifier)
+        && !has_transfer_role(tx_cache, to, token_identifier)
+    {
+        return Err(ACTION_NOT_ALLOWED_ERR_MSG.to_string());
+    }
+
+    Ok(())
+}
+
+/// Accounts missing from the world state are presumed to be in another shard, and never frozen.
+fn is_frozen(tx_cache: &TxCache, address: &Address, token_identifier: &[u8], nonce: u64) -> bool {
+    tx_cache.account_exists(address)
+        && tx_cache.with_account(address, |account| {
+            account.dct.is_frozen(token_identifier, nonce)
+        })
+}
+
+fn has_transfer_role(tx_cache: &TxCache, address: &Address, token_identifier: &[u8]) -> bool {
+    let transfer_role = DctLocalRole::Transfer.as_role_name().to_vec();
+    tx_cache.account_exists(address)
+        && tx_cache.with_account(address, |account| {
+            account
+                .dct
+                .get_roles(token_identifier)
+                .contains(&transfer_role)
+        })
+}
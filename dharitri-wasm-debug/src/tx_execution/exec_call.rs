@@ -1,14 +1,20 @@
 use std::{collections::HashMap, rc::Rc};
 
+use dharitri_wasm::types::CodeMetadata;
+
 use crate::{
     tx_mock::{
-        async_call_tx_input, async_callback_tx_input, merge_results, AsyncCallTxData, TxCache,
-        TxContext, TxInput, TxResult, TxResultCalls,
+        async_call_tx_input, async_callback_tx_input, merge_results, promise_call_tx_input,
+        promise_callback_tx_input, AsyncCallPromiseTxData, AsyncCallTxData, CrossShardCall,
+        TxCache, TxContext, TxInput, TxResult,
     },
     world_mock::{AccountData, AccountDct, BlockchainMock},
 };
 
-use super::{execute_builtin_function_or_default, execute_tx_context};
+use super::{
+    execute_builtin_function_or_default, execute_system_sc, execute_tx_context,
+    is_system_sc_address,
+};
 
 pub fn sc_query(tx_input: TxInput, state: Rc<BlockchainMock>) -> TxResult {
     let tx_cache = TxCache::new(state);
@@ -29,9 +35,14 @@ pub fn sc_call(
     state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
 
     let tx_cache = TxCache::new(state.clone());
-    let (tx_result, blockchain_updates) = execute_builtin_function_or_default(tx_input, tx_cache);
+    let (mut tx_result, blockchain_updates) = if is_system_sc_address(&tx_input.to) {
+        execute_system_sc(tx_input, tx_cache)
+    } else {
+        execute_builtin_function_or_default(tx_input, tx_cache)
+    };
 
     if tx_result.result_status == 0 {
+        tx_result.storage_diff.writes = blockchain_updates.storage_writes(state);
         blockchain_updates.apply(Rc::get_mut(state).unwrap());
     }
 
@@ -42,7 +53,7 @@ pub fn execute_async_call_and_callback(
     async_data: AsyncCallTxData,
     state: &mut Rc<BlockchainMock>,
 ) -> (TxResult, TxResult) {
-    if state.accounts.contains_key(&async_data.to) {
+    if state.accounts.contains_key(&async_data.to) || is_system_sc_address(&async_data.to) {
         let async_input = async_call_tx_input(&async_data);
 
         let async_result = sc_call_with_async_and_callback(async_input, state, false);
@@ -55,24 +66,82 @@ pub fn execute_async_call_and_callback(
         );
         (async_result, callback_result)
     } else {
-        let tx_cache = TxCache::new(state.clone());
-        tx_cache.subtract_moax_balance(&async_data.from, &async_data.call_value);
-        tx_cache.insert_account(AccountData {
-            address: async_data.to.clone(),
-            nonce: 0,
-            moax_balance: async_data.call_value,
-            dct: AccountDct::default(),
-            username: Vec::new(),
-            storage: HashMap::new(),
-            contract_path: None,
-            contract_owner: None,
-        });
-        state.commit_tx_cache(tx_cache);
+        transfer_to_unknown_account(&async_data, state);
+        (TxResult::empty(), TxResult::empty())
+    }
+}
 
+/// Performs a promise, followed by its callback, if it has one for the given outcome.
+/// Promises registered by either of them are performed right after.
+pub fn execute_promise_call_and_callback(
+    promise: &AsyncCallPromiseTxData,
+    state: &mut Rc<BlockchainMock>,
+) -> (TxResult, TxResult) {
+    if state.accounts.contains_key(&promise.call.to) || is_system_sc_address(&promise.call.to) {
+        let async_input = promise_call_tx_input(promise);
+        let async_result = sc_call_with_async_and_callback(async_input, state, false);
+
+        let callback_result =
+            if let Some(callback_input) = promise_callback_tx_input(promise, &async_result) {
+                sc_call_with_async_and_callback(callback_input, state, false)
+            } else {
+                transfer_callback_dct_payments(&promise.call, &async_result, state);
+                TxResult::empty()
+            };
+        (async_result, callback_result)
+    } else {
+        transfer_to_unknown_account(&promise.call, state);
         (TxResult::empty(), TxResult::empty())
     }
 }
 
+/// Tokens meant to be sent back with a callback, when there is no callback to send them with.
+/// They go to the caller directly, instead of being stuck with the callee.
+pub(super) fn transfer_callback_dct_payments(
+    async_data: &AsyncCallTxData,
+    async_result: &TxResult,
+    state: &mut Rc<BlockchainMock>,
+) {
+    let payments = &async_result.result_calls.callback_dct_payments;
+    if payments.is_empty() {
+        return;
+    }
+
+    let tx_cache = TxCache::new(state.clone());
+    for payment in payments.iter() {
+        tx_cache.transfer_dct_balance(
+            &async_data.to,
+            &async_data.from,
+            &payment.token_identifier,
+            payment.nonce,
+            &payment.value,
+        );
+    }
+    state.commit_tx_cache(tx_cache);
+}
+
+/// The destination is not known, so it is presumed to be in another shard.
+/// The funds leave the caller and a new account is created for them.
+pub(super) fn transfer_to_unknown_account(
+    async_data: &AsyncCallTxData,
+    state: &mut Rc<BlockchainMock>,
+) {
+    let tx_cache = TxCache::new(state.clone());
+    tx_cache.subtract_moax_balance(&async_data.from, &async_data.call_value);
+    tx_cache.insert_account(AccountData {
+        address: async_data.to.clone(),
+        nonce: 0,
+        moax_balance: async_data.call_value.clone(),
+        dct: AccountDct::default(),
+        username: Vec::new(),
+        storage: HashMap::new(),
+        contract_path: None,
+        contract_owner: None,
+        code_metadata: CodeMetadata::DEFAULT,
+    });
+    state.commit_tx_cache(tx_cache);
+}
+
 // TODO: refactor
 pub fn sc_call_with_async_and_callback(
     tx_input: TxInput,
@@ -80,15 +149,45 @@ pub fn sc_call_with_async_and_callback(
     increase_nonce: bool,
 ) -> TxResult {
     let mut tx_result = sc_call(tx_input, state, increase_nonce);
-    let result_calls = std::mem::replace(&mut tx_result.result_calls, TxResultCalls::empty());
+    let promises = std::mem::take(&mut tx_result.result_calls.promises);
+    let async_call = tx_result.result_calls.async_call.take();
+    let cross_shard_transfer_execute =
+        std::mem::take(&mut tx_result.result_calls.cross_shard_transfer_execute);
     if tx_result.result_status == 0 {
-        if let Some(async_data) = result_calls.async_call {
-            let (async_result, callback_result) =
-                execute_async_call_and_callback(async_data, state);
+        let state_mut = Rc::get_mut(state).unwrap();
+        for call in cross_shard_transfer_execute {
+            state_mut.add_cross_shard_call(CrossShardCall::TransferExecute(call));
+        }
+
+        // promises are performed in the order in which they were registered,
+        // the legacy async call, if any, comes last
+        for promise in promises.iter() {
+            if state.is_cross_shard(&promise.call.from, &promise.call.to) {
+                Rc::get_mut(state)
+                    .unwrap()
+                    .add_cross_shard_call(CrossShardCall::Promise(promise.clone()));
+                continue;
+            }
+
+            let (async_result, callback_result) = execute_promise_call_and_callback(promise, state);
 
             tx_result = merge_results(tx_result, async_result);
             tx_result = merge_results(tx_result, callback_result);
         }
+
+        if let Some(async_data) = async_call {
+            if state.is_cross_shard(&async_data.from, &async_data.to) {
+                Rc::get_mut(state)
+                    .unwrap()
+                    .add_cross_shard_call(CrossShardCall::AsyncCall(async_data));
+            } else {
+                let (async_result, callback_result) =
+                    execute_async_call_and_callback(async_data, state);
+
+                tx_result = merge_results(tx_result, async_result);
+                tx_result = merge_results(tx_result, callback_result);
+            }
+        }
     }
 
     tx_result
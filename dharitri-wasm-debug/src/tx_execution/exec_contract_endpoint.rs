@@ -1,7 +1,10 @@
 use std::rc::Rc;
 
 use alloc::boxed::Box;
-use dharitri_wasm::contract_base::CallableContract;
+use dharitri_wasm::{
+    contract_base::CallableContract, dharitri_codec::NestedDecode,
+    types::CALLBACK_CLOSURE_STORAGE_BASE_KEY,
+};
 
 use crate::{
     address_hex,
@@ -28,14 +31,30 @@ fn execute_tx_context_rc(tx_context_rc: Rc<TxContext>) -> (Rc<TxContext>, TxResu
     let func_name = tx_context_ref.tx_input_box.func_name.as_slice();
     let contract_identifier = get_contract_identifier(&tx_context_ref);
     let contract_map = &tx_context_rc.blockchain_ref().contract_map;
+    // has to be read before the execution, which clears the closure from storage
+    let callback_name = if func_name == b"callBack" {
+        callback_closure_name(&tx_context_ref)
+    } else {
+        None
+    };
 
     let contract_instance =
         contract_map.new_contract_instance(contract_identifier.as_slice(), tx_context_ref.clone());
 
-    TxContextStack::static_push(tx_context_rc.clone());
-    let tx_result = execute_contract_instance_endpoint(contract_instance, func_name);
+    TxContextStack::static_push_traced(tx_context_rc.clone());
+    let mut tx_result = execute_contract_instance_endpoint(contract_instance, func_name);
+    if tx_result.result_status != 0 {
+        // the storage reads made before the failure are still relevant for coverage
+        tx_result.storage_diff = tx_context_rc.extract_result().storage_diff;
+    }
 
-    let tx_context_rc = TxContextStack::static_pop();
+    let tx_context_rc = TxContextStack::static_pop_traced(&tx_result);
+    tx_context_rc.blockchain_ref().record_endpoint_call(
+        contract_identifier.as_slice(),
+        func_name,
+        callback_name.as_deref(),
+        tx_result.result_status,
+    );
     (tx_context_rc, tx_result)
 }
 
@@ -52,6 +71,31 @@ fn get_contract_identifier(tx_context: &TxContext) -> Vec<u8> {
         })
 }
 
+/// All named callbacks are dispatched through this function,
+/// the callback closure says which one is actually called.
+fn callback_closure_name(tx_context: &TxContext) -> Option<Vec<u8>> {
+    let tx_input = &tx_context.tx_input_box;
+    let closure = if !tx_input.promise_callback_closure_data.is_empty() {
+        tx_input.promise_callback_closure_data.clone()
+    } else {
+        let mut storage_key = CALLBACK_CLOSURE_STORAGE_BASE_KEY.to_vec();
+        storage_key.extend_from_slice(tx_input.tx_hash.as_bytes());
+        tx_context.tx_cache.with_account(&tx_input.to, |account| {
+            account
+                .storage
+                .get(&storage_key)
+                .cloned()
+                .unwrap_or_default()
+        })
+    };
+    let callback_name = Vec::<u8>::dep_decode(&mut closure.as_slice()).ok()?;
+    if callback_name.is_empty() {
+        None
+    } else {
+        Some(callback_name)
+    }
+}
+
 /// The actual execution and the extraction/wrapping of results.
 fn execute_contract_instance_endpoint(
     contract_instance: Box<dyn CallableContract>,
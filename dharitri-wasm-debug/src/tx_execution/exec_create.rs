@@ -2,7 +2,7 @@ use std::rc::Rc;
 
 use crate::{
     tx_mock::{TxCache, TxInput, TxResult},
-    world_mock::BlockchainMock,
+    world_mock::{AccountData, BlockchainMock},
 };
 
 use super::deploy_contract;
@@ -18,9 +18,14 @@ pub fn sc_create(
     state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
 
     let tx_cache = TxCache::new(state.clone());
-    let (tx_result, blockchain_updates, _) =
-        deploy_contract(tx_input, contract_path.to_vec(), tx_cache);
+    let (mut tx_result, blockchain_updates, _) = deploy_contract(
+        tx_input,
+        contract_path.to_vec(),
+        AccountData::default_contract_code_metadata(),
+        tx_cache,
+    );
 
+    tx_result.storage_diff.writes = blockchain_updates.storage_writes(state);
     blockchain_updates.apply(Rc::get_mut(state).unwrap());
 
     tx_result
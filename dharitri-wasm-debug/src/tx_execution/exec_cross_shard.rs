@@ -0,0 +1,89 @@
+use std::rc::Rc;
+
+use crate::{
+    tx_mock::{
+        async_call_tx_input, async_callback_tx_input, promise_call_tx_input,
+        promise_callback_tx_input, AsyncCallTxData, CrossShardCall, TxCache, TxInput, TxResult,
+    },
+    world_mock::BlockchainMock,
+};
+
+use super::{
+    is_system_sc_address, sc_call_with_async_and_callback, transfer_callback_dct_payments,
+    transfer_to_unknown_account,
+};
+
+/// Delivers the cross-shard calls sent in the previous blocks.
+/// Called whenever the block nonce advances.
+///
+/// Each call is executed on its own, so a failing callback does not undo the call that triggered it.
+/// The callbacks, as well as any new cross-shard calls, are sent in turn,
+/// and will only be delivered once the block nonce advances again.
+pub fn process_cross_shard_calls(state: &mut Rc<BlockchainMock>) {
+    let calls = Rc::get_mut(state)
+        .unwrap()
+        .take_deliverable_cross_shard_calls();
+    for call in calls {
+        deliver_cross_shard_call(call, state);
+    }
+}
+
+fn deliver_cross_shard_call(call: CrossShardCall, state: &mut Rc<BlockchainMock>) {
+    match call {
+        CrossShardCall::AsyncCall(async_data) => {
+            let async_input = async_call_tx_input(&async_data);
+            if let Some(async_result) = execute_call_in_transit(&async_data, async_input, state) {
+                let callback_input = async_callback_tx_input(&async_data, &async_result);
+                send_callback(callback_input, state);
+            }
+        },
+        CrossShardCall::Promise(promise) => {
+            let async_input = promise_call_tx_input(&promise);
+            if let Some(async_result) = execute_call_in_transit(&promise.call, async_input, state) {
+                if let Some(callback_input) = promise_callback_tx_input(&promise, &async_result) {
+                    send_callback(callback_input, state);
+                } else {
+                    transfer_callback_dct_payments(&promise.call, &async_result, state);
+                }
+            }
+        },
+        CrossShardCall::TransferExecute(call_data) => {
+            let tx_input = async_call_tx_input(&call_data);
+            let _ = execute_call_in_transit(&call_data, tx_input, state);
+        },
+        CrossShardCall::Callback(callback_input) => {
+            let _ = sc_call_with_async_and_callback(callback_input, state, false);
+        },
+    }
+}
+
+/// The MOAX value left the caller when the call was sent.
+/// It is given back right before the execution, which transfers it once more,
+/// so that the caller is refunded if the call fails.
+///
+/// Returns `None` if the recipient is unknown, in which case there is no callback either.
+fn execute_call_in_transit(
+    call_data: &AsyncCallTxData,
+    tx_input: TxInput,
+    state: &mut Rc<BlockchainMock>,
+) -> Option<TxResult> {
+    if state.is_cross_shard(&call_data.from, &call_data.to) {
+        let tx_cache = TxCache::new(state.clone());
+        tx_cache.increase_moax_balance(&call_data.from, &call_data.call_value);
+        state.commit_tx_cache(tx_cache);
+    }
+
+    if state.accounts.contains_key(&call_data.to) || is_system_sc_address(&call_data.to) {
+        Some(sc_call_with_async_and_callback(tx_input, state, false))
+    } else {
+        transfer_to_unknown_account(call_data, state);
+        None
+    }
+}
+
+/// The callback goes back to the shard of the caller, so it is delivered in a later block as well.
+fn send_callback(callback_input: TxInput, state: &mut Rc<BlockchainMock>) {
+    Rc::get_mut(state)
+        .unwrap()
+        .add_cross_shard_call(CrossShardCall::Callback(callback_input));
+}
@@ -1,13 +1,24 @@
-use dharitri_wasm::types::Address;
+use dharitri_wasm::types::{Address, CodeMetadata};
 
 use crate::{
     tx_mock::{BlockchainUpdate, TxCache, TxContext, TxInput, TxResult},
     world_mock::is_smart_contract_address,
 };
 
-use super::execute_tx_context;
+use super::{check_dct_transfer_allowed, check_payable, execute_tx_context};
 
 pub fn default_execution(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    for dct_transfer in tx_input.dct_values.iter() {
+        if let Err(message) =
+            check_dct_transfer_allowed(&tx_cache, &tx_input.from, &tx_input.to, dct_transfer)
+        {
+            return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+        }
+    }
+    if let Err(message) = check_payable(&tx_cache, &tx_input) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
     let mut tx_context = TxContext::new(tx_input, tx_cache);
 
     tx_context.tx_cache.subtract_moax_balance(
@@ -49,6 +60,7 @@ pub fn default_execution(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Blo
 pub fn deploy_contract(
     mut tx_input: TxInput,
     contract_path: Vec<u8>,
+    code_metadata: CodeMetadata,
     tx_cache: TxCache,
 ) -> (TxResult, BlockchainUpdate, Address) {
     let new_address = tx_cache.get_new_address(&tx_input.from);
@@ -60,7 +72,12 @@ pub fn deploy_contract(
     tx_context
         .tx_cache
         .subtract_moax_balance(&tx_input_ref.from, &tx_input_ref.moax_value);
-    tx_context.create_new_contract(&new_address, contract_path, tx_input_ref.from.clone());
+    tx_context.create_new_contract(
+        &new_address,
+        contract_path,
+        tx_input_ref.from.clone(),
+        code_metadata,
+    );
     tx_context
         .tx_cache
         .increase_moax_balance(&new_address, &tx_input_ref.moax_value);
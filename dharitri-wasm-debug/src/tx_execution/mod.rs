@@ -1,11 +1,19 @@
 mod builtin_function_mocks;
+mod dct_transfer_checks;
 mod exec_call;
 mod exec_contract_endpoint;
 mod exec_create;
+mod exec_cross_shard;
 mod exec_general_tx;
+mod payable_checks;
+mod system_sc_mock;
 
 pub use builtin_function_mocks::*;
+pub use dct_transfer_checks::*;
 pub use exec_call::*;
 pub use exec_contract_endpoint::*;
 pub use exec_create::*;
+pub use exec_cross_shard::*;
 pub use exec_general_tx::*;
+pub use payable_checks::*;
+pub use system_sc_mock::*;
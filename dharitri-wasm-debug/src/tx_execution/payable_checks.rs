@@ -0,0 +1,51 @@
+use dharitri_wasm::types::Address;
+use num_traits::Zero;
+
+use crate::{
+    tx_mock::{TxCache, TxInput},
+    world_mock::is_smart_contract_address,
+};
+
+use super::is_system_sc_address;
+
+const NON_PAYABLE_ERR_MSG: &str = "sending value to non payable contract";
+
+/// Value sent to a contract without calling an endpoint is only accepted if the contract is payable.
+///
+/// Endpoint calls are not checked here, their payment annotations take care of that.
+pub fn check_payable(tx_cache: &TxCache, tx_input: &TxInput) -> Result<(), String> {
+    if !tx_input.func_name.is_empty() {
+        return Ok(());
+    }
+    if tx_input.moax_value.is_zero() && tx_input.dct_values.is_empty() {
+        return Ok(());
+    }
+
+    check_direct_transfer_payable(tx_cache, &tx_input.from, &tx_input.to)
+}
+
+/// Checks a direct transfer of some non-zero value.
+/// Contracts that are only payable by SC accept it from other contracts.
+///
+/// Transfers coming from the system SC are not checked, since it only returns tokens to their owners.
+/// Accounts missing from the world state are presumed to be in another shard, and accept anything.
+pub fn check_direct_transfer_payable(
+    tx_cache: &TxCache,
+    from: &Address,
+    to: &Address,
+) -> Result<(), String> {
+    if is_system_sc_address(from) || !tx_cache.account_exists(to) {
+        return Ok(());
+    }
+
+    let is_payable = tx_cache.with_account(to, |account| {
+        account.contract_path.is_none()
+            || account.code_metadata.is_payable()
+            || (account.code_metadata.is_payable_by_sc() && is_smart_contract_address(from))
+    });
+    if is_payable {
+        Ok(())
+    } else {
+        Err(NON_PAYABLE_ERR_MSG.to_string())
+    }
+}
@@ -0,0 +1,11 @@
+mod system_sc_exec;
+mod system_sc_issue;
+mod system_sc_special_roles;
+mod system_sc_token_data;
+mod system_sc_token_management;
+
+pub use system_sc_exec::{
+    create_system_sc_account_if_necessary, execute_system_sc, is_system_sc_address,
+    system_sc_address,
+};
+pub use system_sc_token_data::*;
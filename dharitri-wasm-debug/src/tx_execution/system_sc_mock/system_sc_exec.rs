@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+
+use dharitri_wasm::{
+    dct::DCT_SYSTEM_SC_ADDRESS_ARRAY,
+    types::{Address, CodeMetadata},
+};
+use num_bigint::BigUint;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxInputDCT, TxPanic, TxResult},
+    world_mock::{is_smart_contract_address, AccountData, AccountDct, DctInstanceMetadata},
+};
+
+use super::{
+    system_sc_issue::{
+        execute_issue_fungible, execute_issue_non_fungible, execute_issue_semi_fungible,
+        execute_register_and_set_all_roles, execute_register_meta_dct,
+    },
+    system_sc_special_roles::{
+        execute_set_special_role, execute_transfer_nft_create_role, execute_unset_special_role,
+    },
+    system_sc_token_management::{
+        execute_burn, execute_change_sft_to_meta_dct, execute_freeze, execute_freeze_single_nft,
+        execute_mint, execute_pause, execute_transfer_ownership, execute_unfreeze,
+        execute_unfreeze_single_nft, execute_unpause, execute_wipe, execute_wipe_single_nft,
+    },
+    SystemScToken,
+};
+
+/// Error messages returned by the system SC mock end up as user errors in the caller's callback.
+pub(super) type SystemScResult = Result<TxResult, String>;
+
+pub fn system_sc_address() -> Address {
+    Address::from(DCT_SYSTEM_SC_ADDRESS_ARRAY)
+}
+
+pub fn is_system_sc_address(address: &Address) -> bool {
+    address.as_array() == &DCT_SYSTEM_SC_ADDRESS_ARRAY
+}
+
+/// Executes a call to the DCT system smart contract.
+///
+/// There is no contract code behind the system SC address in the mock,
+/// the endpoints are all implemented here, directly on top of the tx cache.
+pub fn execute_system_sc(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    create_system_sc_account_if_necessary(&tx_cache);
+
+    let caller_balance =
+        tx_cache.with_account(&tx_input.from, |account| account.moax_balance.clone());
+    if caller_balance < tx_input.moax_value {
+        return system_sc_error("failed transfer (insufficient funds)".to_string());
+    }
+    tx_cache.subtract_moax_balance(&tx_input.from, &tx_input.moax_value);
+    tx_cache.increase_moax_balance(&tx_input.to, &tx_input.moax_value);
+    for payment in tx_input.dct_values.iter() {
+        if let Err(message) = transfer_dct_to_system_sc(
+            &tx_input,
+            &tx_cache,
+            &payment.token_identifier,
+            payment.nonce,
+            &payment.value,
+        ) {
+            return system_sc_error(message);
+        }
+    }
+
+    let result = match tx_input.func_name.as_slice() {
+        b"issue" => execute_issue_fungible(&tx_input, &tx_cache),
+        b"issueNonFungible" => execute_issue_non_fungible(&tx_input, &tx_cache),
+        b"issueSemiFungible" => execute_issue_semi_fungible(&tx_input, &tx_cache),
+        b"registerMetaDCT" => execute_register_meta_dct(&tx_input, &tx_cache),
+        b"registerAndSetAllRoles" => execute_register_and_set_all_roles(&tx_input, &tx_cache),
+        b"setSpecialRole" => execute_set_special_role(&tx_input, &tx_cache),
+        b"unSetSpecialRole" => execute_unset_special_role(&tx_input, &tx_cache),
+        b"transferNFTCreateRole" => execute_transfer_nft_create_role(&tx_input, &tx_cache),
+        b"mint" => execute_mint(&tx_input, &tx_cache),
+        b"DCTBurn" => execute_burn(&tx_input, &tx_cache),
+        b"pause" => execute_pause(&tx_input, &tx_cache),
+        b"unPause" => execute_unpause(&tx_input, &tx_cache),
+        b"freeze" => execute_freeze(&tx_input, &tx_cache),
+        b"unFreeze" => execute_unfreeze(&tx_input, &tx_cache),
+        b"wipe" => execute_wipe(&tx_input, &tx_cache),
+        b"freezeSingleNFT" => execute_freeze_single_nft(&tx_input, &tx_cache),
+        b"unFreezeSingleNFT" => execute_unfreeze_single_nft(&tx_input, &tx_cache),
+        b"wipeSingleNFT" => execute_wipe_single_nft(&tx_input, &tx_cache),
+        b"changeSFTToMetaDCT" => execute_change_sft_to_meta_dct(&tx_input, &tx_cache),
+        b"transferOwnership" => execute_transfer_ownership(&tx_input, &tx_cache),
+        _ => Err("invalid function (not found)".to_string()),
+    };
+
+    match result {
+        Ok(tx_result) => (tx_result, tx_cache.into_blockchain_updates()),
+        Err(message) => system_sc_error(message),
+    }
+}
+
+fn system_sc_error(message: String) -> (TxResult, BlockchainUpdate) {
+    let tx_result = TxResult::from_panic_obj(&TxPanic {
+        status: 4,
+        message: message.into_bytes(),
+    });
+    (tx_result, BlockchainUpdate::empty())
+}
+
+/// The system SC account only shows up in the world state once it is first needed.
+pub fn create_system_sc_account_if_necessary(tx_cache: &TxCache) {
+    let address = system_sc_address();
+    if !tx_cache.account_exists(&address) {
+        tx_cache.insert_account(AccountData {
+            address,
+            nonce: 0,
+            moax_balance: BigUint::from(0u32),
+            dct: AccountDct::default(),
+            username: Vec::new(),
+            storage: HashMap::new(),
+            contract_path: None,
+            contract_owner: None,
+            code_metadata: CodeMetadata::DEFAULT,
+        });
+    }
+}
+
+pub(super) fn check_num_args(tx_input: &TxInput, min_num_args: usize) -> Result<(), String> {
+    if tx_input.args.len() < min_num_args {
+        return Err(format!(
+            "{} expects at least {} arguments",
+            String::from_utf8_lossy(tx_input.func_name.as_slice()),
+            min_num_args
+        ));
+    }
+    Ok(())
+}
+
+/// Moves tokens from the caller to the system SC account, failing if the caller does not have them.
+pub(super) fn transfer_dct_to_system_sc(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+    nonce: u64,
+    amount: &BigUint,
+) -> Result<(), String> {
+    let balance = tx_cache.with_account(&tx_input.from, |account| {
+        account.dct.get_dct_balance(token_identifier, nonce)
+    });
+    if &balance < amount {
+        return Err("insufficient funds".to_string());
+    }
+    tx_cache.transfer_dct_balance(
+        &tx_input.from,
+        &tx_input.to,
+        token_identifier,
+        nonce,
+        amount,
+    );
+    Ok(())
+}
+
+/// Most system SC endpoints can only be called by the owner of the token.
+pub(super) fn load_token_owned_by_caller<'a>(
+    tx_input: &TxInput,
+    tx_cache: &'a TxCache,
+    token_identifier: &'a [u8],
+) -> Result<SystemScToken<'a>, String> {
+    let token = SystemScToken::new(tx_cache, token_identifier);
+    if !token.exists() {
+        return Err("no token with this identifier".to_string());
+    }
+    if token.owner() != tx_input.from {
+        return Err("can be called by owner only".to_string());
+    }
+    Ok(token)
+}
+
+pub(super) fn require_property(token: &SystemScToken, property_name: &[u8]) -> Result<(), String> {
+    if !token.has_property(property_name) {
+        return Err(format!(
+            "token property {} is not set",
+            String::from_utf8_lossy(property_name)
+        ));
+    }
+    Ok(())
+}
+
+/// Tokens minted for a contract are sent back with the callback,
+/// so that the callback can see them as payment.
+/// Users calling the system SC directly receive them right away.
+pub(super) fn send_tokens_to_caller(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    tx_result: &mut TxResult,
+    token_identifier: &[u8],
+    amount: &BigUint,
+) {
+    if is_smart_contract_address(&tx_input.from) {
+        tx_cache.increase_dct_balance(
+            &tx_input.to,
+            token_identifier,
+            0,
+            amount,
+            DctInstanceMetadata::default(),
+        );
+        tx_result
+            .result_calls
+            .callback_dct_payments
+            .push(TxInputDCT {
+                token_identifier: token_identifier.to_vec(),
+                nonce: 0,
+                value: amount.clone(),
+            });
+    } else {
+        tx_cache.increase_dct_balance(
+            &tx_input.from,
+            token_identifier,
+            0,
+            amount,
+            DctInstanceMetadata::default(),
+        );
+    }
+}
+
+/// Merges the given roles into the roles the account already has for the token.
+/// Accounts that are not in the world state are presumed to be in another shard and are skipped.
+pub(super) fn add_roles(
+    tx_cache: &TxCache,
+    address: &Address,
+    token_identifier: &[u8],
+    roles: &[Vec<u8>],
+) {
+    if !tx_cache.account_exists(address) {
+        return;
+    }
+    tx_cache.with_account_mut(address, |account| {
+        let mut account_roles = account.dct.get_roles(token_identifier);
+        for role in roles {
+            if !account_roles.contains(role) {
+                account_roles.push(role.clone());
+            }
+        }
+        account
+            .dct
+            .set_roles(token_identifier.to_vec(), account_roles);
+    });
+}
+
+pub(super) fn remove_roles(
+    tx_cache: &TxCache,
+    address: &Address,
+    token_identifier: &[u8],
+    roles: &[Vec<u8>],
+) {
+    if !tx_cache.account_exists(address) {
+        return;
+    }
+    tx_cache.with_account_mut(address, |account| {
+        let mut account_roles = account.dct.get_roles(token_identifier);
+        account_roles.retain(|role| !roles.contains(role));
+        account
+            .dct
+            .set_roles(token_identifier.to_vec(), account_roles);
+    });
+}
@@ -0,0 +1,243 @@
+use dharitri_wasm::{dharitri_codec::TopDecode, types::DctLocalRole};
+use num_bigint::BigUint;
+
+use crate::tx_mock::{BlockchainRng, TxCache, TxInput, TxResult};
+
+use super::{
+    system_sc_exec::{add_roles, check_num_args, send_tokens_to_caller, SystemScResult},
+    SystemScToken, FUNGIBLE_TOKEN_TYPE, META_TOKEN_TYPE, NON_FUNGIBLE_TOKEN_TYPE,
+    SEMI_FUNGIBLE_TOKEN_TYPE,
+};
+
+const MIN_TOKEN_NAME_LENGTH: usize = 3;
+const MAX_TOKEN_NAME_LENGTH: usize = 20;
+const MIN_TICKER_LENGTH: usize = 3;
+const MAX_TICKER_LENGTH: usize = 10;
+const MAX_NUM_DECIMALS: u32 = 18;
+const RANDOM_SUFFIX_NUM_BYTES: usize = 3;
+
+const TRUE_BYTES: &[u8] = b"true";
+const FALSE_BYTES: &[u8] = b"false";
+const CAN_UPGRADE_PROPERTY: &[u8] = b"canUpgrade";
+const ALL_PROPERTIES: &[&[u8]] = &[
+    b"canFreeze",
+    b"canWipe",
+    b"canPause",
+    b"canMint",
+    b"canBurn",
+    b"canChangeOwner",
+    CAN_UPGRADE_PROPERTY,
+    b"canAddSpecialRoles",
+    b"canTransferNFTCreateRole",
+];
+
+/// Args: name, ticker, initial supply, number of decimals, properties.
+pub fn execute_issue_fungible(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 4)?;
+    let initial_supply = BigUint::from_bytes_be(tx_input.args[2].as_slice());
+    let token_identifier = register_token(
+        tx_input,
+        tx_cache,
+        FUNGIBLE_TOKEN_TYPE,
+        tx_input.args[3].as_slice(),
+    )?;
+    let token = SystemScToken::new(tx_cache, token_identifier.as_slice());
+    set_properties(&token, &tx_input.args[4..])?;
+
+    let mut tx_result = TxResult::empty();
+    send_tokens_to_caller(
+        tx_input,
+        tx_cache,
+        &mut tx_result,
+        token_identifier.as_slice(),
+        &initial_supply,
+    );
+    Ok(tx_result)
+}
+
+/// Args: name, ticker, properties.
+pub fn execute_issue_non_fungible(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = register_token(tx_input, tx_cache, NON_FUNGIBLE_TOKEN_TYPE, &[])?;
+    let token = SystemScToken::new(tx_cache, token_identifier.as_slice());
+    set_properties(&token, &tx_input.args[2..])?;
+
+    Ok(token_identifier_result(token_identifier))
+}
+
+/// Args: name, ticker, properties.
+pub fn execute_issue_semi_fungible(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = register_token(tx_input, tx_cache, SEMI_FUNGIBLE_TOKEN_TYPE, &[])?;
+    let token = SystemScToken::new(tx_cache, token_identifier.as_slice());
+    set_properties(&token, &tx_input.args[2..])?;
+
+    Ok(token_identifier_result(token_identifier))
+}
+
+/// Args: name, ticker, number of decimals, properties.
+pub fn execute_register_meta_dct(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 3)?;
+    let token_identifier = register_token(
+        tx_input,
+        tx_cache,
+        META_TOKEN_TYPE,
+        tx_input.args[2].as_slice(),
+    )?;
+    let token = SystemScToken::new(tx_cache, token_identifier.as_slice());
+    set_properties(&token, &tx_input.args[3..])?;
+
+    Ok(token_identifier_result(token_identifier))
+}
+
+/// Args: name, ticker, token type (`FNG`, `NFT`, `SFT` or `META`), number of decimals.
+///
+/// The new token gets all the properties and the caller gets all the roles that make sense for its type,
+/// except for the transfer role, which would limit transfers.
+pub fn execute_register_and_set_all_roles(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> SystemScResult {
+    check_num_args(tx_input, 4)?;
+    let token_type = match tx_input.args[2].as_slice() {
+        b"FNG" => FUNGIBLE_TOKEN_TYPE,
+        b"NFT" => NON_FUNGIBLE_TOKEN_TYPE,
+        b"SFT" => SEMI_FUNGIBLE_TOKEN_TYPE,
+        b"META" => META_TOKEN_TYPE,
+        _ => return Err("invalid argument".to_string()),
+    };
+    let token_identifier =
+        register_token(tx_input, tx_cache, token_type, tx_input.args[3].as_slice())?;
+
+    let token = SystemScToken::new(tx_cache, token_identifier.as_slice());
+    for property_name in ALL_PROPERTIES {
+        token.set_property(property_name, true);
+    }
+
+    let roles: Vec<Vec<u8>> = roles_for_token_type(token_type)
+        .iter()
+        .filter(|role| **role != DctLocalRole::Transfer)
+        .map(|role| role.as_role_name().to_vec())
+        .collect();
+    add_roles(
+        tx_cache,
+        &tx_input.from,
+        token_identifier.as_slice(),
+        roles.as_slice(),
+    );
+
+    Ok(token_identifier_result(token_identifier))
+}
+
+/// The local roles that can be assigned for a token type.
+pub fn roles_for_token_type(token_type: &[u8]) -> &'static [DctLocalRole] {
+    match token_type {
+        FUNGIBLE_TOKEN_TYPE => &[
+            DctLocalRole::Mint,
+            DctLocalRole::Burn,
+            DctLocalRole::Transfer,
+        ],
+        NON_FUNGIBLE_TOKEN_TYPE => &[
+            DctLocalRole::NftCreate,
+            DctLocalRole::NftBurn,
+            DctLocalRole::NftAddUri,
+            DctLocalRole::NftUpdateAttributes,
+            DctLocalRole::Transfer,
+        ],
+        _ => &[
+            DctLocalRole::NftCreate,
+            DctLocalRole::NftBurn,
+            DctLocalRole::NftAddQuantity,
+            DctLocalRole::NftAddUri,
+            DctLocalRole::NftUpdateAttributes,
+            DctLocalRole::Transfer,
+        ],
+    }
+}
+
+/// Validates the name and ticker (the first 2 arguments) and records the new token,
+/// owned by the caller.
+fn register_token(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    token_type: &[u8],
+    num_decimals_arg: &[u8],
+) -> Result<Vec<u8>, String> {
+    let name = tx_input.args[0].as_slice();
+    let ticker = tx_input.args[1].as_slice();
+    if !is_valid_token_name(name) {
+        return Err("invalid token name".to_string());
+    }
+    if !is_valid_ticker(ticker) {
+        return Err("ticker name is not valid".to_string());
+    }
+    match u32::top_decode(num_decimals_arg) {
+        Ok(num_decimals) if num_decimals <= MAX_NUM_DECIMALS => {},
+        _ => return Err("invalid number of decimals".to_string()),
+    }
+
+    let token_identifier = generate_token_identifier(tx_input, tx_cache, ticker);
+    let token = SystemScToken::new(tx_cache, token_identifier.as_slice());
+    token.set_owner(&tx_input.from);
+    token.set_token_type(token_type);
+    token.set_name(name);
+    token.set_ticker(ticker);
+    token.set_num_decimals(num_decimals_arg);
+    token.set_property(CAN_UPGRADE_PROPERTY, true);
+
+    Ok(token_identifier)
+}
+
+/// The ticker, followed by a dash and 6 random hex characters.
+/// The random bytes come from the blockchain RNG, so the identifiers are deterministic.
+/// Suffixes that are already taken are skipped.
+fn generate_token_identifier(tx_input: &TxInput, tx_cache: &TxCache, ticker: &[u8]) -> Vec<u8> {
+    let mut rng = BlockchainRng::new(tx_input, tx_cache);
+    loop {
+        let mut random_suffix = [0u8; RANDOM_SUFFIX_NUM_BYTES];
+        rng.fill(&mut random_suffix[..]);
+        let token_identifier = [ticker, b"-", hex::encode(random_suffix).as_bytes()].concat();
+        if !SystemScToken::new(tx_cache, token_identifier.as_slice()).exists() {
+            return token_identifier;
+        }
+    }
+}
+
+fn is_valid_token_name(name: &[u8]) -> bool {
+    (MIN_TOKEN_NAME_LENGTH..=MAX_TOKEN_NAME_LENGTH).contains(&name.len())
+        && name.iter().all(u8::is_ascii_alphanumeric)
+}
+
+fn is_valid_ticker(ticker: &[u8]) -> bool {
+    (MIN_TICKER_LENGTH..=MAX_TICKER_LENGTH).contains(&ticker.len())
+        && ticker
+            .iter()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// Properties come in pairs: the property name, followed by `true` or `false`.
+fn set_properties(token: &SystemScToken, args: &[Vec<u8>]) -> Result<(), String> {
+    let pairs = args.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err("invalid number of arguments".to_string());
+    }
+    for pair in pairs {
+        let property_name = pair[0].as_slice();
+        if !ALL_PROPERTIES.contains(&property_name) {
+            return Err("invalid argument".to_string());
+        }
+        let value = match pair[1].as_slice() {
+            TRUE_BYTES => true,
+            FALSE_BYTES => false,
+            _ => return Err("invalid argument".to_string()),
+        };
+        token.set_property(property_name, value);
+    }
+    Ok(())
+}
+
+fn token_identifier_result(token_identifier: Vec<u8>) -> TxResult {
+    let mut tx_result = TxResult::empty();
+    tx_result.result_values.push(token_identifier);
+    tx_result
+}
@@ -0,0 +1,99 @@
+use dharitri_wasm::types::{Address, DctLocalRole};
+
+use crate::tx_mock::{TxCache, TxInput, TxResult};
+
+use super::{
+    system_sc_exec::{
+        add_roles, check_num_args, load_token_owned_by_caller, remove_roles, require_property,
+        SystemScResult,
+    },
+    system_sc_issue::roles_for_token_type,
+};
+
+/// Args: token identifier, address, roles.
+pub fn execute_set_special_role(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let token = load_token_owned_by_caller(tx_input, tx_cache, token_identifier)?;
+    require_property(&token, b"canAddSpecialRoles")?;
+
+    let roles = &tx_input.args[2..];
+    let allowed_roles = roles_for_token_type(token.token_type().as_slice());
+    for role in roles {
+        if !allowed_roles
+            .iter()
+            .any(|allowed_role| allowed_role.as_role_name() == role.as_slice())
+        {
+            return Err("invalid argument".to_string());
+        }
+    }
+
+    // the holders of the role are not counted,
+    // so the token stays limited even after the role is unset from everyone
+    if roles
+        .iter()
+        .any(|role| role.as_slice() == DctLocalRole::Transfer.as_role_name())
+    {
+        token.set_limited_transfer(true);
+    }
+
+    let address = Address::from_slice(tx_input.args[1].as_slice());
+    add_roles(tx_cache, &address, token_identifier, roles);
+
+    Ok(TxResult::empty())
+}
+
+/// Args: token identifier, address, roles.
+pub fn execute_unset_special_role(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    load_token_owned_by_caller(tx_input, tx_cache, token_identifier)?;
+
+    let address = Address::from_slice(tx_input.args[1].as_slice());
+    remove_roles(tx_cache, &address, token_identifier, &tx_input.args[2..]);
+
+    Ok(TxResult::empty())
+}
+
+/// Args: token identifier, old creator, new creator.
+///
+/// Moves the NFT create role, together with the last created nonce.
+pub fn execute_transfer_nft_create_role(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 3)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let token = load_token_owned_by_caller(tx_input, tx_cache, token_identifier)?;
+    require_property(&token, b"canTransferNFTCreateRole")?;
+
+    let old_creator = Address::from_slice(tx_input.args[1].as_slice());
+    let new_creator = Address::from_slice(tx_input.args[2].as_slice());
+    let nft_create_role = vec![DctLocalRole::NftCreate.as_role_name().to_vec()];
+
+    let has_role = tx_cache.account_exists(&old_creator)
+        && tx_cache.with_account(&old_creator, |account| {
+            account
+                .dct
+                .get_roles(token_identifier)
+                .contains(&nft_create_role[0])
+        });
+    if !has_role {
+        return Err("old creator does not have the NFT create role".to_string());
+    }
+
+    let last_nonce = tx_cache.with_account(&old_creator, |account| {
+        account
+            .dct
+            .get_by_identifier_or_default(token_identifier)
+            .last_nonce
+    });
+    remove_roles(tx_cache, &old_creator, token_identifier, &nft_create_role);
+    add_roles(tx_cache, &new_creator, token_identifier, &nft_create_role);
+    if tx_cache.account_exists(&new_creator) {
+        tx_cache.with_account_mut(&new_creator, |account| {
+            if let Some(dct_data) = account.dct.get_mut_by_identifier(token_identifier) {
+                dct_data.last_nonce = last_nonce;
+            }
+        });
+    }
+
+    Ok(TxResult::empty())
+}
@@ -0,0 +1,132 @@
+use dharitri_wasm::types::Address;
+
+use crate::tx_mock::TxCache;
+
+use super::system_sc_address;
+
+pub const FUNGIBLE_TOKEN_TYPE: &[u8] = b"FungibleDCT";
+pub const NON_FUNGIBLE_TOKEN_TYPE: &[u8] = b"NonFungibleDCT";
+pub const SEMI_FUNGIBLE_TOKEN_TYPE: &[u8] = b"SemiFungibleDCT";
+pub const META_TOKEN_TYPE: &[u8] = b"MetaDCT";
+
+const OWNER_FIELD: &[u8] = b"owner";
+const TYPE_FIELD: &[u8] = b"type";
+const NAME_FIELD: &[u8] = b"name";
+const TICKER_FIELD: &[u8] = b"ticker";
+const NUM_DECIMALS_FIELD: &[u8] = b"decimals";
+const PAUSED_FIELD: &[u8] = b"paused";
+const LIMITED_TRANSFER_FIELD: &[u8] = b"limitedTransfer";
+
+/// Handle to a token registered in the system SC mock.
+///
+/// The token data is kept in the storage of the system SC account,
+/// one entry per field, under `<token identifier>.<field name>`,
+/// so it gets reverted together with the rest of a failed transaction
+/// and can be checked from scenarios.
+pub struct SystemScToken<'a> {
+    tx_cache: &'a TxCache,
+    token_identifier: &'a [u8],
+}
+
+impl<'a> SystemScToken<'a> {
+    pub fn new(tx_cache: &'a TxCache, token_identifier: &'a [u8]) -> Self {
+        SystemScToken {
+            tx_cache,
+            token_identifier,
+        }
+    }
+
+    pub fn exists(&self) -> bool {
+        !self.get_field(TYPE_FIELD).is_empty()
+    }
+
+    pub fn owner(&self) -> Address {
+        Address::from_slice(self.get_field(OWNER_FIELD).as_slice())
+    }
+
+    pub fn set_owner(&self, owner: &Address) {
+        self.set_field(OWNER_FIELD, owner.to_vec());
+    }
+
+    pub fn token_type(&self) -> Vec<u8> {
+        self.get_field(TYPE_FIELD)
+    }
+
+    pub fn set_token_type(&self, token_type: &[u8]) {
+        self.set_field(TYPE_FIELD, token_type.to_vec());
+    }
+
+    pub fn set_name(&self, name: &[u8]) {
+        self.set_field(NAME_FIELD, name.to_vec());
+    }
+
+    pub fn set_ticker(&self, ticker: &[u8]) {
+        self.set_field(TICKER_FIELD, ticker.to_vec());
+    }
+
+    pub fn set_num_decimals(&self, num_decimals: &[u8]) {
+        self.set_field(NUM_DECIMALS_FIELD, num_decimals.to_vec());
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.get_bool_field(PAUSED_FIELD)
+    }
+
+    pub fn set_paused(&self, paused: bool) {
+        self.set_bool_field(PAUSED_FIELD, paused);
+    }
+
+    /// Only addresses with the transfer role can send or receive limited transfer tokens.
+    pub fn is_limited_transfer(&self) -> bool {
+        self.get_bool_field(LIMITED_TRANSFER_FIELD)
+    }
+
+    pub fn set_limited_transfer(&self, limited_transfer: bool) {
+        self.set_bool_field(LIMITED_TRANSFER_FIELD, limited_transfer);
+    }
+
+    /// Properties such as `canFreeze` or `canMint`. Missing properties are false.
+    pub fn has_property(&self, property_name: &[u8]) -> bool {
+        self.get_bool_field(property_name)
+    }
+
+    pub fn set_property(&self, property_name: &[u8], value: bool) {
+        self.set_bool_field(property_name, value);
+    }
+
+    fn field_key(&self, field_name: &[u8]) -> Vec<u8> {
+        [self.token_identifier, b".", field_name].concat()
+    }
+
+    fn get_field(&self, field_name: &[u8]) -> Vec<u8> {
+        let address = system_sc_address();
+        if !self.tx_cache.account_exists(&address) {
+            return Vec::new();
+        }
+        let key = self.field_key(field_name);
+        self.tx_cache.with_account(&address, |account| {
+            account.storage.get(&key).cloned().unwrap_or_default()
+        })
+    }
+
+    fn set_field(&self, field_name: &[u8], value: Vec<u8>) {
+        let key = self.field_key(field_name);
+        self.tx_cache
+            .with_account_mut(&system_sc_address(), |account| {
+                if value.is_empty() {
+                    account.storage.remove(&key);
+                } else {
+                    account.storage.insert(key, value);
+                }
+            });
+    }
+
+    fn get_bool_field(&self, field_name: &[u8]) -> bool {
+        self.get_field(field_name) == [1u8]
+    }
+
+    fn set_bool_field(&self, field_name: &[u8], value: bool) {
+        let encoded = if value { vec![1u8] } else { Vec::new() };
+        self.set_field(field_name, encoded);
+    }
+}
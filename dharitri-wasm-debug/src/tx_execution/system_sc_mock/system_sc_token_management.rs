@@ -0,0 +1,203 @@
+use dharitri_wasm::{dharitri_codec::TopDecode, types::Address};
+use num_bigint::BigUint;
+
+use crate::{
+    tx_execution::{freeze_dct, wipe_dct},
+    tx_mock::{TxCache, TxInput, TxResult},
+};
+
+use super::{
+    system_sc_exec::{
+        check_num_args, load_token_owned_by_caller, require_property, send_tokens_to_caller,
+        transfer_dct_to_system_sc, SystemScResult,
+    },
+    SystemScToken, FUNGIBLE_TOKEN_TYPE, META_TOKEN_TYPE, SEMI_FUNGIBLE_TOKEN_TYPE,
+};
+
+/// Args: token identifier, amount.
+pub fn execute_mint(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let token = load_token_owned_by_caller(tx_input, tx_cache, token_identifier)?;
+    require_fungible(&token)?;
+    require_property(&token, b"canMint")?;
+
+    let amount = BigUint::from_bytes_be(tx_input.args[1].as_slice());
+    let mut tx_result = TxResult::empty();
+    send_tokens_to_caller(
+        tx_input,
+        tx_cache,
+        &mut tx_result,
+        token_identifier,
+        &amount,
+    );
+    Ok(tx_result)
+}
+
+/// Args: token identifier, amount.
+///
+/// Burns the tokens transferred to the system SC with the call.
+/// Calls without a DCT payment transfer the amount from the caller first, as the protocol does.
+/// The caller does not need to be the owner.
+pub fn execute_burn(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let token = SystemScToken::new(tx_cache, token_identifier);
+    if !token.exists() {
+        return Err("no token with this identifier".to_string());
+    }
+    require_fungible(&token)?;
+    require_property(&token, b"canBurn")?;
+
+    let amount = BigUint::from_bytes_be(tx_input.args[1].as_slice());
+    match tx_input.dct_values.as_slice() {
+        [] => transfer_dct_to_system_sc(tx_input, tx_cache, token_identifier, 0, &amount)?,
+        [payment]
+            if payment.token_identifier == token_identifier
+                && payment.nonce == 0
+                && payment.value == amount => {},
+        _ => return Err("burn payment does not match the arguments".to_string()),
+    }
+    tx_cache.subtract_dct_balance(&tx_input.to, token_identifier, 0, &amount);
+
+    Ok(TxResult::empty())
+}
+
+/// Args: token identifier.
+pub fn execute_pause(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 1)?;
+    let token = load_token_owned_by_caller(tx_input, tx_cache, tx_input.args[0].as_slice())?;
+    require_property(&token, b"canPause")?;
+    if token.is_paused() {
+        return Err("cannot pause an already paused token".to_string());
+    }
+    token.set_paused(true);
+
+    Ok(TxResult::empty())
+}
+
+/// Args: token identifier.
+pub fn execute_unpause(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 1)?;
+    let token = load_token_owned_by_caller(tx_input, tx_cache, tx_input.args[0].as_slice())?;
+    require_property(&token, b"canPause")?;
+    if !token.is_paused() {
+        return Err("cannot unpause a token that is not paused".to_string());
+    }
+    token.set_paused(false);
+
+    Ok(TxResult::empty())
+}
+
+/// Args: token identifier, address.
+pub fn execute_freeze(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    set_frozen(tx_input, tx_cache, 0, true)
+}
+
+/// Args: token identifier, address.
+pub fn execute_unfreeze(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    set_frozen(tx_input, tx_cache, 0, false)
+}
+
+/// Args: token identifier, nonce, address.
+pub fn execute_freeze_single_nft(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 3)?;
+    let nonce = single_nft_nonce(tx_input)?;
+    set_frozen(tx_input, tx_cache, nonce, true)
+}
+
+/// Args: token identifier, nonce, address.
+pub fn execute_unfreeze_single_nft(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 3)?;
+    let nonce = single_nft_nonce(tx_input)?;
+    set_frozen(tx_input, tx_cache, nonce, false)
+}
+
+/// The address is the last argument, the nonce (if any) comes before it.
+fn set_frozen(tx_input: &TxInput, tx_cache: &TxCache, nonce: u64, frozen: bool) -> SystemScResult {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let token = load_token_owned_by_caller(tx_input, tx_cache, token_identifier)?;
+    require_property(&token, b"canFreeze")?;
+
+    let address = Address::from_slice(tx_input.args[tx_input.args.len() - 1].as_slice());
+    if tx_cache.account_exists(&address) {
+        freeze_dct(tx_cache, &address, token_identifier, nonce, frozen)?;
+    }
+
+    Ok(TxResult::empty())
+}
+
+/// Args: token identifier, address.
+///
+/// Removes the entire balance of the token from a frozen account.
+pub fn execute_wipe(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    wipe(tx_input, tx_cache, 0)
+}
+
+/// Args: token identifier, nonce, address.
+///
+/// Removes a single frozen NFT from an account.
+pub fn execute_wipe_single_nft(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 3)?;
+    let nonce = single_nft_nonce(tx_input)?;
+    wipe(tx_input, tx_cache, nonce)
+}
+
+fn wipe(tx_input: &TxInput, tx_cache: &TxCache, nonce: u64) -> SystemScResult {
+    check_num_args(tx_input, 2)?;
+    let token_identifier = tx_input.args[0].as_slice();
+    let token = load_token_owned_by_caller(tx_input, tx_cache, token_identifier)?;
+    require_property(&token, b"canWipe")?;
+
+    let address = Address::from_slice(tx_input.args[tx_input.args.len() - 1].as_slice());
+    if tx_cache.account_exists(&address) {
+        wipe_dct(tx_cache, &address, token_identifier, nonce)?;
+    }
+
+    Ok(TxResult::empty())
+}
+
+fn single_nft_nonce(tx_input: &TxInput) -> Result<u64, String> {
+    match u64::top_decode(tx_input.args[1].as_slice()) {
+        Ok(nonce) if nonce > 0 => Ok(nonce),
+        _ => Err("invalid argument".to_string()),
+    }
+}
+
+/// Args: token identifier, number of decimals.
+pub fn execute_change_sft_to_meta_dct(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 2)?;
+    let token = load_token_owned_by_caller(tx_input, tx_cache, tx_input.args[0].as_slice())?;
+    if token.token_type() != SEMI_FUNGIBLE_TOKEN_TYPE {
+        return Err("change can happen to semi fungible tokens only".to_string());
+    }
+    let num_decimals_arg = tx_input.args[1].as_slice();
+    if u32::top_decode(num_decimals_arg).is_err() {
+        return Err("invalid number of decimals".to_string());
+    }
+    token.set_token_type(META_TOKEN_TYPE);
+    token.set_num_decimals(num_decimals_arg);
+
+    Ok(TxResult::empty())
+}
+
+/// Args: token identifier, new owner.
+pub fn execute_transfer_ownership(tx_input: &TxInput, tx_cache: &TxCache) -> SystemScResult {
+    check_num_args(tx_input, 2)?;
+    let token = load_token_owned_by_caller(tx_input, tx_cache, tx_input.args[0].as_slice())?;
+    require_property(&token, b"canChangeOwner")?;
+    if tx_input.args[1].len() != Address::len_bytes() {
+        return Err("invalid argument".to_string());
+    }
+    token.set_owner(&Address::from_slice(tx_input.args[1].as_slice()));
+
+    Ok(TxResult::empty())
+}
+
+fn require_fungible(token: &SystemScToken) -> Result<(), String> {
+    if token.token_type() != FUNGIBLE_TOKEN_TYPE {
+        return Err("token is not fungible".to_string());
+    }
+    Ok(())
+}
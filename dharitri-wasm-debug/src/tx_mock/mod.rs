@@ -2,12 +2,14 @@
 
 mod blockchain_rng;
 mod tx_async_call_data;
+mod tx_async_promise;
 mod tx_cache;
 mod tx_cache_balance_util;
 mod tx_cache_source;
 mod tx_context;
 mod tx_context_ref;
 mod tx_context_stack;
+mod tx_cross_shard_call;
 mod tx_input;
 mod tx_input_util;
 mod tx_log;
@@ -15,14 +17,18 @@ mod tx_managed_types;
 mod tx_panic;
 mod tx_result;
 mod tx_result_calls;
+mod tx_storage_diff;
+mod tx_trace;
 
 pub use blockchain_rng::*;
 pub use tx_async_call_data::*;
+pub use tx_async_promise::*;
 pub use tx_cache::*;
 pub use tx_cache_source::*;
 pub use tx_context::*;
 pub use tx_context_ref::*;
 pub use tx_context_stack::*;
+pub use tx_cross_shard_call::*;
 pub use tx_input::*;
 pub use tx_input_util::*;
 pub use tx_log::*;
@@ -30,3 +36,5 @@ pub use tx_managed_types::*;
 pub use tx_panic::*;
 pub use tx_result::*;
 pub use tx_result_calls::*;
+pub use tx_storage_diff::*;
+pub use tx_trace::*;
@@ -29,6 +29,7 @@ pub fn async_call_tx_input(async_data: &AsyncCallTxData) -> TxInput {
         gas_limit: 1000,
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
+        ..Default::default()
     }
 }
 
@@ -45,12 +46,13 @@ pub fn async_callback_tx_input(async_data: &AsyncCallTxData, async_result: &TxRe
         from: async_data.to.clone(),
         to: async_data.from.clone(),
         moax_value: 0u32.into(),
-        dct_values: Vec::new(),
+        dct_values: async_result.result_calls.callback_dct_payments.clone(),
         func_name: b"callBack".to_vec(),
         args,
         gas_limit: 1000,
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
+        ..Default::default()
     }
 }
 
@@ -59,6 +61,7 @@ pub fn merge_results(mut original: TxResult, mut new: TxResult) -> TxResult {
         original.result_values.append(&mut new.result_values);
         original.result_logs.append(&mut new.result_logs);
         original.result_message = new.result_message;
+        original.storage_diff.merge(new.storage_diff);
         original
     } else {
         new
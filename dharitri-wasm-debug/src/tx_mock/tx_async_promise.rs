@@ -0,0 +1,43 @@
+use crate::tx_mock::{async_call_tx_input, async_callback_tx_input, TxInput, TxResult};
+
+use alloc::vec::Vec;
+
+use super::AsyncCallTxData;
+
+/// An async call registered via `create_async_call_raw`.
+/// Unlike the legacy async call, it does not end the execution of the transaction.
+#[derive(Debug, Clone)]
+pub struct AsyncCallPromiseTxData {
+    pub call: AsyncCallTxData,
+    pub success_callback_closure: Vec<u8>,
+    pub error_callback_closure: Vec<u8>,
+    pub gas_limit: u64,
+    pub extra_gas_for_callback: u64,
+}
+
+pub fn promise_call_tx_input(promise: &AsyncCallPromiseTxData) -> TxInput {
+    let mut tx_input = async_call_tx_input(&promise.call);
+    tx_input.gas_limit = promise.gas_limit;
+    tx_input
+}
+
+/// Picks the callback closure based on the call result.
+/// Yields no callback at all if the corresponding closure is empty.
+pub fn promise_callback_tx_input(
+    promise: &AsyncCallPromiseTxData,
+    async_result: &TxResult,
+) -> Option<TxInput> {
+    let callback_closure = if async_result.result_status == 0 {
+        &promise.success_callback_closure
+    } else {
+        &promise.error_callback_closure
+    };
+    if callback_closure.is_empty() {
+        return None;
+    }
+
+    let mut tx_input = async_callback_tx_input(&promise.call, async_result);
+    tx_input.gas_limit = promise.extra_gas_for_callback;
+    tx_input.promise_callback_closure_data = callback_closure.clone();
+    Some(tx_input)
+}
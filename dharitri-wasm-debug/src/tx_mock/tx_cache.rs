@@ -12,7 +12,7 @@ use crate::{
     world_mock::{AccountData, BlockchainMock},
 };
 
-use super::TxCacheSource;
+use super::{TxCacheSource, TxStorageWrite};
 
 pub struct TxCache {
     source_ref: Rc<dyn TxCacheSource>,
@@ -48,6 +48,11 @@ impl TxCache {
         }
     }
 
+    pub fn account_exists(&self, address: &Address) -> bool {
+        self.load_account_if_necessary(address);
+        self.accounts.borrow().contains_key(address)
+    }
+
     pub fn with_account<R, F>(&self, address: &Address, f: F) -> R
     where
         F: FnOnce(&AccountData) -> R,
@@ -60,6 +65,20 @@ impl TxCache {
         f(account)
     }
 
+    /// Accounts missing from the world state are presumed to be empty,
+    /// e.g. users that never received anything.
+    pub fn with_account_or_default<R, F>(&self, address: &Address, f: F) -> R
+    where
+        F: FnOnce(&AccountData) -> R,
+        R: Default,
+    {
+        if self.account_exists(address) {
+            self.with_account(address, f)
+        } else {
+            R::default()
+        }
+    }
+
     pub fn with_account_mut<R, F>(&self, address: &Address, f: F) -> R
     where
         F: FnOnce(&mut AccountData) -> R,
@@ -122,6 +141,45 @@ impl BlockchainUpdate {
         }
     }
 
+    /// The storage changes these updates would make to the given state, sorted by address and key.
+    pub fn storage_writes(&self, blockchain: &BlockchainMock) -> Vec<TxStorageWrite> {
+        let mut addresses: Vec<_> = self.accounts.keys().collect();
+        addresses.sort_by_key(|address| address.as_bytes());
+
+        let mut writes = Vec::new();
+        for address in addresses {
+            let new_storage = &self.accounts[address].storage;
+            let old_storage = blockchain
+                .accounts
+                .get(address)
+                .map(|account| &account.storage);
+
+            let mut keys: Vec<_> = new_storage.keys().collect();
+            if let Some(old_storage) = old_storage {
+                keys.extend(old_storage.keys());
+            }
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                let old_value = old_storage
+                    .and_then(|storage| storage.get(key))
+                    .cloned()
+                    .unwrap_or_default();
+                let new_value = new_storage.get(key).cloned().unwrap_or_default();
+                if old_value != new_value {
+                    writes.push(TxStorageWrite {
+                        address: address.clone(),
+                        key: key.clone(),
+                        old_value,
+                        new_value,
+                    });
+                }
+            }
+        }
+        writes
+    }
+
     pub fn apply(self, blockchain: &mut BlockchainMock) {
         blockchain.accounts.extend(self.accounts.into_iter());
     }
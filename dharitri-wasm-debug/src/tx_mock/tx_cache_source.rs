@@ -12,6 +12,9 @@ pub trait TxCacheSource {
 
 impl TxCacheSource for TxCache {
     fn load_account(&self, address: &Address) -> Option<AccountData> {
+        if !self.account_exists(address) {
+            return None;
+        }
         Some(self.with_account(address, AccountData::clone))
     }
 
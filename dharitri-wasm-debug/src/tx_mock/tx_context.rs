@@ -1,7 +1,7 @@
 use crate::world_mock::{AccountData, AccountDct, BlockchainMock};
 use alloc::vec::Vec;
 use core::cell::RefCell;
-use dharitri_wasm::types::{Address, LockableStaticBuffer};
+use dharitri_wasm::types::{Address, CodeMetadata, LockableStaticBuffer};
 use num_bigint::BigUint;
 use num_traits::Zero;
 use std::{
@@ -51,6 +51,7 @@ impl TxContext {
             username: Vec::new(),
             contract_path: None,
             contract_owner: None,
+            code_metadata: CodeMetadata::DEFAULT,
         });
 
         let tx_input = TxInput {
@@ -63,6 +64,7 @@ impl TxContext {
             gas_limit: 0,
             gas_price: 0,
             tx_hash: b"dummy...........................".into(),
+            ..Default::default()
         };
 
         let b_rng = RefCell::new(BlockchainRng::new(&tx_input, &tx_cache));
@@ -146,6 +148,7 @@ impl TxContext {
         new_address: &Address,
         contract_path: Vec<u8>,
         contract_owner: Address,
+        code_metadata: CodeMetadata,
     ) {
         assert!(
             !self.tx_cache.blockchain_ref().account_exists(new_address),
@@ -161,6 +164,7 @@ impl TxContext {
             username: Vec::new(),
             contract_path: Some(contract_path),
             contract_owner: Some(contract_owner),
+            code_metadata,
         });
     }
 
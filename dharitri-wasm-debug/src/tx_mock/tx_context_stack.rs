@@ -1,4 +1,4 @@
-use super::TxContext;
+use super::{TxContext, TxResult, TxTrace, TxTraceFrame, TxTracer};
 
 use std::{cell::RefCell, rc::Rc};
 
@@ -7,27 +7,93 @@ thread_local!(
 );
 
 #[derive(Debug, Default)]
-pub struct TxContextStack(Vec<Rc<TxContext>>);
+pub struct TxContextStack {
+    contexts: Vec<Rc<TxContext>>,
+    tracer: Option<TxTracer>,
+}
 
 impl TxContextStack {
     pub fn static_peek() -> Rc<TxContext> {
         API_STACK.with(|cell| {
             let stack = cell.borrow();
-            stack.0.last().unwrap().clone()
+            stack.contexts.last().unwrap().clone()
         })
     }
 
     pub fn static_push(tx_context_rc: Rc<TxContext>) {
         API_STACK.with(|cell| {
             let mut stack = cell.borrow_mut();
-            stack.0.push(tx_context_rc);
+            stack.contexts.push(tx_context_rc);
         })
     }
 
     pub fn static_pop() -> Rc<TxContext> {
         API_STACK.with(|cell| {
             let mut stack = cell.borrow_mut();
-            stack.0.pop().unwrap()
+            stack.contexts.pop().unwrap()
+        })
+    }
+
+    /// Same as `static_push`, but also starts a new trace frame, if tracing is on.
+    /// Only used for actual contract executions, not for the dummy contexts.
+    pub fn static_push_traced(tx_context_rc: Rc<TxContext>) {
+        API_STACK.with(|cell| {
+            let mut stack = cell.borrow_mut();
+            if let Some(tracer) = &mut stack.tracer {
+                tracer.open_frame(tx_context_rc.input_ref());
+            }
+            stack.contexts.push(tx_context_rc);
+        })
+    }
+
+    /// Counterpart of `static_push_traced`, closes the trace frame with the execution result.
+    pub fn static_pop_traced(tx_result: &TxResult) -> Rc<TxContext> {
+        API_STACK.with(|cell| {
+            let mut stack = cell.borrow_mut();
+            if let Some(tracer) = &mut stack.tracer {
+                tracer.close_frame(tx_result);
+            }
+            stack.contexts.pop().unwrap()
+        })
+    }
+
+    /// Starts recording all contract executions on the current thread.
+    /// Anything recorded before is discarded.
+    pub fn static_start_trace() {
+        API_STACK.with(|cell| {
+            cell.borrow_mut().tracer = Some(TxTracer::default());
+        })
+    }
+
+    /// Stops recording and discards everything not yet taken.
+    pub fn static_stop_trace() {
+        API_STACK.with(|cell| {
+            cell.borrow_mut().tracer = None;
+        })
+    }
+
+    pub fn static_is_tracing() -> bool {
+        API_STACK.with(|cell| cell.borrow().tracer.is_some())
+    }
+
+    /// Retrieves the frames completed since tracing started or since the last call.
+    /// Returns an empty trace if tracing is off.
+    pub fn static_take_trace() -> TxTrace {
+        API_STACK.with(|cell| {
+            cell.borrow_mut()
+                .tracer
+                .as_mut()
+                .map(TxTracer::take_trace)
+                .unwrap_or_default()
+        })
+    }
+
+    /// Adds some data to the frame of the contract currently executing, if tracing is on.
+    pub fn static_trace<F: FnOnce(&mut TxTraceFrame)>(f: F) {
+        API_STACK.with(|cell| {
+            if let Some(tracer) = &mut cell.borrow_mut().tracer {
+                tracer.with_current_frame(f);
+            }
         })
     }
 }
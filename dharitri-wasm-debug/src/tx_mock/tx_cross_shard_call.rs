@@ -0,0 +1,24 @@
+use super::{AsyncCallPromiseTxData, AsyncCallTxData, TxInput};
+
+/// A call between accounts in different shards.
+/// It is delivered in a block after the one in which it was sent.
+#[derive(Debug, Clone)]
+pub enum CrossShardCall {
+    /// Once executed, its callback is sent back to the caller.
+    AsyncCall(AsyncCallTxData),
+
+    /// Once executed, its callback, if any, is sent back to the caller.
+    Promise(AsyncCallPromiseTxData),
+
+    /// Nothing gets sent back to the caller.
+    TransferExecute(AsyncCallTxData),
+
+    /// Executed in the shard of the original caller.
+    Callback(TxInput),
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingCrossShardCall {
+    pub sent_block_nonce: u64,
+    pub call: CrossShardCall,
+}
@@ -16,6 +16,8 @@ pub struct TxInput {
     pub gas_limit: u64,
     pub gas_price: u64,
     pub tx_hash: H256,
+    /// Handed back to the contract when executing a promise callback, empty otherwise.
+    pub promise_callback_closure_data: Vec<u8>,
 }
 
 impl fmt::Display for TxInput {
@@ -36,6 +38,12 @@ impl TxInput {
     }
 
     pub fn dummy() -> Self {
+        TxInput::default()
+    }
+}
+
+impl Default for TxInput {
+    fn default() -> Self {
         TxInput {
             from: Address::zero(),
             to: Address::zero(),
@@ -46,6 +54,7 @@ impl TxInput {
             gas_limit: 0,
             gas_price: 0,
             tx_hash: H256::zero(),
+            promise_callback_closure_data: Vec::new(),
         }
     }
 }
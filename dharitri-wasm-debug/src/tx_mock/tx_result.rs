@@ -2,7 +2,10 @@ use alloc::vec::Vec;
 
 use std::fmt;
 
-use super::{TxLog, TxPanic, TxResultCalls};
+use super::{TxLog, TxPanic, TxResultCalls, TxStorageDiff};
+
+/// Rust panics in contract code, such as arithmetic overflows, show up with this message.
+pub const UNHANDLED_PANIC_MESSAGE: &str = "panic occurred";
 
 #[derive(Clone, Default, Debug)]
 #[must_use]
@@ -12,6 +15,7 @@ pub struct TxResult {
     pub result_values: Vec<Vec<u8>>,
     pub result_logs: Vec<TxLog>,
     pub result_calls: TxResultCalls,
+    pub storage_diff: TxStorageDiff,
 }
 
 impl fmt::Display for TxResult {
@@ -37,6 +41,7 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            storage_diff: TxStorageDiff::default(),
         }
     }
 
@@ -51,17 +56,19 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            storage_diff: TxStorageDiff::default(),
         }
     }
 
     pub fn from_panic_string(_s: &str) -> Self {
         TxResult {
             result_status: 4,
-            result_message: "panic occurred".to_string(),
+            result_message: UNHANDLED_PANIC_MESSAGE.to_string(),
             // result_message: _s.to_string(),
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            storage_diff: TxStorageDiff::default(),
         }
     }
 
@@ -76,6 +83,7 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            storage_diff: TxStorageDiff::default(),
         }
     }
 
@@ -91,6 +99,19 @@ impl TxResult {
             );
             self.result_calls.async_call = Some(sync_result_async.clone());
         }
+        self.result_calls
+            .promises
+            .extend_from_slice(sync_call_result.result_calls.promises.as_slice());
+        self.result_calls
+            .cross_shard_transfer_execute
+            .extend_from_slice(
+                sync_call_result
+                    .result_calls
+                    .cross_shard_transfer_execute
+                    .as_slice(),
+            );
+        self.storage_diff
+            .merge(sync_call_result.storage_diff.clone());
     }
 
     pub fn assert_ok(&self) {
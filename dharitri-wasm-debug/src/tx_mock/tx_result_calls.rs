@@ -1,16 +1,31 @@
-use super::AsyncCallTxData;
+use super::{AsyncCallPromiseTxData, AsyncCallTxData, TxInputDCT};
 
 #[derive(Clone, Default, Debug)]
 pub struct TxResultCalls {
     pub async_call: Option<AsyncCallTxData>,
+    /// Promises, in the order in which they were registered.
+    pub promises: Vec<AsyncCallPromiseTxData>,
+    /// Transfer-execute calls to other shards.
+    /// The ones within the same shard are performed right away, like sync calls.
+    pub cross_shard_transfer_execute: Vec<AsyncCallTxData>,
+    /// Tokens sent back to the caller together with the callback.
+    /// Only the DCT system SC mock produces them, e.g. the initial supply of a newly issued token.
+    pub callback_dct_payments: Vec<TxInputDCT>,
 }
 
 impl TxResultCalls {
     pub fn empty() -> Self {
-        TxResultCalls { async_call: None }
+        TxResultCalls {
+            async_call: None,
+            promises: Vec::new(),
+            cross_shard_transfer_execute: Vec::new(),
+            callback_dct_payments: Vec::new(),
+        }
     }
 
     pub fn is_empty(&self) -> bool {
         self.async_call.is_none()
+            && self.promises.is_empty()
+            && self.cross_shard_transfer_execute.is_empty()
     }
 }
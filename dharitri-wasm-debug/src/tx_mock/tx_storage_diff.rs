@@ -0,0 +1,250 @@
+use alloc::vec::Vec;
+use dharitri_wasm::types::Address;
+use std::fmt;
+
+use crate::{address_hex, verbose_hex};
+
+/// The storage keys touched by a transaction.
+///
+/// Reads are listed in the order they first occurred, with the value seen at that moment.
+/// Writes are sorted by address and key, and only contain the values that actually changed.
+#[derive(Clone, Debug, Default)]
+pub struct TxStorageDiff {
+    pub reads: Vec<TxStorageRead>,
+    pub writes: Vec<TxStorageWrite>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxStorageRead {
+    pub address: Address,
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+}
+
+/// Missing storage entries are considered empty, same as in the VM.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxStorageWrite {
+    pub address: Address,
+    pub key: Vec<u8>,
+    pub old_value: Vec<u8>,
+    pub new_value: Vec<u8>,
+}
+
+impl TxStorageDiff {
+    /// Repeated reads of the same key are only recorded once.
+    pub fn add_read(&mut self, address: &Address, key: &[u8], value: &[u8]) {
+        if self
+            .reads
+            .iter()
+            .any(|read| &read.address == address && read.key == key)
+        {
+            return;
+        }
+        self.reads.push(TxStorageRead {
+            address: address.clone(),
+            key: key.to_vec(),
+            value: value.to_vec(),
+        });
+    }
+
+    /// Adds the storage accessed by a later execution in the same transaction, e.g. an async callback.
+    /// Keys written by both keep the original old value.
+    pub fn merge(&mut self, other: TxStorageDiff) {
+        for read in other.reads {
+            self.add_read(&read.address, &read.key, &read.value);
+        }
+        for write in other.writes {
+            if let Some(existing) = self
+                .writes
+                .iter_mut()
+                .find(|existing| existing.address == write.address && existing.key == write.key)
+            {
+                existing.new_value = write.new_value;
+            } else {
+                self.writes.push(write);
+            }
+        }
+        self.writes
+            .retain(|write| write.old_value != write.new_value);
+        self.writes
+            .sort_by(|a, b| (a.address.as_bytes(), &a.key).cmp(&(b.address.as_bytes(), &b.key)));
+    }
+
+    pub fn written_keys(&self, address: &Address) -> Vec<&[u8]> {
+        self.writes
+            .iter()
+            .filter(|write| &write.address == address)
+            .map(|write| write.key.as_slice())
+            .collect()
+    }
+
+    pub fn read_keys(&self, address: &Address) -> Vec<&[u8]> {
+        self.reads
+            .iter()
+            .filter(|read| &read.address == address)
+            .map(|read| read.key.as_slice())
+            .collect()
+    }
+
+    /// Panics if the transaction changed any storage other than that of the given address,
+    /// under keys starting with one of the given prefixes.
+    ///
+    /// Using the storage mapper name as prefix covers all the keys of that mapper.
+    pub fn assert_no_unexpected_writes(&self, address: &Address, allowed_key_prefixes: &[&[u8]]) {
+        let unexpected: Vec<String> = self
+            .writes
+            .iter()
+            .filter(|write| {
+                &write.address != address
+                    || !allowed_key_prefixes
+                        .iter()
+                        .any(|prefix| write.key.starts_with(prefix))
+            })
+            .map(TxStorageWrite::to_string)
+            .collect();
+        assert!(
+            unexpected.is_empty(),
+            "Unexpected storage writes:\n{}",
+            unexpected.join("\n")
+        );
+    }
+}
+
+impl fmt::Display for TxStorageRead {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "read {} {}: {}",
+            address_hex(&self.address),
+            storage_key_pretty(&self.key),
+            verbose_hex(&self.value)
+        )
+    }
+}
+
+impl fmt::Display for TxStorageWrite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "write {} {}: {} -> {}",
+            address_hex(&self.address),
+            storage_key_pretty(&self.key),
+            verbose_hex(&self.old_value),
+            verbose_hex(&self.new_value)
+        )
+    }
+}
+
+/// One line per access, writes first.
+impl fmt::Display for TxStorageDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for write in &self.writes {
+            writeln!(f, "{}", write)?;
+        }
+        for read in &self.reads {
+            writeln!(f, "{}", read)?;
+        }
+        Ok(())
+    }
+}
+
+/// Key suffixes added by the storage mappers.
+/// Suffixes that start with another one in the list come first.
+const MAPPER_KEY_SUFFIXES: &[&[u8]] = &[
+    b"_address_to_id",
+    b"_id_to_address",
+    b"_count",
+    b".node_links",
+    b".node_id",
+    b".node",
+    b".item",
+    b".len",
+    b".info",
+    b".value",
+    b".mapped",
+    b".storage",
+    b".index",
+    b".mapping",
+    b".counter",
+    b".attr",
+    b".nonce",
+];
+
+/// Suffixes followed by a `u32` index or id.
+const MAPPER_INDEX_SUFFIXES: &[&[u8]] = &[
+    b"_id_to_address",
+    b".node_links",
+    b".node",
+    b".item",
+    b".value",
+];
+
+/// Formats a storage key in Denali value syntax, e.g. `str:list.item|u32:3`.
+///
+/// Keys produced by storage mappers are split into the mapper name, the mapper suffix and the rest,
+/// which is shown as a `u32` where the mapper uses an index, as hex otherwise.
+/// The key is shown as `str:` as far as it is printable, the rest is hex.
+pub fn storage_key_pretty(key: &[u8]) -> String {
+    let mut segments = Vec::new();
+    match find_mapper_suffix(key) {
+        Some((position, suffix)) => {
+            push_bytes_segments(&mut segments, &key[..position]);
+            let suffix_str = String::from_utf8_lossy(suffix);
+            match segments.last_mut() {
+                Some(last) if last.starts_with("str:") => last.push_str(&suffix_str),
+                _ => segments.push(format!("str:{}", suffix_str)),
+            }
+
+            let rest = &key[position + suffix.len()..];
+            if rest.len() == 4 && MAPPER_INDEX_SUFFIXES.contains(&suffix) {
+                let index = u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]);
+                segments.push(format!("u32:{}", index));
+            } else if !rest.is_empty() {
+                segments.push(verbose_hex(rest));
+            }
+        },
+        None => push_bytes_segments(&mut segments, key),
+    }
+
+    if segments.is_empty() {
+        verbose_hex(key)
+    } else {
+        segments.join("|")
+    }
+}
+
+/// The earliest suffix occurrence in the key, if any.
+fn find_mapper_suffix(key: &[u8]) -> Option<(usize, &'static [u8])> {
+    let mut found: Option<(usize, &'static [u8])> = None;
+    for &suffix in MAPPER_KEY_SUFFIXES {
+        if let Some(position) = key
+            .windows(suffix.len())
+            .position(|window| window == suffix)
+        {
+            let is_earliest = match found {
+                Some((found_position, _)) => position < found_position,
+                None => true,
+            };
+            if is_earliest {
+                found = Some((position, suffix));
+            }
+        }
+    }
+    found
+}
+
+fn push_bytes_segments(segments: &mut Vec<String>, bytes: &[u8]) {
+    let printable_len = bytes
+        .iter()
+        .position(|b| !(b' '..=b'~').contains(b))
+        .unwrap_or(bytes.len());
+    if printable_len > 0 {
+        segments.push(format!(
+            "str:{}",
+            String::from_utf8_lossy(&bytes[..printable_len])
+        ));
+    }
+    if printable_len < bytes.len() {
+        segments.push(verbose_hex(&bytes[printable_len..]));
+    }
+}
@@ -0,0 +1,274 @@
+use alloc::vec::Vec;
+use dharitri_wasm::types::Address;
+use num_bigint::BigUint;
+use serde_json::{json, Value};
+use std::fmt::Write;
+
+use crate::{address_hex, bytes_to_string, verbose_hex};
+
+use super::{TxInput, TxInputDCT, TxLog, TxResult};
+
+/// How a trace gets printed by the Denali runner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxTraceFormat {
+    CallTree,
+    Json,
+}
+
+/// The contract executions recorded while tracing is on.
+///
+/// Executions triggered by other contracts (e.g. via `execute_on_dest_context`) are nested in their caller.
+/// Async calls and callbacks are executed after their caller finished, so they show up as separate frames.
+#[derive(Clone, Debug, Default)]
+pub struct TxTrace {
+    pub frames: Vec<TxTraceFrame>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TxTraceStorageAccess {
+    pub address: Address,
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+}
+
+/// Everything that happened in one contract execution.
+#[derive(Clone, Debug)]
+pub struct TxTraceFrame {
+    pub caller: Address,
+    pub callee: Address,
+    pub endpoint: Vec<u8>,
+    pub args: Vec<Vec<u8>>,
+    pub moax_value: BigUint,
+    pub dct_values: Vec<TxInputDCT>,
+    pub storage_reads: Vec<TxTraceStorageAccess>,
+    pub storage_writes: Vec<TxTraceStorageAccess>,
+    /// Only the logs emitted by this contract, the ones from nested calls are in the nested frames.
+    pub logs: Vec<TxLog>,
+    pub result_status: u64,
+    pub result_message: String,
+    pub result_values: Vec<Vec<u8>>,
+    pub children: Vec<TxTraceFrame>,
+}
+
+impl TxTraceFrame {
+    pub fn new(tx_input: &TxInput) -> Self {
+        TxTraceFrame {
+            caller: tx_input.from.clone(),
+            callee: tx_input.to.clone(),
+            endpoint: tx_input.func_name.clone(),
+            args: tx_input.args.clone(),
+            moax_value: tx_input.moax_value.clone(),
+            dct_values: tx_input.dct_values.clone(),
+            storage_reads: Vec::new(),
+            storage_writes: Vec::new(),
+            logs: Vec::new(),
+            result_status: 0,
+            result_message: String::new(),
+            result_values: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn set_result(&mut self, tx_result: &TxResult) {
+        self.result_status = tx_result.result_status;
+        self.result_message = tx_result.result_message.clone();
+        self.result_values = tx_result.result_values.clone();
+    }
+
+    fn write_call_tree(&self, out: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth);
+        writeln!(
+            out,
+            "{}{}.{} <- {}",
+            indent,
+            address_hex(&self.callee),
+            bytes_to_string(&self.endpoint),
+            address_hex(&self.caller)
+        )
+        .unwrap();
+
+        let indent = "  ".repeat(depth + 1);
+        if !self.args.is_empty() {
+            writeln!(out, "{}args: {}", indent, hex_list(&self.args).join(", ")).unwrap();
+        }
+        if self.moax_value != BigUint::default() {
+            writeln!(out, "{}moax: {}", indent, self.moax_value).unwrap();
+        }
+        for dct in &self.dct_values {
+            writeln!(
+                out,
+                "{}dct: {} nonce {} amount {}",
+                indent,
+                bytes_to_string(&dct.token_identifier),
+                dct.nonce,
+                dct.value
+            )
+            .unwrap();
+        }
+        for read in &self.storage_reads {
+            writeln!(
+                out,
+                "{}storage read: {}",
+                indent,
+                storage_access_string(read, &self.callee)
+            )
+            .unwrap();
+        }
+        for write in &self.storage_writes {
+            writeln!(
+                out,
+                "{}storage write: {}",
+                indent,
+                storage_access_string(write, &self.callee)
+            )
+            .unwrap();
+        }
+        for log in &self.logs {
+            writeln!(
+                out,
+                "{}log: topics [{}], data {}",
+                indent,
+                hex_list(&log.topics).join(", "),
+                verbose_hex(&log.data)
+            )
+            .unwrap();
+        }
+        for child in &self.children {
+            child.write_call_tree(out, depth + 1);
+        }
+        if self.result_status == 0 {
+            writeln!(
+                out,
+                "{}ok: [{}]",
+                indent,
+                hex_list(&self.result_values).join(", ")
+            )
+            .unwrap();
+        } else {
+            writeln!(
+                out,
+                "{}error {}: {}",
+                indent, self.result_status, self.result_message
+            )
+            .unwrap();
+        }
+    }
+
+    fn to_json_value(&self) -> Value {
+        json!({
+            "caller": address_hex(&self.caller),
+            "callee": address_hex(&self.callee),
+            "endpoint": bytes_to_string(&self.endpoint),
+            "args": hex_list(&self.args),
+            "moaxValue": self.moax_value.to_string(),
+            "dctValues": self.dct_values.iter().map(|dct| json!({
+                "tokenIdentifier": bytes_to_string(&dct.token_identifier),
+                "nonce": dct.nonce,
+                "value": dct.value.to_string(),
+            })).collect::<Vec<_>>(),
+            "storageReads": self.storage_reads.iter().map(storage_access_json).collect::<Vec<_>>(),
+            "storageWrites": self.storage_writes.iter().map(storage_access_json).collect::<Vec<_>>(),
+            "logs": self.logs.iter().map(|log| json!({
+                "address": address_hex(&log.address),
+                "endpoint": bytes_to_string(&log.endpoint),
+                "topics": hex_list(&log.topics),
+                "data": verbose_hex(&log.data),
+            })).collect::<Vec<_>>(),
+            "status": self.result_status,
+            "message": self.result_message,
+            "out": hex_list(&self.result_values),
+            "calls": self.children.iter().map(TxTraceFrame::to_json_value).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl TxTrace {
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// One line per call, with everything that happened in it indented below.
+    pub fn to_call_tree(&self) -> String {
+        let mut out = String::new();
+        for frame in &self.frames {
+            frame.write_call_tree(&mut out, 0);
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        let frames: Vec<Value> = self
+            .frames
+            .iter()
+            .map(TxTraceFrame::to_json_value)
+            .collect();
+        serde_json::to_string_pretty(&frames).unwrap()
+    }
+
+    pub fn render(&self, format: TxTraceFormat) -> String {
+        match format {
+            TxTraceFormat::CallTree => self.to_call_tree(),
+            TxTraceFormat::Json => self.to_json(),
+        }
+    }
+}
+
+/// Records the frames as the contexts get pushed onto and popped from the stack.
+#[derive(Debug, Default)]
+pub(crate) struct TxTracer {
+    open_frames: Vec<TxTraceFrame>,
+    trace: TxTrace,
+}
+
+impl TxTracer {
+    pub fn open_frame(&mut self, tx_input: &TxInput) {
+        self.open_frames.push(TxTraceFrame::new(tx_input));
+    }
+
+    pub fn close_frame(&mut self, tx_result: &TxResult) {
+        let mut frame = self.open_frames.pop().unwrap();
+        frame.set_result(tx_result);
+        if let Some(parent) = self.open_frames.last_mut() {
+            parent.children.push(frame);
+        } else {
+            self.trace.frames.push(frame);
+        }
+    }
+
+    /// Events outside of any contract execution are not recorded.
+    pub fn with_current_frame<F: FnOnce(&mut TxTraceFrame)>(&mut self, f: F) {
+        if let Some(frame) = self.open_frames.last_mut() {
+            f(frame);
+        }
+    }
+
+    pub fn take_trace(&mut self) -> TxTrace {
+        core::mem::take(&mut self.trace)
+    }
+}
+
+fn hex_list(values: &[Vec<u8>]) -> Vec<String> {
+    values.iter().map(|value| verbose_hex(value)).collect()
+}
+
+/// The address is only shown for the storage of other accounts.
+fn storage_access_string(access: &TxTraceStorageAccess, callee: &Address) -> String {
+    let key_value = format!(
+        "{} -> {}",
+        verbose_hex(&access.key),
+        verbose_hex(&access.value)
+    );
+    if &access.address == callee {
+        key_value
+    } else {
+        format!("{} {}", address_hex(&access.address), key_value)
+    }
+}
+
+fn storage_access_json(access: &TxTraceStorageAccess) -> Value {
+    json!({
+        "address": address_hex(&access.address),
+        "key": verbose_hex(&access.key),
+        "value": verbose_hex(&access.value),
+    })
+}
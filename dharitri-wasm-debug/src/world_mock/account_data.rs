@@ -1,5 +1,5 @@
 use alloc::vec::Vec;
-use dharitri_wasm::types::Address;
+use dharitri_wasm::types::{Address, CodeMetadata};
 use num_bigint::BigUint;
 use std::{collections::HashMap, fmt, fmt::Write};
 
@@ -19,6 +19,16 @@ pub struct AccountData {
     pub username: Vec<u8>,
     pub contract_path: Option<Vec<u8>>,
     pub contract_owner: Option<Address>,
+    pub code_metadata: CodeMetadata,
+}
+
+impl AccountData {
+    /// The metadata of contracts that were not given one explicitly,
+    /// e.g. in a Denali `setState` or `scDeploy` step.
+    /// Nothing is restricted, so only contracts that opt out of something are affected.
+    pub fn default_contract_code_metadata() -> CodeMetadata {
+        CodeMetadata::all()
+    }
 }
 
 impl fmt::Display for AccountData {
@@ -58,6 +58,48 @@ impl AccountDct {
         dct_data.roles = DctRoles::new(roles);
     }
 
+    pub fn set_frozen(&mut self, token_identifier: Vec<u8>, frozen: bool) {
+        let dct_data = self
+            .0
+            .entry(token_identifier.clone())
+            .or_insert_with(|| DctData {
+                token_identifier,
+                instances: DctInstances::new(),
+                last_nonce: 0,
+                roles: DctRoles::default(),
+                frozen: false,
+            });
+        dct_data.frozen = frozen;
+    }
+
+    /// Freezes or unfreezes a single NFT.
+    /// Returns false if the account does not hold the NFT.
+    pub fn set_nft_frozen(&mut self, token_identifier: &[u8], nonce: u64, frozen: bool) -> bool {
+        let opt_instance = self
+            .get_mut_by_identifier(token_identifier)
+            .and_then(|dct_data| dct_data.instances.get_mut_by_nonce(nonce));
+        if let Some(instance) = opt_instance {
+            instance.frozen = frozen;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// A token instance is frozen either when the whole token is frozen for the account,
+    /// or when that single NFT is.
+    pub fn is_frozen(&self, token_identifier: &[u8], nonce: u64) -> bool {
+        if let Some(dct_data) = self.get_by_identifier(token_identifier) {
+            dct_data.frozen
+                || matches!(
+                    dct_data.instances.get_by_nonce(nonce),
+                    Some(instance) if instance.frozen
+                )
+        } else {
+            false
+        }
+    }
+
     /// Will provide a clone.
     pub fn get_by_identifier_or_default(&self, identifier: &[u8]) -> DctData {
         if let Some(value) = self.0.get(identifier) {
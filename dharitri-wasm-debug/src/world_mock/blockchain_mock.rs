@@ -2,14 +2,14 @@ use dharitri_wasm::types::Address;
 use denali::{interpret_trait::InterpreterContext, value_interpreter::interpret_string};
 use num_bigint::BigUint;
 use num_traits::Zero;
-use std::{collections::HashMap, path::PathBuf, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
 
 use crate::{
-    tx_mock::{BlockchainUpdate, TxCache},
+    tx_mock::{BlockchainUpdate, PendingCrossShardCall, TxCache, TxTrace, TxTraceFormat},
     ContractMap,
 };
 
-use super::{AccountData, BlockInfo};
+use super::{AccountData, BlockInfo, EndpointCoverage};
 
 const DHARITRI_REWARD_KEY: &[u8] = b"DHARITRIreward";
 
@@ -21,6 +21,12 @@ pub struct BlockchainMock {
     pub current_block_info: BlockInfo,
     pub contract_map: ContractMap,
     pub current_dir: PathBuf,
+    pub num_shards: u32,
+    pub pending_cross_shard_calls: Vec<PendingCrossShardCall>,
+    pub trace_format: Option<TxTraceFormat>,
+    /// The traces printed by the Denali runner, each with the id of the step that produced it.
+    pub tx_traces: RefCell<Vec<(String, TxTrace)>>,
+    pub endpoint_calls: RefCell<EndpointCoverage>,
 }
 
 impl BlockchainMock {
@@ -32,6 +38,11 @@ impl BlockchainMock {
             current_block_info: BlockInfo::new(),
             contract_map: ContractMap::default(),
             current_dir: std::env::current_dir().unwrap(),
+            num_shards: 1,
+            pending_cross_shard_calls: Vec::new(),
+            trace_format: None,
+            tx_traces: RefCell::new(Vec::new()),
+            endpoint_calls: RefCell::new(EndpointCoverage::default()),
         }
     }
 }
@@ -43,6 +54,11 @@ impl Default for BlockchainMock {
 }
 
 impl BlockchainMock {
+    /// Makes the Denali runner print a trace of each transaction, before checking its outcome.
+    pub fn enable_trace(&mut self, format: TxTraceFormat) {
+        self.trace_format = Some(format);
+    }
+
     pub fn account_exists(&self, address: &Address) -> bool {
         self.accounts.contains_key(address)
     }
@@ -3,7 +3,7 @@ use dharitri_wasm::types::Address;
 
 use std::fmt::Write;
 
-use crate::address_hex;
+use crate::{address_hex, tx_execution::is_system_sc_address};
 
 use super::AccountData;
 
@@ -46,7 +46,9 @@ impl BlockchainMock {
     }
 
     pub fn validate_account(&self, account: &AccountData) {
-        let is_sc = is_smart_contract_address(&account.address);
+        // the DCT system SC is mocked natively, it has no code
+        let is_sc = is_smart_contract_address(&account.address)
+            && !is_system_sc_address(&account.address);
         let has_code = self.check_account_has_code(account);
 
         assert!(
@@ -0,0 +1,45 @@
+use crate::abi_endpoint_kinds;
+
+use super::{BlockchainMock, EndpointCoverage, EndpointKind};
+
+impl BlockchainMock {
+    /// Called by the endpoint dispatch after each contract execution, including the nested ones.
+    /// Callbacks are recorded under the name from their closure, when there is one.
+    pub fn record_endpoint_call(
+        &self,
+        contract_bytes: &[u8],
+        func_name: &[u8],
+        callback_name: Option<&[u8]>,
+        result_status: u64,
+    ) {
+        let func_name = String::from_utf8_lossy(func_name);
+        let kind = self.contract_map.endpoint_kind(contract_bytes, &func_name);
+        let endpoint_name = match callback_name {
+            Some(callback_name) if kind == EndpointKind::Callback => {
+                String::from_utf8_lossy(callback_name)
+            },
+            _ => func_name,
+        };
+        self.endpoint_calls.borrow_mut().add_call(
+            &self.contract_map.contract_name(contract_bytes),
+            &endpoint_name,
+            kind,
+            result_status,
+        );
+    }
+
+    /// The endpoint calls recorded so far, together with all the registered contracts.
+    /// The endpoints of contracts with a registered ABI are listed even if they were never called.
+    pub fn endpoint_coverage(&self) -> EndpointCoverage {
+        let mut coverage = self.endpoint_calls.borrow().clone();
+        for (contract_name, abi) in self.contract_map.registered_contracts() {
+            coverage.contracts.entry(contract_name.clone()).or_default();
+            if let Some(abi) = abi {
+                for (endpoint_name, kind) in abi_endpoint_kinds(abi) {
+                    coverage.add_endpoint(&contract_name, endpoint_name, kind);
+                }
+            }
+        }
+        coverage
+    }
+}
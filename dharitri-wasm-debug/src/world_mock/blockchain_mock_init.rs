@@ -1,8 +1,9 @@
 use std::path::{Path, PathBuf};
 
 use dharitri_wasm::{
+    abi::ContractAbi,
     api::ExternalViewApi,
-    contract_base::{CallableContract, CallableContractBuilder},
+    contract_base::{CallableContract, CallableContractBuilder, ContractAbiProvider},
 };
 use denali::{interpret_trait::InterpreterContext, value_interpreter::interpret_string};
 
@@ -44,15 +45,37 @@ impl BlockchainMock {
             expression,
             &InterpreterContext::new(self.current_dir.clone()),
         );
+        self.contract_map
+            .set_contract_name(contract_bytes.clone(), expression.to_string());
         self.contract_map
             .register_contract(contract_bytes, new_contract_obj);
     }
 
+    /// Lets the endpoint coverage list the endpoints that were never called.
+    /// The contract is identified by the same expression as when registering it.
+    /// Only needed for contracts registered without a builder,
+    /// `register_contract_builder` registers the ABI of the contracts generated by the framework.
+    pub fn register_contract_abi<A: ContractAbiProvider>(&mut self, expression: &str) {
+        self.register_abi(expression, A::abi());
+    }
+
+    fn register_abi(&mut self, expression: &str, abi: ContractAbi) {
+        let contract_bytes = interpret_string(
+            expression,
+            &InterpreterContext::new(self.current_dir.clone()),
+        );
+        self.contract_map.set_contract_abi(contract_bytes, abi);
+    }
+
+    /// Also registers the contract ABI, if the builder provides it.
     pub fn register_contract_builder<B: CallableContractBuilder>(
         &mut self,
         expression: &str,
         contract_builder: B,
     ) {
+        if let Some(abi) = contract_builder.abi() {
+            self.register_abi(expression, abi);
+        }
         self.register_contract_obj(expression, contract_builder.new_contract_obj::<DebugApi>())
     }
 
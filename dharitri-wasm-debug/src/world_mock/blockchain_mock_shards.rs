@@ -0,0 +1,92 @@
+use dharitri_wasm::types::Address;
+
+use crate::tx_mock::{CrossShardCall, PendingCrossShardCall};
+
+use super::{is_smart_contract_address, BlockchainMock};
+
+/// The shard of the system smart contracts.
+pub const METACHAIN_SHARD_ID: u32 = u32::MAX;
+
+const MAX_NUM_SHARDS: u32 = 256;
+const SC_ADDRESS_NUM_INIT_BYTES: usize = 10;
+const METACHAIN_SC_ADDRESS_NUM_ZERO_BYTES: usize = 5;
+
+impl BlockchainMock {
+    /// Splits the accounts into shards, by address.
+    /// Calls between contracts in different shards are then delivered in a later block.
+    ///
+    /// With a single shard, which is the default, all calls are executed right away.
+    pub fn set_num_shards(&mut self, num_shards: u32) {
+        assert!(
+            (1..=MAX_NUM_SHARDS).contains(&num_shards),
+            "the number of shards must be between 1 and {}",
+            MAX_NUM_SHARDS
+        );
+        self.num_shards = num_shards;
+    }
+
+    pub fn is_sharded(&self) -> bool {
+        self.num_shards > 1
+    }
+
+    pub fn get_shard_of_address(&self, address: &Address) -> u32 {
+        compute_shard_id(address, self.num_shards)
+    }
+
+    pub fn is_cross_shard(&self, from: &Address, to: &Address) -> bool {
+        self.is_sharded() && self.get_shard_of_address(from) != self.get_shard_of_address(to)
+    }
+
+    pub fn add_cross_shard_call(&mut self, call: CrossShardCall) {
+        self.pending_cross_shard_calls.push(PendingCrossShardCall {
+            sent_block_nonce: self.current_block_info.block_nonce,
+            call,
+        });
+    }
+
+    /// Removes the calls sent in the blocks before the current one, in the order in which they were sent.
+    pub fn take_deliverable_cross_shard_calls(&mut self) -> Vec<CrossShardCall> {
+        let current_block_nonce = self.current_block_info.block_nonce;
+        let (deliverable, pending) = std::mem::take(&mut self.pending_cross_shard_calls)
+            .into_iter()
+            .partition(|pending_call| pending_call.sent_block_nonce < current_block_nonce);
+        self.pending_cross_shard_calls = pending;
+        deliverable
+            .into_iter()
+            .map(|pending_call: PendingCrossShardCall| pending_call.call)
+            .collect()
+    }
+}
+
+/// Same rule as the protocol: the shard is given by the last bits of the address,
+/// just enough of them to cover all the shards.
+/// When these bits point to a shard that does not exist, one bit less is used.
+///
+/// System smart contracts live on the metachain.
+pub fn compute_shard_id(address: &Address, num_shards: u32) -> u32 {
+    if is_metachain_sc_address(address) {
+        return METACHAIN_SHARD_ID;
+    }
+    if num_shards <= 1 {
+        return 0;
+    }
+
+    let num_bits = 32 - (num_shards - 1).leading_zeros();
+    let mask_high = (1u32 << num_bits) - 1;
+    let mask_low = (1u32 << (num_bits - 1)) - 1;
+    let last_byte = u32::from(address.as_bytes()[Address::len_bytes() - 1]);
+    let shard = last_byte & mask_high;
+    if shard < num_shards {
+        shard
+    } else {
+        last_byte & mask_low
+    }
+}
+
+fn is_metachain_sc_address(address: &Address) -> bool {
+    is_smart_contract_address(address)
+        && address.as_bytes()[SC_ADDRESS_NUM_INIT_BYTES
+            ..SC_ADDRESS_NUM_INIT_BYTES + METACHAIN_SC_ADDRESS_NUM_ZERO_BYTES]
+            .iter()
+            .all(|byte| *byte == 0)
+}
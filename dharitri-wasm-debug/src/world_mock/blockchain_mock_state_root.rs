@@ -0,0 +1,113 @@
+use dharitri_wasm::types::H256;
+use sha3::{Digest, Keccak256};
+
+use super::{AccountData, BlockchainMock, DctData};
+
+impl BlockchainMock {
+    /// A hash over all the accounts in the world state, in place of the root hash of the state trie.
+    ///
+    /// It is deterministic: the same accounts always produce the same hash,
+    /// irrespective of the order in which they were added.
+    /// Any change to an account, including its storage or its tokens, changes the hash.
+    pub fn state_root_hash(&self) -> H256 {
+        let mut addresses: Vec<_> = self.accounts.keys().collect();
+        addresses.sort_by_key(|address| address.as_bytes());
+
+        let mut hasher = Keccak256::new();
+        for address in addresses {
+            hasher.update(address.as_bytes());
+            hasher.update(account_hash(&self.accounts[address]));
+        }
+        let hash: [u8; 32] = hasher.finalize().into();
+        hash.into()
+    }
+}
+
+fn account_hash(account: &AccountData) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(account.nonce.to_be_bytes());
+    update_with_bytes(&mut hasher, &account.moax_balance.to_bytes_be());
+    update_with_bytes(&mut hasher, &account.username);
+    update_with_bytes(
+        &mut hasher,
+        account.contract_path.as_deref().unwrap_or_default(),
+    );
+    update_with_bytes(
+        &mut hasher,
+        account
+            .contract_owner
+            .as_ref()
+            .map(|owner| owner.as_bytes())
+            .unwrap_or_default(),
+    );
+    hasher.update(account.code_metadata.to_byte_array());
+
+    let mut storage_keys: Vec<_> = account.storage.keys().collect();
+    storage_keys.sort();
+    hasher.update((storage_keys.len() as u64).to_be_bytes());
+    for key in storage_keys {
+        update_with_bytes(&mut hasher, key);
+        update_with_bytes(&mut hasher, &account.storage[key]);
+    }
+
+    let mut dct_data: Vec<_> = account
+        .dct
+        .iter()
+        .map(|(_, dct_data)| dct_data)
+        .filter(|dct_data| !dct_data.is_empty())
+        .collect();
+    dct_data.sort_by(|a, b| a.token_identifier.cmp(&b.token_identifier));
+    hasher.update((dct_data.len() as u64).to_be_bytes());
+    for dct_data in dct_data {
+        update_with_dct_data(&mut hasher, dct_data);
+    }
+
+    hasher.finalize().into()
+}
+
+fn update_with_dct_data(hasher: &mut Keccak256, dct_data: &DctData) {
+    update_with_bytes(hasher, &dct_data.token_identifier);
+    hasher.update(dct_data.last_nonce.to_be_bytes());
+    hasher.update([dct_data.frozen as u8]);
+
+    // roles are a set, the order they were granted in does not matter
+    let mut roles = dct_data.roles.get();
+    roles.sort();
+    roles.dedup();
+    hasher.update((roles.len() as u64).to_be_bytes());
+    for role in roles.iter() {
+        update_with_bytes(hasher, role);
+    }
+
+    let instances = dct_data.instances.get_instances();
+    hasher.update((instances.len() as u64).to_be_bytes());
+    for (nonce, instance) in instances {
+        hasher.update(nonce.to_be_bytes());
+        update_with_bytes(hasher, &instance.balance.to_bytes_be());
+        hasher.update([instance.frozen as u8]);
+
+        let metadata = &instance.metadata;
+        update_with_bytes(hasher, &metadata.name);
+        update_with_bytes(
+            hasher,
+            metadata
+                .creator
+                .as_ref()
+                .map(|creator| creator.as_bytes())
+                .unwrap_or_default(),
+        );
+        hasher.update(metadata.royalties.to_be_bytes());
+        update_with_bytes(hasher, metadata.hash.as_deref().unwrap_or_default());
+        hasher.update((metadata.uris.len() as u64).to_be_bytes());
+        for uri in metadata.uris.iter() {
+            update_with_bytes(hasher, uri);
+        }
+        update_with_bytes(hasher, &metadata.attributes);
+    }
+}
+
+/// Length-prefixed, so that consecutive fields cannot be confused with one another.
+fn update_with_bytes(hasher: &mut Keccak256, bytes: &[u8]) {
+    hasher.update((bytes.len() as u64).to_be_bytes());
+    hasher.update(bytes);
+}
@@ -9,6 +9,8 @@ pub struct DctInstance {
     pub nonce: u64,
     pub balance: BigUint,
     pub metadata: DctInstanceMetadata,
+    /// Single NFTs can be frozen, independently of the rest of the token.
+    pub frozen: bool,
 }
 
 impl DctInstance {
@@ -17,6 +19,7 @@ impl DctInstance {
             nonce,
             balance: BigUint::zero(),
             metadata: DctInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
@@ -25,6 +28,7 @@ impl DctInstance {
             nonce: 0,
             balance,
             metadata: DctInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
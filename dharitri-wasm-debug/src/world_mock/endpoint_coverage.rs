@@ -0,0 +1,254 @@
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    path::Path,
+    thread,
+    time::{Duration, Instant},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EndpointKind {
+    Constructor,
+    Upgrade,
+    Endpoint,
+    View,
+    Callback,
+}
+
+impl EndpointKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EndpointKind::Constructor => "constructor",
+            EndpointKind::Upgrade => "upgrade",
+            EndpointKind::Endpoint => "endpoint",
+            EndpointKind::View => "view",
+            EndpointKind::Callback => "callback",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointCallStats {
+    pub kind: EndpointKind,
+    pub calls: u64,
+    /// Number of calls for each result status.
+    pub statuses: BTreeMap<u64, u64>,
+}
+
+impl EndpointCallStats {
+    pub fn new(kind: EndpointKind) -> Self {
+        EndpointCallStats {
+            kind,
+            calls: 0,
+            statuses: BTreeMap::new(),
+        }
+    }
+
+    pub fn add_call(&mut self, result_status: u64) {
+        self.calls += 1;
+        *self.statuses.entry(result_status).or_insert(0) += 1;
+    }
+
+    pub fn merge(&mut self, other: &EndpointCallStats) {
+        self.calls += other.calls;
+        for (status, count) in &other.statuses {
+            *self.statuses.entry(*status).or_insert(0) += count;
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractEndpointCoverage {
+    pub endpoints: BTreeMap<String, EndpointCallStats>,
+}
+
+impl ContractEndpointCoverage {
+    pub fn uncalled_endpoints(&self) -> Vec<&str> {
+        self.endpoints
+            .iter()
+            .filter(|(_, stats)| stats.calls == 0)
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+}
+
+/// Which endpoints of the registered contracts were called, how many times and with what result.
+///
+/// Contracts are identified by the expression they were registered with, e.g. `file:output/adder.wasm`.
+/// Endpoints only show up with zero calls if the contract ABI is known,
+/// which is the case for contracts registered with `register_contract_builder`.
+/// Callbacks are recorded under their own name, and only show up once called.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndpointCoverage {
+    pub contracts: BTreeMap<String, ContractEndpointCoverage>,
+}
+
+impl EndpointCoverage {
+    pub fn add_call(
+        &mut self,
+        contract_name: &str,
+        endpoint_name: &str,
+        kind: EndpointKind,
+        result_status: u64,
+    ) {
+        self.endpoint_stats_mut(contract_name, endpoint_name, kind)
+            .add_call(result_status);
+    }
+
+    /// Lists an endpoint with zero calls, if not already present.
+    pub fn add_endpoint(&mut self, contract_name: &str, endpoint_name: &str, kind: EndpointKind) {
+        let _ = self.endpoint_stats_mut(contract_name, endpoint_name, kind);
+    }
+
+    fn endpoint_stats_mut(
+        &mut self,
+        contract_name: &str,
+        endpoint_name: &str,
+        kind: EndpointKind,
+    ) -> &mut EndpointCallStats {
+        self.contracts
+            .entry(contract_name.to_string())
+            .or_default()
+            .endpoints
+            .entry(endpoint_name.to_string())
+            .or_insert_with(|| EndpointCallStats::new(kind))
+    }
+
+    pub fn merge(&mut self, other: &EndpointCoverage) {
+        for (contract_name, contract_coverage) in &other.contracts {
+            for (endpoint_name, stats) in &contract_coverage.endpoints {
+                self.endpoint_stats_mut(contract_name, endpoint_name, stats.kind)
+                    .merge(stats);
+            }
+        }
+    }
+
+    /// One block per contract, one line per endpoint, uncalled endpoints clearly marked.
+    pub fn to_summary(&self) -> String {
+        let mut out = String::new();
+        for (contract_name, contract_coverage) in &self.contracts {
+            let called = contract_coverage
+                .endpoints
+                .values()
+                .filter(|stats| stats.calls > 0)
+                .count();
+            writeln!(
+                out,
+                "{} ({}/{} called)",
+                contract_name,
+                called,
+                contract_coverage.endpoints.len()
+            )
+            .unwrap();
+            for (endpoint_name, stats) in &contract_coverage.endpoints {
+                if stats.calls == 0 {
+                    writeln!(
+                        out,
+                        "  {:<11} {:<30} NOT CALLED",
+                        stats.kind.as_str(),
+                        endpoint_name
+                    )
+                    .unwrap();
+                } else {
+                    let statuses: Vec<String> = stats
+                        .statuses
+                        .iter()
+                        .map(|(status, count)| format!("status {}: {}", status, count))
+                        .collect();
+                    writeln!(
+                        out,
+                        "  {:<11} {:<30} {} calls ({})",
+                        stats.kind.as_str(),
+                        endpoint_name,
+                        stats.calls,
+                        statuses.join(", ")
+                    )
+                    .unwrap();
+                }
+            }
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn from_json(json: &str) -> Self {
+        serde_json::from_str(json).expect("invalid endpoint coverage JSON")
+    }
+
+    /// Adds this coverage to the one already saved at the given path, if any,
+    /// and writes the result back as JSON.
+    /// A readable summary is written next to it, with the `.txt` extension.
+    /// Safe to call from several test processes at once.
+    pub fn merge_into_file<P: AsRef<Path>>(&self, path: P) {
+        let path = path.as_ref();
+        let _lock = CoverageFileLock::acquire(path);
+
+        let mut coverage = match fs::read_to_string(path) {
+            Ok(json) => EndpointCoverage::from_json(&json),
+            Err(_) => EndpointCoverage::default(),
+        };
+        coverage.merge(self);
+
+        fs::write(path, coverage.to_json()).unwrap();
+        fs::write(path.with_extension("txt"), coverage.to_summary()).unwrap();
+    }
+}
+
+/// How long to wait for another test process to finish writing the coverage file.
+const COVERAGE_FILE_LOCK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Tests run in parallel, each test crate in its own process,
+/// but all of them add their coverage to the same file.
+/// Uses an advisory lock from the operating system, on a `.lock` file next to the coverage file,
+/// released when the file is closed, or when its process dies.
+/// The lock file itself is left in place.
+struct CoverageFileLock {
+    _lock_file: fs::File,
+}
+
+impl CoverageFileLock {
+    fn acquire(path: &Path) -> Self {
+        let mut lock_path = path.as_os_str().to_owned();
+        lock_path.push(".lock");
+        let lock_file = fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&lock_path)
+            .unwrap_or_else(|err| {
+                panic!(
+                    "cannot open endpoint coverage lock file {:?}: {}",
+                    lock_path, err
+                )
+            });
+
+        let start = Instant::now();
+        while let Err(err) = lock_file.try_lock_exclusive() {
+            if err.kind() != fs2::lock_contended_error().kind() {
+                panic!(
+                    "cannot lock endpoint coverage file {}: {}",
+                    path.display(),
+                    err
+                );
+            }
+            if start.elapsed() > COVERAGE_FILE_LOCK_TIMEOUT {
+                panic!(
+                    "timed out waiting for the lock on endpoint coverage file {}",
+                    path.display()
+                );
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        CoverageFileLock {
+            _lock_file: lock_file,
+        }
+    }
+}
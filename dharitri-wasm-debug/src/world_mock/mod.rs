@@ -3,20 +3,26 @@ mod account_dct;
 mod block_info;
 mod blockchain_mock;
 mod blockchain_mock_account_util;
+mod blockchain_mock_coverage;
 mod blockchain_mock_init;
+mod blockchain_mock_shards;
+mod blockchain_mock_state_root;
 mod blockchain_tx_info;
 mod dct_instance;
 mod dct_instance_metadata;
 mod dct_instances;
 mod dct_roles;
+mod endpoint_coverage;
 
 pub use account_data::*;
 pub use account_dct::*;
 pub use block_info::*;
 pub use blockchain_mock::*;
 pub use blockchain_mock_account_util::is_smart_contract_address;
+pub use blockchain_mock_shards::*;
 pub use blockchain_tx_info::*;
 pub use dct_instance::*;
 pub use dct_instance_metadata::*;
 pub use dct_instances::*;
 pub use dct_roles::*;
+pub use endpoint_coverage::*;
@@ -0,0 +1,49 @@
+use dharitri_wasm::types::Address;
+use dharitri_wasm_debug::{
+    tx_execution::system_sc_address,
+    world_mock::{compute_shard_id, METACHAIN_SHARD_ID},
+    BlockchainMock,
+};
+
+fn address_with_last_byte(last_byte: u8) -> Address {
+    let mut bytes = [0xffu8; 32];
+    bytes[31] = last_byte;
+    Address::from(bytes)
+}
+
+#[test]
+fn test_compute_shard_id() {
+    assert_eq!(compute_shard_id(&address_with_last_byte(0x05), 1), 0);
+
+    assert_eq!(compute_shard_id(&address_with_last_byte(0x00), 2), 0);
+    assert_eq!(compute_shard_id(&address_with_last_byte(0x5f), 2), 1);
+
+    assert_eq!(compute_shard_id(&address_with_last_byte(0x02), 3), 2);
+    assert_eq!(compute_shard_id(&address_with_last_byte(0x03), 3), 1);
+    assert_eq!(compute_shard_id(&address_with_last_byte(0x07), 3), 1);
+    assert_eq!(compute_shard_id(&address_with_last_byte(0x04), 3), 0);
+}
+
+#[test]
+fn test_system_sc_on_metachain() {
+    assert_eq!(
+        compute_shard_id(&system_sc_address(), 1),
+        METACHAIN_SHARD_ID
+    );
+    assert_eq!(
+        compute_shard_id(&system_sc_address(), 3),
+        METACHAIN_SHARD_ID
+    );
+}
+
+#[test]
+fn test_cross_shard() {
+    let mut blockchain = BlockchainMock::new();
+    let first = address_with_last_byte(0x00);
+    let second = address_with_last_byte(0x01);
+    assert!(!blockchain.is_cross_shard(&first, &second));
+
+    blockchain.set_num_shards(2);
+    assert!(blockchain.is_cross_shard(&first, &second));
+    assert!(!blockchain.is_cross_shard(&first, &address_with_last_byte(0x02)));
+}
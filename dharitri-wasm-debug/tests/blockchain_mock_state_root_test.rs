@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use dharitri_wasm::types::{Address, CodeMetadata};
+use dharitri_wasm_debug::{
+    rust_biguint,
+    world_mock::{AccountData, AccountDct},
+    BlockchainMock,
+};
+
+fn account(last_byte: u8, moax_balance: u64) -> AccountData {
+    let mut address_bytes = [0x11u8; 32];
+    address_bytes[31] = last_byte;
+    AccountData {
+        address: Address::from(address_bytes),
+        nonce: 0,
+        moax_balance: rust_biguint!(moax_balance),
+        dct: AccountDct::default(),
+        storage: HashMap::new(),
+        username: Vec::new(),
+        contract_path: None,
+        contract_owner: None,
+        code_metadata: CodeMetadata::DEFAULT,
+    }
+}
+
+#[test]
+fn test_state_root_hash_deterministic() {
+    let mut first = BlockchainMock::new();
+    first.add_account(account(1, 100));
+    first.add_account(account(2, 200));
+
+    let mut second = BlockchainMock::new();
+    second.add_account(account(2, 200));
+    second.add_account(account(1, 100));
+
+    assert_eq!(first.state_root_hash(), second.state_root_hash());
+}
+
+#[test]
+fn test_state_root_hash_changes_with_state() {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.add_account(account(1, 100));
+    let initial_hash = blockchain.state_root_hash();
+
+    blockchain.add_account(account(1, 101));
+    let balance_changed_hash = blockchain.state_root_hash();
+    assert_ne!(initial_hash, balance_changed_hash);
+
+    let mut with_storage = account(1, 101);
+    with_storage
+        .storage
+        .insert(b"key".to_vec(), b"value".to_vec());
+    blockchain.add_account(with_storage);
+    let storage_changed_hash = blockchain.state_root_hash();
+    assert_ne!(balance_changed_hash, storage_changed_hash);
+
+    let mut with_dct = account(1, 101);
+    with_dct.storage.insert(b"key".to_vec(), b"value".to_vec());
+    with_dct.dct.increase_balance(
+        b"TOKEN-123456".to_vec(),
+        0,
+        &rust_biguint!(5),
+        Default::default(),
+    );
+    blockchain.add_account(with_dct);
+    assert_ne!(storage_changed_hash, blockchain.state_root_hash());
+}
+
+#[test]
+fn test_state_root_hash_ignores_role_order() {
+    let with_roles = |roles: &[&[u8]]| {
+        let mut account = account(1, 100);
+        account.dct.set_roles(
+            b"TOKEN-123456".to_vec(),
+            roles.iter().map(|role| role.to_vec()).collect(),
+        );
+        let mut blockchain = BlockchainMock::new();
+        blockchain.add_account(account);
+        blockchain.state_root_hash()
+    };
+
+    assert_eq!(
+        with_roles(&[b"DCTRoleLocalMint", b"DCTRoleLocalBurn"]),
+        with_roles(&[b"DCTRoleLocalBurn", b"DCTRoleLocalMint"])
+    );
+    assert_ne!(
+        with_roles(&[b"DCTRoleLocalMint"]),
+        with_roles(&[b"DCTRoleLocalBurn"])
+    );
+}
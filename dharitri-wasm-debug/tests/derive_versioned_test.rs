@@ -0,0 +1,77 @@
+use dharitri_wasm::{
+    abi::{TypeAbi, TypeContents, TypeDescriptionContainer, TypeDescriptionContainerImpl},
+    api::ManagedTypeApi,
+    dharitri_codec::test_util::check_top_encode_decode,
+    types::{BigUint, ManagedBuffer},
+};
+use dharitri_wasm_debug::DebugApi;
+
+dharitri_wasm::derive_imports!();
+
+// to test, run the following command in dharitri-wasm-debug folder:
+// cargo expand --test derive_versioned_test > expanded.rs
+
+#[derive(NestedDecode)]
+pub struct PositionV0<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+}
+
+/// A position, as saved in storage.
+#[derive(VersionedCodec, TypeAbi, PartialEq, Debug)]
+#[codec(version = 1, previous_versions("PositionV0<M>"))]
+pub struct Position<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub label: ManagedBuffer<M>,
+    #[codec(skip)]
+    pub dirty: bool,
+}
+
+impl<M: ManagedTypeApi> From<PositionV0<M>> for Position<M> {
+    fn from(v0: PositionV0<M>) -> Self {
+        Position {
+            amount: v0.amount,
+            label: ManagedBuffer::new(),
+            dirty: false,
+        }
+    }
+}
+
+#[test]
+fn versioned_managed_test() {
+    let _ = DebugApi::dummy();
+
+    let position = Position::<DebugApi> {
+        amount: BigUint::from(5u32),
+        label: ManagedBuffer::from(&b"a"[..]),
+        dirty: false,
+    };
+    check_top_encode_decode(position, &[1, 0, 0, 0, 1, 5, 0, 0, 0, 1, b'a']);
+
+    let v0_bytes: &[u8] = &[0, 0, 0, 0, 1, 7];
+    let upgraded = dharitri_wasm::dharitri_codec::TopDecode::top_decode(
+        ManagedBuffer::<DebugApi>::from(v0_bytes),
+    )
+    .unwrap();
+    assert_eq!(
+        Position::<DebugApi> {
+            amount: BigUint::from(7u32),
+            label: ManagedBuffer::new(),
+            dirty: false,
+        },
+        upgraded
+    );
+}
+
+#[test]
+fn versioned_type_abi_test() {
+    let mut accumulator = TypeDescriptionContainerImpl::new();
+    Position::<DebugApi>::provide_type_descriptions(&mut accumulator);
+    let description = accumulator.0.get("Position").unwrap();
+    if let TypeContents::Struct(fields) = &description.contents {
+        let field_names: Vec<&str> = fields.iter().map(|field| field.name).collect();
+        assert_eq!(field_names, vec!["_version", "amount", "label"]);
+        assert_eq!(fields[0].field_type, "u8");
+    } else {
+        panic!("struct type description expected");
+    }
+}
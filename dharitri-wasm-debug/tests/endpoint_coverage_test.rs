@@ -0,0 +1,118 @@
+use dharitri_wasm_debug::world_mock::{EndpointCoverage, EndpointKind};
+
+fn sample_coverage() -> EndpointCoverage {
+    let mut coverage = EndpointCoverage::default();
+    coverage.add_endpoint("file:sc.wasm", "init", EndpointKind::Constructor);
+    coverage.add_endpoint("file:sc.wasm", "getValue", EndpointKind::View);
+    coverage.add_endpoint("file:sc.wasm", "untested", EndpointKind::Endpoint);
+    coverage.add_call("file:sc.wasm", "init", EndpointKind::Constructor, 0);
+    coverage.add_call("file:sc.wasm", "setValue", EndpointKind::Endpoint, 0);
+    coverage.add_call("file:sc.wasm", "setValue", EndpointKind::Endpoint, 4);
+    coverage
+}
+
+#[test]
+fn test_endpoint_coverage_calls() {
+    let coverage = sample_coverage();
+    let sc_coverage = &coverage.contracts["file:sc.wasm"];
+    assert_eq!(sc_coverage.endpoints.len(), 4);
+    assert_eq!(sc_coverage.endpoints["setValue"].calls, 2);
+    assert_eq!(sc_coverage.endpoints["setValue"].statuses[&4], 1);
+    assert_eq!(
+        sc_coverage.uncalled_endpoints(),
+        vec!["getValue", "untested"]
+    );
+}
+
+#[test]
+fn test_endpoint_coverage_merge() {
+    let mut coverage = sample_coverage();
+    let mut other = EndpointCoverage::default();
+    other.add_call("file:sc.wasm", "getValue", EndpointKind::View, 0);
+    other.add_call("file:sc.wasm", "setValue", EndpointKind::Endpoint, 0);
+    other.add_call("file:other.wasm", "init", EndpointKind::Constructor, 0);
+    coverage.merge(&other);
+
+    let sc_coverage = &coverage.contracts["file:sc.wasm"];
+    assert_eq!(sc_coverage.endpoints["setValue"].calls, 3);
+    assert_eq!(sc_coverage.endpoints["setValue"].statuses[&0], 2);
+    assert_eq!(sc_coverage.uncalled_endpoints(), vec!["untested"]);
+    assert_eq!(
+        coverage.contracts["file:other.wasm"].endpoints["init"].calls,
+        1
+    );
+}
+
+#[test]
+fn test_endpoint_coverage_summary() {
+    let summary = sample_coverage().to_summary();
+    assert!(summary.starts_with("file:sc.wasm (2/4 called)\n"));
+    assert!(summary.contains("getValue"));
+    assert!(summary.contains("NOT CALLED"));
+    assert!(summary.contains("2 calls (status 0: 1, status 4: 1)"));
+}
+
+#[test]
+fn test_endpoint_coverage_file() {
+    let coverage = sample_coverage();
+    assert_eq!(EndpointCoverage::from_json(&coverage.to_json()), coverage);
+
+    let mut path = std::env::temp_dir();
+    path.push(format!(
+        "endpoint_coverage_test_{}.json",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+
+    coverage.merge_into_file(&path);
+    coverage.merge_into_file(&path);
+    let saved = EndpointCoverage::from_json(&std::fs::read_to_string(&path).unwrap());
+    assert_eq!(
+        saved.contracts["file:sc.wasm"].endpoints["setValue"].calls,
+        4
+    );
+    assert!(std::fs::read_to_string(path.with_extension("txt"))
+        .unwrap()
+        .contains("4 calls"));
+
+    std::fs::remove_file(&path).unwrap();
+    std::fs::remove_file(path.with_extension("txt")).unwrap();
+    let mut lock_path = path.as_os_str().to_owned();
+    lock_path.push(".lock");
+    std::fs::remove_file(&lock_path).unwrap();
+}
+
+#[test]
+fn test_endpoint_coverage_file_concurrent() {
+    let mut path = std::env::temp_dir();
+    path.push(format!(
+        "endpoint_coverage_concurrent_test_{}.json",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+
+    let threads: Vec<_> = (0..8)
+        .map(|_| {
+            let path = path.clone();
+            std::thread::spawn(move || sample_coverage().merge_into_file(&path))
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    let saved = EndpointCoverage::from_json(&std::fs::read_to_string(&path).unwrap());
+    assert_eq!(
+        saved.contracts["file:sc.wasm"].endpoints["setValue"].calls,
+        16
+    );
+    let mut lock_path = path.as_os_str().to_owned();
+    lock_path.push(".lock");
+    let lock_file = std::fs::File::open(&lock_path).unwrap();
+    fs2::FileExt::try_lock_exclusive(&lock_file).unwrap();
+    drop(lock_file);
+
+    std::fs::remove_file(&path).unwrap();
+    std::fs::remove_file(path.with_extension("txt")).unwrap();
+    std::fs::remove_file(&lock_path).unwrap();
+}
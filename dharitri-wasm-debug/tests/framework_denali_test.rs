@@ -182,6 +182,24 @@ fn builtin_func_dct_transfer() {
     dharitri_wasm_debug::denali_rs("tests/denali/builtin-func-dct-transfer.scen.json", world());
 }
 
+#[test]
+fn builtin_func_freeze_wipe() {
+    dharitri_wasm_debug::denali_rs("tests/denali/builtin-func-freeze-wipe.scen.json", world());
+}
+
+#[test]
+fn builtin_func_nft_update_attributes_add_uri() {
+    dharitri_wasm_debug::denali_rs(
+        "tests/denali/builtin-func-nft-update-attributes-add-uri.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn builtin_func_save_key_value() {
+    dharitri_wasm_debug::denali_rs("tests/denali/builtin-func-save-key-value.scen.json", world());
+}
+
 #[test]
 #[should_panic]
 fn dct_non_zero_balance_check_err_rs() {
@@ -194,10 +212,7 @@ fn dct_non_zero_balance_check_err_rs() {
 #[test]
 #[should_panic]
 fn dct_zero_balance_check_err_rs() {
-    dharitri_wasm_debug::denali_rs(
-        "tests/denali/dct-zero-balance-check-err.scen.json",
-        world(),
-    );
+    dharitri_wasm_debug::denali_rs("tests/denali/dct-zero-balance-check-err.scen.json", world());
 }
 
 #[test]
@@ -215,6 +230,11 @@ fn transfer_dct_rs() {
     dharitri_wasm_debug::denali_rs("tests/denali/transfer-dct.scen.json", world());
 }
 
+#[test]
+fn transfer_dct_frozen_rs() {
+    dharitri_wasm_debug::denali_rs("tests/denali/transfer-dct-frozen.scen.json", world());
+}
+
 #[test]
 fn validator_reward_rs() {
     dharitri_wasm_debug::denali_rs("tests/denali/validatorReward.scen.json", world());
@@ -0,0 +1,123 @@
+use core::cmp::Ordering;
+
+use dharitri_wasm::types::{BigUint, ManagedBuffer};
+use dharitri_wasm_debug::DebugApi;
+
+fn mb(bytes: &[u8]) -> ManagedBuffer<DebugApi> {
+    ManagedBuffer::from(bytes)
+}
+
+#[test]
+fn test_managed_buffer_cmp() {
+    let _ = DebugApi::dummy();
+
+    assert_eq!(mb(b"abc").cmp(&mb(b"abc")), Ordering::Equal);
+    assert_eq!(mb(b"abc").cmp(&mb(b"abd")), Ordering::Less);
+    assert_eq!(mb(b"abc").cmp(&mb(b"ab")), Ordering::Greater);
+    assert_eq!(mb(b"").cmp(&mb(b"a")), Ordering::Less);
+    assert!(mb(b"b") > mb(b"abcdef"));
+
+    let mut long_1 = [5u8; 100];
+    let long_2 = [5u8; 100];
+    long_1[99] = 4;
+    assert!(mb(&long_1[..]) < mb(&long_2[..]));
+}
+
+#[test]
+fn test_managed_buffer_find() {
+    let _ = DebugApi::dummy();
+
+    let buffer = mb(b"https://ipfs.io/ipfs/cid/file.json");
+    assert_eq!(buffer.find(b"/ipfs/"), Some(15));
+    assert_eq!(buffer.find(b"https"), Some(0));
+    assert_eq!(buffer.find(b".json"), Some(29));
+    assert_eq!(buffer.find(b".jsonx"), None);
+    assert_eq!(buffer.find(b""), Some(0));
+    assert_eq!(buffer.find_from(16, b"/"), Some(20));
+    assert!(buffer.contains_slice(b"cid"));
+    assert!(!buffer.contains_slice(b"CID"));
+    assert!(buffer.starts_with(b"https://"));
+    assert!(!buffer.starts_with(b"http://"));
+    assert!(buffer.ends_with(b".json"));
+    assert!(!buffer.ends_with(b"https://ipfs.io/ipfs/cid/file.json.json"));
+    assert_eq!(mb(b"").find(b"a"), None);
+}
+
+#[test]
+fn test_managed_buffer_find_across_chunks() {
+    let _ = DebugApi::dummy();
+
+    let mut bytes = [b'a'; 300];
+    bytes[62..66].copy_from_slice(b"bcde");
+    bytes[200..280].copy_from_slice(&[b'x'; 80]);
+    let buffer = mb(&bytes[..]);
+    assert_eq!(buffer.find(b"bcde"), Some(62));
+    assert_eq!(buffer.find(&[b'x'; 80]), Some(200));
+    assert_eq!(buffer.find(&[b'x'; 81]), None);
+    assert_eq!(buffer.find(b"ax"), Some(199));
+}
+
+#[test]
+fn test_managed_buffer_split() {
+    let _ = DebugApi::dummy();
+
+    let parts = mb(b"tags:art;music;;video").split(b";");
+    let expected = [&b"tags:art"[..], b"music", b"", b"video"];
+    assert_eq!(parts.len(), expected.len());
+    for (part, expected_part) in parts.iter().zip(expected.iter()) {
+        assert_eq!(part.to_boxed_bytes().as_slice(), *expected_part);
+    }
+
+    let parts = mb(b"a::b::").split(b"::");
+    assert_eq!(parts.len(), 3);
+    assert!(parts.get(2).is_empty());
+
+    let parts = mb(b"").split(b",");
+    assert_eq!(parts.len(), 1);
+    assert!(parts.get(0).is_empty());
+
+    let parts = mb(b"abc").split(b"");
+    assert_eq!(parts.len(), 1);
+    assert_eq!(*parts.get(0), mb(b"abc"));
+}
+
+#[test]
+fn test_managed_buffer_hex() {
+    let _ = DebugApi::dummy();
+
+    assert_eq!(mb(&[0x01, 0xab, 0x00, 0xff]).to_hex(), mb(b"01ab00ff"));
+    assert_eq!(mb(b"").to_hex(), mb(b""));
+    assert_eq!(
+        mb(b"01ab00ff").parse_hex(),
+        Some(mb(&[0x01, 0xab, 0x00, 0xff]))
+    );
+    assert_eq!(mb(b"").parse_hex(), Some(mb(b"")));
+    assert_eq!(mb(b"01a").parse_hex(), None);
+    assert_eq!(mb(b"0g").parse_hex(), None);
+
+    let long_bytes: Vec<u8> = (0..=255u8).collect();
+    let long_buffer = mb(long_bytes.as_slice());
+    assert_eq!(long_buffer.to_hex().parse_hex(), Some(long_buffer));
+}
+
+#[test]
+fn test_managed_buffer_parse_biguint_decimal() {
+    let _ = DebugApi::dummy();
+
+    assert_eq!(
+        mb(b"0").parse_as_biguint_decimal(),
+        Some(BigUint::from(0u64))
+    );
+    assert_eq!(
+        mb(b"9223372036854775807").parse_as_biguint_decimal(),
+        Some(BigUint::from(i64::MAX as u64))
+    );
+    let expected = BigUint::from(i64::MAX as u64) * BigUint::from(1_000_000_000_000u64);
+    assert_eq!(
+        mb(b"9223372036854775807000000000000").parse_as_biguint_decimal(),
+        Some(expected)
+    );
+    assert_eq!(mb(b"").parse_as_biguint_decimal(), None);
+    assert_eq!(mb(b"12 3").parse_as_biguint_decimal(), None);
+    assert_eq!(mb(b"-1").parse_as_biguint_decimal(), None);
+}
@@ -0,0 +1,116 @@
+use dharitri_wasm::types::{BigUint, ManagedAddress, ManagedBuffer, ManagedMap, TokenIdentifier};
+use dharitri_wasm_debug::DebugApi;
+
+fn token(name: &[u8]) -> TokenIdentifier<DebugApi> {
+    TokenIdentifier::from(name)
+}
+
+#[test]
+fn test_managed_map_insert_get_remove() {
+    let _ = DebugApi::dummy();
+
+    let mut map = ManagedMap::<DebugApi, ManagedBuffer<DebugApi>, u64>::new();
+    assert!(map.is_empty());
+
+    assert_eq!(map.insert(ManagedBuffer::from(&b"b"[..]), 2), None);
+    assert_eq!(map.insert(ManagedBuffer::from(&b"a"[..]), 1), None);
+    assert_eq!(map.insert(ManagedBuffer::from(&b"c"[..]), 3), None);
+    assert_eq!(map.insert(ManagedBuffer::from(&b"b"[..]), 20), Some(2));
+    assert_eq!(map.len(), 3);
+
+    assert_eq!(map.get(&ManagedBuffer::from(&b"b"[..])), Some(20));
+    assert_eq!(map.get(&ManagedBuffer::from(&b"d"[..])), None);
+    assert!(map.contains_key(&ManagedBuffer::from(&b"a"[..])));
+    assert!(!map.contains_key(&ManagedBuffer::from(&b"ab"[..])));
+
+    assert_eq!(map.remove(&ManagedBuffer::from(&b"a"[..])), Some(1));
+    assert_eq!(map.remove(&ManagedBuffer::from(&b"a"[..])), None);
+    assert_eq!(map.len(), 2);
+
+    map.clear();
+    assert!(map.is_empty());
+}
+
+#[test]
+fn test_managed_map_iter_is_sorted() {
+    let _ = DebugApi::dummy();
+
+    let mut map = ManagedMap::<DebugApi, TokenIdentifier<DebugApi>, u32>::new();
+    map.insert(token(b"WMOAX-abcdef"), 3);
+    map.insert(token(b"MEX-123456"), 2);
+    map.insert(token(b"ALC-999999"), 1);
+
+    let keys: Vec<TokenIdentifier<DebugApi>> = map.keys().collect();
+    assert_eq!(
+        keys,
+        vec![
+            token(b"ALC-999999"),
+            token(b"MEX-123456"),
+            token(b"WMOAX-abcdef")
+        ]
+    );
+    let values: Vec<u32> = map.values().collect();
+    assert_eq!(values, vec![1, 2, 3]);
+    let pairs: Vec<(TokenIdentifier<DebugApi>, u32)> = map.iter().collect();
+    assert_eq!(pairs[2], (token(b"WMOAX-abcdef"), 3));
+}
+
+#[test]
+fn test_managed_map_entry_aggregate() {
+    let _ = DebugApi::dummy();
+
+    let payments = [
+        (b"TOKA-111111", 10u64),
+        (b"TOKB-222222", 5u64),
+        (b"TOKA-111111", 7u64),
+        (b"TOKB-222222", 1u64),
+        (b"TOKC-333333", 100u64),
+    ];
+
+    let mut totals = ManagedMap::<DebugApi, TokenIdentifier<DebugApi>, BigUint<DebugApi>>::new();
+    for (token_name, amount) in payments.iter() {
+        totals
+            .entry(token(&token_name[..]))
+            .or_default()
+            .update(|total| *total += *amount);
+    }
+
+    assert_eq!(totals.len(), 3);
+    assert_eq!(*totals.get(&token(b"TOKA-111111")).unwrap(), 17u64);
+    assert_eq!(*totals.get(&token(b"TOKB-222222")).unwrap(), 6u64);
+    assert_eq!(*totals.get(&token(b"TOKC-333333")).unwrap(), 100u64);
+}
+
+#[test]
+fn test_managed_map_entry_api() {
+    let _ = DebugApi::dummy();
+
+    let mut map = ManagedMap::<DebugApi, ManagedAddress<DebugApi>, u32>::new();
+    let address_1 = ManagedAddress::from(&[1u8; 32]);
+    let address_2 = ManagedAddress::from(&[2u8; 32]);
+
+    let entry = map.entry(address_2.clone()).or_insert(5);
+    assert_eq!(entry.get(), 5);
+    assert_eq!(entry.key(), &address_2);
+
+    map.entry(address_2.clone()).and_modify(|value| *value += 1);
+    assert_eq!(map.get(&address_2), Some(6));
+
+    map.entry(address_1.clone()).and_modify(|value| *value += 1);
+    assert!(!map.contains_key(&address_1));
+
+    let entry = map.entry(address_1.clone()).or_insert_with_key(|key| {
+        assert_eq!(key, &address_1);
+        1
+    });
+    assert_eq!(entry.insert(10), 1);
+    assert_eq!(map.get(&address_1), Some(10));
+
+    match map.entry(address_1.clone()) {
+        dharitri_wasm::types::managed_map::Entry::Occupied(entry) => {
+            assert_eq!(entry.remove(), 10);
+        },
+        dharitri_wasm::types::managed_map::Entry::Vacant(_) => panic!("entry should be occupied"),
+    }
+    assert_eq!(map.len(), 1);
+}
@@ -0,0 +1,56 @@
+use dharitri_wasm::types::{ManagedBuffer, ManagedSet};
+use dharitri_wasm_debug::DebugApi;
+
+#[test]
+fn test_managed_set() {
+    let _ = DebugApi::dummy();
+
+    let mut set = ManagedSet::<DebugApi, ManagedBuffer<DebugApi>>::new();
+    assert!(set.is_empty());
+    assert!(set.insert(ManagedBuffer::from(&b"xyz"[..])));
+    assert!(set.insert(ManagedBuffer::from(&b"abc"[..])));
+    assert!(!set.insert(ManagedBuffer::from(&b"xyz"[..])));
+    assert!(set.insert(ManagedBuffer::from(&b"ab"[..])));
+    assert_eq!(set.len(), 3);
+
+    assert!(set.contains(&ManagedBuffer::from(&b"abc"[..])));
+    assert!(!set.contains(&ManagedBuffer::from(&b"abcd"[..])));
+
+    let items: Vec<ManagedBuffer<DebugApi>> = set.iter().collect();
+    assert_eq!(
+        items,
+        vec![
+            ManagedBuffer::from(&b"ab"[..]),
+            ManagedBuffer::from(&b"abc"[..]),
+            ManagedBuffer::from(&b"xyz"[..]),
+        ]
+    );
+
+    assert!(set.remove(&ManagedBuffer::from(&b"abc"[..])));
+    assert!(!set.remove(&ManagedBuffer::from(&b"abc"[..])));
+    assert_eq!(set.len(), 2);
+
+    set.clear();
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_managed_set_long_items() {
+    let _ = DebugApi::dummy();
+
+    // longer than the chunk size used for comparisons
+    let mut long_a = [7u8; 70];
+    let mut long_b = [7u8; 70];
+    long_a[65] = 1;
+    long_b[65] = 2;
+
+    let mut set = ManagedSet::<DebugApi, ManagedBuffer<DebugApi>>::new();
+    assert!(set.insert(ManagedBuffer::from(&long_b[..])));
+    assert!(set.insert(ManagedBuffer::from(&long_a[..])));
+    assert!(!set.insert(ManagedBuffer::from(&long_a[..])));
+    assert!(set.contains(&ManagedBuffer::from(&long_b[..])));
+    assert_eq!(
+        set.as_managed_vec().get(0).to_boxed_bytes().as_slice(),
+        &long_a[..]
+    );
+}
@@ -1,6 +1,6 @@
 use std::ops::Deref;
 
-use dharitri_wasm::types::{BigUint, ManagedVec};
+use dharitri_wasm::types::{BigUint, ManagedType, ManagedVec};
 use dharitri_wasm_debug::DebugApi;
 
 #[test]
@@ -173,3 +173,179 @@ fn test_managed_vec_get_mut() {
     assert_eq!(*managed_vec.get(0), 200u32);
     assert_eq!(*managed_vec.get(1), 300u32);
 }
+
+fn managed_vec_of_biguints(numbers: &[u64]) -> ManagedVec<DebugApi, BigUint<DebugApi>> {
+    let mut managed_vec = ManagedVec::new();
+    for n in numbers {
+        managed_vec.push(BigUint::from(*n));
+    }
+    managed_vec
+}
+
+fn biguints_to_u64s(managed_vec: &ManagedVec<DebugApi, BigUint<DebugApi>>) -> Vec<u64> {
+    managed_vec
+        .iter()
+        .map(|biguint| biguint.to_u64().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_managed_vec_insert_remove() {
+    let _ = DebugApi::dummy();
+
+    let mut managed_vec = managed_vec_of_biguints(&[1, 2, 3]);
+    managed_vec.insert(0, BigUint::from(0u64));
+    managed_vec.insert(2, BigUint::from(10u64));
+    managed_vec.insert(5, BigUint::from(20u64));
+    assert_eq!(biguints_to_u64s(&managed_vec), vec![0, 1, 10, 2, 3, 20]);
+
+    assert_eq!(managed_vec.remove(2), 10u64);
+    assert_eq!(managed_vec.remove(0), 0u64);
+    assert_eq!(managed_vec.remove(3), 20u64);
+    assert_eq!(biguints_to_u64s(&managed_vec), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_managed_vec_swap() {
+    let _ = DebugApi::dummy();
+
+    let mut managed_vec = ManagedVec::<DebugApi, u32>::new();
+    for i in 1..=4 {
+        managed_vec.push(i);
+    }
+    managed_vec.swap(0, 3);
+    managed_vec.swap(1, 1);
+    assert_eq!(managed_vec.into_vec(), vec![4, 2, 3, 1]);
+}
+
+#[test]
+#[should_panic]
+fn test_managed_vec_swap_same_index_out_of_range() {
+    let _ = DebugApi::dummy();
+
+    let mut managed_vec = ManagedVec::<DebugApi, u32>::new();
+    managed_vec.push(1);
+    managed_vec.swap(1, 1);
+}
+
+#[test]
+fn test_managed_vec_find_contains() {
+    let _ = DebugApi::dummy();
+
+    let managed_vec = managed_vec_of_biguints(&[5, 7, 9, 7]);
+    assert_eq!(managed_vec.find(&BigUint::from(7u64)), Some(1));
+    assert_eq!(managed_vec.find(&BigUint::from(8u64)), None);
+    assert!(managed_vec.contains(&BigUint::from(9u64)));
+    assert!(!managed_vec.contains(&BigUint::from(10u64)));
+    assert_eq!(managed_vec.find_by(|item| *item > 7u64), Some(2));
+}
+
+#[test]
+fn test_managed_vec_retain() {
+    let _ = DebugApi::dummy();
+
+    let mut managed_vec = managed_vec_of_biguints(&[1, 2, 3, 4, 5, 6]);
+    managed_vec.retain(|item| item.to_u64().unwrap() % 2 == 0);
+    assert_eq!(biguints_to_u64s(&managed_vec), vec![2, 4, 6]);
+
+    managed_vec.retain(|_| false);
+    assert!(managed_vec.is_empty());
+}
+
+#[test]
+fn test_managed_vec_dedup() {
+    let _ = DebugApi::dummy();
+
+    let mut managed_vec = managed_vec_of_biguints(&[1, 1, 2, 3, 3, 3, 1, 4, 4]);
+    managed_vec.dedup();
+    assert_eq!(biguints_to_u64s(&managed_vec), vec![1, 2, 3, 1, 4]);
+
+    let mut numbers = ManagedVec::<DebugApi, u32>::new();
+    for i in [10, 11, 20, 25, 31] {
+        numbers.push(i);
+    }
+    numbers.dedup_by_key(|item| *item / 10);
+    assert_eq!(numbers.into_vec(), vec![10, 20, 31]);
+}
+
+#[test]
+fn test_managed_vec_sort() {
+    let _ = DebugApi::dummy();
+
+    let unsorted = [9, 3, 7, 1, 8, 2, 2, 6, 5, 4, 0];
+    let mut expected = unsorted.to_vec();
+    expected.sort_unstable();
+
+    let mut managed_vec = managed_vec_of_biguints(&unsorted);
+    managed_vec.sort();
+    assert_eq!(biguints_to_u64s(&managed_vec), expected);
+
+    let mut managed_vec = managed_vec_of_biguints(&unsorted);
+    managed_vec.sort_unstable();
+    assert_eq!(biguints_to_u64s(&managed_vec), expected);
+
+    let mut numbers = ManagedVec::<DebugApi, i64>::new();
+    for i in unsorted {
+        numbers.push(-(i as i64));
+    }
+    numbers.sort_unstable_by(|a, b| b.cmp(a));
+    let expected_desc: Vec<i64> = expected.iter().map(|i| -(*i as i64)).collect();
+    assert_eq!(numbers.into_vec(), expected_desc);
+
+    let mut empty = ManagedVec::<DebugApi, u32>::new();
+    empty.sort();
+    empty.sort_unstable();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_managed_vec_sort_is_stable() {
+    let _ = DebugApi::dummy();
+
+    let mut managed_vec = ManagedVec::<DebugApi, u32>::new();
+    for i in [31, 12, 23, 11, 32, 22, 13, 21, 33] {
+        managed_vec.push(i);
+    }
+    managed_vec.sort_by_key(|item| *item % 10);
+    assert_eq!(
+        managed_vec.into_vec(),
+        vec![31, 11, 21, 12, 32, 22, 23, 13, 33]
+    );
+}
+
+#[test]
+fn test_managed_vec_binary_search() {
+    let _ = DebugApi::dummy();
+
+    let managed_vec = managed_vec_of_biguints(&[1, 3, 5, 7, 9]);
+    assert_eq!(managed_vec.binary_search(&BigUint::from(1u64)), Ok(0));
+    assert_eq!(managed_vec.binary_search(&BigUint::from(7u64)), Ok(3));
+    assert_eq!(managed_vec.binary_search(&BigUint::from(0u64)), Err(0));
+    assert_eq!(managed_vec.binary_search(&BigUint::from(4u64)), Err(2));
+    assert_eq!(managed_vec.binary_search(&BigUint::from(10u64)), Err(5));
+    assert_eq!(
+        managed_vec.binary_search_by_key(&9, |item| item.to_u64().unwrap()),
+        Ok(4)
+    );
+}
+
+#[test]
+fn test_managed_vec_algorithms_keep_handle() {
+    let _ = DebugApi::dummy();
+
+    let mut managed_vec = managed_vec_of_biguints(&[5, 3, 3, 8, 1]);
+    let handle = managed_vec.get_raw_handle();
+
+    managed_vec.insert(1, BigUint::from(7u64));
+    let _ = managed_vec.remove(0);
+    managed_vec.sort();
+    managed_vec.dedup();
+    managed_vec.retain(|item| item.to_u64().unwrap() != 8);
+    assert_eq!(managed_vec.get_raw_handle(), handle);
+
+    let numbers: Vec<u64> = managed_vec
+        .iter()
+        .map(|biguint| biguint.to_u64().unwrap())
+        .collect();
+    assert_eq!(numbers, vec![1, 3, 7]);
+}
@@ -311,6 +311,10 @@ mod sample_adder {
                 _phantom: core::marker::PhantomData,
             })
         }
+
+        fn abi(&self) -> Option<dharitri_wasm::abi::ContractAbi> {
+            Some(<AbiProvider as dharitri_wasm::contract_base::ContractAbiProvider>::abi())
+        }
     }
 
     pub struct AbiProvider {}
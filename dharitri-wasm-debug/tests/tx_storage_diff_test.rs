@@ -0,0 +1,91 @@
+use dharitri_wasm::types::Address;
+use dharitri_wasm_debug::tx_mock::{storage_key_pretty, TxStorageDiff, TxStorageWrite};
+
+fn mapper_key(base: &[u8], suffix: &[u8], rest: &[u8]) -> Vec<u8> {
+    [base, suffix, rest].concat()
+}
+
+#[test]
+fn test_storage_key_pretty() {
+    assert_eq!(storage_key_pretty(b"counter"), "str:counter");
+    assert_eq!(storage_key_pretty(b""), "0x");
+    assert_eq!(storage_key_pretty(&[0x01, 0x02]), "0x0102");
+    assert_eq!(storage_key_pretty(b"my_vec.len"), "str:my_vec.len");
+    assert_eq!(
+        storage_key_pretty(&mapper_key(b"my_vec", b".item", &3u32.to_be_bytes())),
+        "str:my_vec.item|u32:3"
+    );
+    assert_eq!(
+        storage_key_pretty(&mapper_key(b"queue", b".node_links", &7u32.to_be_bytes())),
+        "str:queue.node_links|u32:7"
+    );
+    assert_eq!(
+        storage_key_pretty(&mapper_key(b"users", b"_address_to_id", &[0xab; 32])),
+        format!("str:users_address_to_id|0x{}", "ab".repeat(32))
+    );
+    assert_eq!(
+        storage_key_pretty(&mapper_key(
+            b"users",
+            b"_id_to_address",
+            &2u32.to_be_bytes()
+        )),
+        "str:users_id_to_address|u32:2"
+    );
+}
+
+#[test]
+fn test_storage_key_pretty_mapper_with_args() {
+    // the mapper arguments are placed between the name and the suffix
+    let key = mapper_key(b"balances", &[0, 0, 0, 1, 0xff], b".len");
+    assert_eq!(
+        storage_key_pretty(&key),
+        "str:balances|0x00000001ff|str:.len"
+    );
+}
+
+#[test]
+fn test_storage_diff_merge() {
+    let address = Address::from([1u8; 32]);
+    let write = |key: &[u8], old_value: &[u8], new_value: &[u8]| TxStorageWrite {
+        address: address.clone(),
+        key: key.to_vec(),
+        old_value: old_value.to_vec(),
+        new_value: new_value.to_vec(),
+    };
+
+    let mut diff = TxStorageDiff {
+        reads: Vec::new(),
+        writes: vec![write(b"a", b"", b"1"), write(b"b", b"", b"1")],
+    };
+    diff.add_read(&address, b"a", b"");
+    diff.add_read(&address, b"a", b"1");
+    assert_eq!(diff.reads.len(), 1);
+
+    let mut later_diff = TxStorageDiff {
+        reads: Vec::new(),
+        writes: vec![write(b"a", b"1", b"2"), write(b"b", b"1", b"")],
+    };
+    later_diff.add_read(&address, b"c", b"3");
+    diff.merge(later_diff);
+
+    // "b" is back to its original value
+    assert_eq!(diff.writes, vec![write(b"a", b"", b"2")]);
+    assert_eq!(diff.read_keys(&address), vec![&b"a"[..], &b"c"[..]]);
+    diff.assert_no_unexpected_writes(&address, &[b"a"]);
+}
+
+#[test]
+#[should_panic(expected = "Unexpected storage writes")]
+fn test_storage_diff_unexpected_write() {
+    let address = Address::from([1u8; 32]);
+    let diff = TxStorageDiff {
+        reads: Vec::new(),
+        writes: vec![TxStorageWrite {
+            address: address.clone(),
+            key: b"other".to_vec(),
+            old_value: Vec::new(),
+            new_value: vec![1],
+        }],
+    };
+    diff.assert_no_unexpected_writes(&address, &[b"expected"]);
+}
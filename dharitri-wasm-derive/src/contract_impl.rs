@@ -6,7 +6,7 @@ use crate::{
         endpoints_mod_gen::generate_endpoints_mod,
         function_selector::generate_function_selector_body, proxy_gen, supertrait_gen,
     },
-    model::ContractTrait,
+    model::{ContractTrait, PublicRole},
 };
 
 /// Provides the implementation for both modules and contracts.
@@ -101,7 +101,11 @@ pub fn contract_implementation(
     let impl_all_auto_impl = supertrait_gen::impl_all_auto_impl(contract.supertraits.as_slice());
     let impl_all_endpoint_wrappers =
         supertrait_gen::impl_all_endpoint_wrappers(contract.supertraits.as_slice());
-    let impl_callable_contract = snippets::impl_callable_contract();
+    let has_upgrade_method = contract
+        .methods
+        .iter()
+        .any(|m| matches!(m.public_role, PublicRole::Upgrade(_)));
+    let impl_callable_contract = snippets::impl_callable_contract(has_upgrade_method);
     let new_contract_object_fn = snippets::new_contract_object_fn();
 
     let contract_obj_code = quote! {
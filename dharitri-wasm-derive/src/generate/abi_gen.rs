@@ -7,6 +7,7 @@ fn generate_endpoint_snippet(
     m: &Method,
     endpoint_name: &str,
     only_owner: bool,
+    only_roles: &[String],
     mutability: EndpointMutabilityMetadata,
     location: EndpointLocationMetadata,
 ) -> proc_macro2::TokenStream {
@@ -52,6 +53,7 @@ fn generate_endpoint_snippet(
             docs: &[ #(#endpoint_docs),* ],
             name: #endpoint_name,
             only_owner: #only_owner,
+            only_roles: &[ #(#only_roles),* ],
             mutability: #mutability_tokens,
             location: #location_tokens,
             payable_in_tokens: &[ #(#payable_in_tokens),* ],
@@ -73,6 +75,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     m,
                     "init",
                     false,
+                    &[],
                     EndpointMutabilityMetadata::Mutable,
                     EndpointLocationMetadata::MainContract,
                 );
@@ -81,12 +84,27 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     contract_abi.constructors.push(endpoint_abi);
                 })
             },
+            PublicRole::Upgrade(_) => {
+                let endpoint_def = generate_endpoint_snippet(
+                    m,
+                    "upgrade",
+                    false,
+                    &[],
+                    EndpointMutabilityMetadata::Mutable,
+                    EndpointLocationMetadata::MainContract,
+                );
+                Some(quote! {
+                    #endpoint_def
+                    contract_abi.upgrade_constructors.push(endpoint_abi);
+                })
+            },
             PublicRole::Endpoint(endpoint_metadata) => {
                 let endpoint_name_str = endpoint_metadata.public_name.to_string();
                 let endpoint_def = generate_endpoint_snippet(
                     m,
                     &endpoint_name_str,
                     endpoint_metadata.only_owner,
+                    endpoint_metadata.only_roles.as_slice(),
                     endpoint_metadata.mutability.clone(),
                     endpoint_metadata.location.clone(),
                 );
@@ -148,6 +166,7 @@ fn generate_abi_method_body(
             docs: &[ #(#contract_docs),* ],
             name: #contract_name,
             constructors: Vec::new(),
+            upgrade_constructors: Vec::new(),
             endpoints: Vec::new(),
             has_callback: #has_callbacks,
             type_descriptions: <dharitri_wasm::abi::TypeDescriptionContainerImpl as dharitri_wasm::abi::TypeDescriptionContainer>::new(),
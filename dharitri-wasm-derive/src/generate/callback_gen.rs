@@ -42,7 +42,7 @@ pub fn generate_callback_selector_and_main(
         } else {
             let cb_selector_body = callback_selector_body(match_arms, module_calls);
             let cb_main_body = quote! {
-                if let Some(___cb_closure___) = dharitri_wasm::types::CallbackClosureForDeser::storage_load_and_clear::<Self::Api>() {
+                if let Some(___cb_closure___) = dharitri_wasm::types::CallbackClosureForDeser::load_for_callback::<Self::Api>() {
                     if let dharitri_wasm::types::CallbackSelectorResult::NotProcessed(_) =
                         self::EndpointWrappers::callback_selector(self, ___cb_closure___)	{
                         dharitri_wasm::api::ErrorApiImpl::signal_error(
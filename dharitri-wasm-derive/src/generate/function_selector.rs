@@ -29,6 +29,11 @@ pub fn generate_function_selector_body(contract: &ContractTrait) -> proc_macro2:
                 "init",
                 &EndpointLocationMetadata::MainContract,
             )),
+            PublicRole::Upgrade(_) => Some(endpoint_match_arm(
+                m,
+                "upgrade",
+                &EndpointLocationMetadata::MainContract,
+            )),
             PublicRole::Endpoint(endpoint_metadata) => Some(endpoint_match_arm(
                 m,
                 endpoint_metadata.public_name.to_string().as_str(),
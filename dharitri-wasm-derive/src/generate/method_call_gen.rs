@@ -1,5 +1,6 @@
 use super::{
-    arg_regular::*, method_gen::generate_arg_call_name, only_owner_gen::*, payable_gen::*, util::*,
+    arg_regular::*, method_gen::generate_arg_call_name, only_owner_gen::*, only_role_gen::*,
+    pause_gen::*, payable_gen::*, util::*,
 };
 use crate::{generate::snippets, model::Method};
 
@@ -35,6 +36,8 @@ pub fn generate_call_method_body_fixed_args(m: &Method) -> proc_macro2::TokenStr
     let api_static_init = snippets::call_method_api_static_init();
     let payable_snippet = generate_payable_snippet(m);
     let only_owner_snippet = generate_only_owner_snippet(m);
+    let only_role_snippet = generate_only_role_snippet(m);
+    let when_not_paused_snippet = generate_when_not_paused_snippet(m);
 
     let mut arg_index = -1i32;
     let arg_init_snippets: Vec<proc_macro2::TokenStream> = m
@@ -67,6 +70,8 @@ pub fn generate_call_method_body_fixed_args(m: &Method) -> proc_macro2::TokenStr
         #api_static_init
         #payable_snippet
         #only_owner_snippet
+        #only_role_snippet
+        #when_not_paused_snippet
         dharitri_wasm::api::EndpointArgumentApiImpl::check_num_arguments(
             &<Self::Api as dharitri_wasm::api::EndpointArgumentApi>::argument_api_impl(),
             #nr_args);
@@ -79,6 +84,8 @@ fn generate_call_method_body_variable_nr_args(m: &Method) -> proc_macro2::TokenS
     let api_static_init = snippets::call_method_api_static_init();
     let payable_snippet = generate_payable_snippet(m);
     let only_owner_snippet = generate_only_owner_snippet(m);
+    let only_role_snippet = generate_only_role_snippet(m);
+    let when_not_paused_snippet = generate_when_not_paused_snippet(m);
 
     let arg_init_snippets: Vec<proc_macro2::TokenStream> = m
         .method_args
@@ -102,6 +109,10 @@ fn generate_call_method_body_variable_nr_args(m: &Method) -> proc_macro2::TokenS
 
         #only_owner_snippet
 
+        #only_role_snippet
+
+        #when_not_paused_snippet
+
         let mut ___arg_loader = dharitri_wasm::io::EndpointDynArgLoader::<Self::Api>::new();
 
         #(#arg_init_snippets)*
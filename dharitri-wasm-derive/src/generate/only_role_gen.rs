@@ -0,0 +1,23 @@
+use super::util::*;
+use crate::model::{Method, PublicRole};
+
+/// The check calls the `PermissionsModule` method through its full path,
+/// so a contract that does not include that module gets an unsatisfied trait bound error,
+/// pointing at the annotated method.
+pub fn generate_only_role_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if !endpoint_metadata.only_roles.is_empty() {
+            let role_literals = endpoint_metadata
+                .only_roles
+                .iter()
+                .map(|role| byte_slice_literal(role.as_bytes()));
+            return quote_spanned! {m.name.span()=>
+                <Self as dharitri_wasm_modules::permissions::PermissionsModule>::require_caller_has_any_role(
+                    self,
+                    &[#(#role_literals),*],
+                );
+            };
+        }
+    }
+    quote! {}
+}
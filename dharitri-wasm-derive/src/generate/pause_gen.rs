@@ -0,0 +1,21 @@
+use super::util::*;
+use crate::model::{Method, PublicRole};
+
+/// The check calls the `PauseModule` method through its full path,
+/// so a contract that does not include that module gets an unsatisfied trait bound error,
+/// pointing at the annotated method.
+pub fn generate_when_not_paused_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if endpoint_metadata.when_not_paused {
+            let endpoint_name = endpoint_metadata.public_name.to_string();
+            let endpoint_name_literal = byte_slice_literal(endpoint_name.as_bytes());
+            return quote_spanned! {m.name.span()=>
+                <Self as dharitri_wasm_modules::pause::PauseModule>::require_endpoint_not_paused(
+                    self,
+                    #endpoint_name_literal,
+                );
+            };
+        }
+    }
+    quote! {}
+}
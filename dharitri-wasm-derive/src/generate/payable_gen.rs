@@ -64,22 +64,54 @@ fn payable_single_snippet_for_metadata(
             }
         },
         MethodPayableMetadata::AnyToken => {
-            let nonce_init = nonce_getter_init(payment_nonce_arg);
-            if payment_amount_arg.is_none() && payment_token_arg.is_none() {
-                nonce_init
-            } else {
-                let payment_var_name = var_name_or_underscore(payment_amount_arg);
-                let token_var_name = var_name_or_underscore(payment_token_arg);
-
-                quote! {
-                    let (#payment_var_name, #token_var_name) = dharitri_wasm::contract_base::CallValueWrapper::<Self::Api>::new().payment_token_pair();
-                    #nonce_init
+            any_token_payment_init(payment_token_arg, payment_amount_arg, payment_nonce_arg)
+        },
+        MethodPayableMetadata::AnyOfTokens(token_identifiers) => {
+            let token_literals: Vec<proc_macro2::TokenStream> = token_identifiers
+                .iter()
+                .map(|token_identifier| byte_str_slice_literal(token_identifier.as_bytes()))
+                .collect();
+            let payment_init =
+                any_token_payment_init(payment_token_arg, payment_amount_arg, payment_nonce_arg);
+            quote! {
+                dharitri_wasm::contract_base::CallValueWrapper::<Self::Api>::new().require_any_of_tokens(&[#(#token_literals),*]);
+                #payment_init
+            }
+        },
+        MethodPayableMetadata::TokenGetter(token_getter) => {
+            let payment_init =
+                any_token_payment_init(payment_token_arg, payment_amount_arg, payment_nonce_arg);
+            quote! {
+                {
+                    let accepted_tokens = self.#token_getter();
+                    dharitri_wasm::contract_base::CallValueWrapper::<Self::Api>::new()
+                        .require_accepted_tokens(|token| accepted_tokens.contains(token));
                 }
+                #payment_init
             }
         },
     }
 }
 
+fn any_token_payment_init(
+    payment_token_arg: &Option<MethodArgument>,
+    payment_amount_arg: &Option<MethodArgument>,
+    payment_nonce_arg: &Option<MethodArgument>,
+) -> proc_macro2::TokenStream {
+    let nonce_init = nonce_getter_init(payment_nonce_arg);
+    if payment_amount_arg.is_none() && payment_token_arg.is_none() {
+        nonce_init
+    } else {
+        let payment_var_name = var_name_or_underscore(payment_amount_arg);
+        let token_var_name = var_name_or_underscore(payment_token_arg);
+
+        quote! {
+            let (#payment_var_name, #token_var_name) = dharitri_wasm::contract_base::CallValueWrapper::<Self::Api>::new().payment_token_pair();
+            #nonce_init
+        }
+    }
+}
+
 fn zero_amount_init(opt_arg: &Option<MethodArgument>) -> proc_macro2::TokenStream {
     if let Some(arg) = opt_arg {
         let pat = &arg.pat;
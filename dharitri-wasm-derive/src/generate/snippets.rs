@@ -41,6 +41,10 @@ pub fn new_contract_object_fn() -> proc_macro2::TokenStream {
                     _phantom: core::marker::PhantomData,
                 })
             }
+
+            fn abi(&self) -> Option<dharitri_wasm::abi::ContractAbi> {
+                Some(<AbiProvider as dharitri_wasm::contract_base::ContractAbiProvider>::abi())
+            }
         }
     }
 }
@@ -60,7 +64,7 @@ pub fn impl_auto_impl() -> proc_macro2::TokenStream {
     }
 }
 
-pub fn impl_callable_contract() -> proc_macro2::TokenStream {
+pub fn impl_callable_contract(has_upgrade_method: bool) -> proc_macro2::TokenStream {
     quote! {
         impl<A> dharitri_wasm::contract_base::CallableContract for ContractObj<A>
         where
@@ -70,6 +74,10 @@ pub fn impl_callable_contract() -> proc_macro2::TokenStream {
                 EndpointWrappers::call(self, fn_name)
             }
 
+            fn has_upgrade_method(&self) -> bool {
+                #has_upgrade_method
+            }
+
             fn clone_obj(&self) -> dharitri_wasm::Box<dyn dharitri_wasm::contract_base::CallableContract> {
                 dharitri_wasm::Box::new(ContractObj::<A> {
                     _phantom: core::marker::PhantomData,
@@ -10,6 +10,8 @@ pub struct EndpointMetadata {
     pub public_name: syn::Ident,
     pub payable: MethodPayableMetadata,
     pub only_owner: bool,
+    pub only_roles: Vec<String>,
+    pub when_not_paused: bool,
     pub mutability: EndpointMutabilityMetadata,
     pub location: EndpointLocationMetadata,
 }
@@ -25,6 +27,9 @@ pub enum PublicRole {
     /// The smart contract constructor. There can be only one.
     Init(InitMetadata),
 
+    /// Called instead of the constructor when the contract code is upgraded. There can be at most one.
+    Upgrade(InitMetadata),
+
     /// Means it gets a smart contract function generated for it
     Endpoint(EndpointMetadata),
 
@@ -76,7 +76,9 @@ impl Method {
 
     pub fn is_payable(&self) -> bool {
         match &self.public_role {
-            PublicRole::Init(init_metadata) => init_metadata.payable.is_payable(),
+            PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) => {
+                init_metadata.payable.is_payable()
+            },
             PublicRole::Endpoint(endpoint_metadata) => endpoint_metadata.payable.is_payable(),
             PublicRole::Callback(_) | PublicRole::CallbackRaw => true,
             PublicRole::Private => false,
@@ -85,7 +87,9 @@ impl Method {
 
     pub fn payable_metadata(&self) -> MethodPayableMetadata {
         match &self.public_role {
-            PublicRole::Init(init_metadata) => init_metadata.payable.clone(),
+            PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) => {
+                init_metadata.payable.clone()
+            },
             PublicRole::Endpoint(endpoint_metadata) => endpoint_metadata.payable.clone(),
             PublicRole::Callback(_) | PublicRole::CallbackRaw => MethodPayableMetadata::AnyToken,
             PublicRole::Private => MethodPayableMetadata::NotPayable,
@@ -6,6 +6,10 @@ pub enum MethodPayableMetadata {
     Moax,
     SingleDctToken(String),
     AnyToken,
+    /// Any of several tokens, e.g. `#[payable("TOKEN-1", "TOKEN-2")]`. Can also contain `MOAX`.
+    AnyOfTokens(Vec<String>),
+    /// The accepted tokens are provided at runtime by a contract method, e.g. `#[payable(token_getter = "accepted_tokens")]`.
+    TokenGetter(syn::Ident),
 }
 
 impl MethodPayableMetadata {
@@ -26,6 +30,9 @@ impl MethodPayableMetadata {
             MethodPayableMetadata::Moax => vec!["MOAX".to_string()],
             MethodPayableMetadata::SingleDctToken(s) => vec![s.clone()],
             MethodPayableMetadata::AnyToken => vec!["*".to_string()],
+            MethodPayableMetadata::AnyOfTokens(tokens) => tokens.clone(),
+            // the list is only known at runtime
+            MethodPayableMetadata::TokenGetter(_) => vec!["*".to_string()],
         }
     }
 }
@@ -1,5 +1,7 @@
 pub(super) static ATTR_PAYABLE: &str = "payable";
 pub(super) static ATTR_ONLY_OWNER: &str = "only_owner";
+pub(super) static ATTR_ONLY_ROLE: &str = "only_role";
+pub(super) static ATTR_WHEN_NOT_PAUSED: &str = "when_not_paused";
 pub(super) static ATTR_OUTPUT_NAME: &str = "output_name";
 pub(super) static ATTR_PAYMENT: &str = "payment"; // synonymous with `payment_amount`
 pub(super) static ATTR_PAYMENT_AMOUNT: &str = "payment_amount";
@@ -8,6 +10,7 @@ pub(super) static ATTR_PAYMENT_NONCE: &str = "payment_nonce";
 pub(super) static ATTR_PAYMENT_MULTI: &str = "payment_multi";
 pub(super) static ATTR_VAR_ARGS: &str = "var_args";
 pub(super) static ATTR_INIT: &str = "init";
+pub(super) static ATTR_UPGRADE: &str = "upgrade";
 pub(super) static ATTR_VIEW: &str = "view";
 pub(super) static ATTR_EXTERNAL_VIEW: &str = "external_view";
 pub(super) static ATTR_LEGACY_EVENT: &str = "legacy_event";
@@ -23,3 +26,4 @@ pub(super) static ATTR_STORAGE_MAPPER: &str = "storage_mapper";
 pub(super) static ATTR_STORAGE_IS_EMPTY: &str = "storage_is_empty";
 pub(super) static ATTR_STORAGE_CLEAR: &str = "storage_clear";
 pub(super) static ATTR_PROXY: &str = "proxy";
+pub(super) static ATTR_CODEC: &str = "codec";
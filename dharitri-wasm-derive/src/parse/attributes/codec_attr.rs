@@ -0,0 +1,43 @@
+use super::attr_names::*;
+
+static CODEC_SKIP: &str = "skip";
+
+/// Fields marked `#[codec(skip)]` are not serialized, so they do not show up in the ABI either.
+pub fn is_codec_skip(attr: &syn::Attribute) -> bool {
+    if !attr.path.is_ident(ATTR_CODEC) {
+        return false;
+    }
+    if let Ok(syn::Meta::List(meta_list)) = attr.parse_meta() {
+        meta_list.nested.iter().any(|nested| {
+            matches!(nested, syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident(CODEC_SKIP))
+        })
+    } else {
+        false
+    }
+}
+
+static CODEC_VERSION: &str = "version";
+
+/// The current version of types marked `#[codec(version = ...)]`, which is encoded as a leading `u8` tag.
+pub fn codec_version(attrs: &[syn::Attribute]) -> Option<u8> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident(ATTR_CODEC))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(meta_list)) => Some(meta_list),
+            _ => None,
+        })
+        .flat_map(|meta_list| meta_list.nested.into_iter())
+        .find_map(|nested| match nested {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident(CODEC_VERSION) =>
+            {
+                if let syn::Lit::Int(lit_int) = &name_value.lit {
+                    lit_int.base10_parse::<u8>().ok()
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        })
+}
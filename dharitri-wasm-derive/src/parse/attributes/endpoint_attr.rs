@@ -4,10 +4,42 @@ pub fn is_init(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_INIT)
 }
 
+pub fn is_upgrade(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_UPGRADE)
+}
+
 pub fn is_only_owner(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_ONLY_OWNER)
 }
 
+pub fn is_when_not_paused(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_WHEN_NOT_PAUSED)
+}
+
+/// Parses `#[only_role("ROLE_1", "ROLE_2")]`, yielding the role names.
+pub fn only_role_names(attr: &syn::Attribute) -> Option<Vec<String>> {
+    if !attr.path.is_ident(ATTR_ONLY_ROLE) {
+        return None;
+    }
+    let meta_list = match attr.parse_meta() {
+        Ok(syn::Meta::List(meta_list)) => meta_list,
+        _ => panic!("role names must be specified in parantheses, e.g. `#[only_role(\"ADMIN\")]`"),
+    };
+    let role_names: Vec<String> = meta_list
+        .nested
+        .iter()
+        .map(|nested| match nested {
+            syn::NestedMeta::Lit(syn::Lit::Str(lit_str)) => lit_str.value(),
+            _ => panic!("string literal expected as only_role attribute argument"),
+        })
+        .collect();
+    assert!(
+        !role_names.is_empty(),
+        "only_role attribute requires at least one role name"
+    );
+    Some(role_names)
+}
+
 pub fn is_callback_raw(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_CALLBACK_RAW_DECL)
 }
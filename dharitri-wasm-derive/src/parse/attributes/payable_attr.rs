@@ -1,16 +1,17 @@
 use super::attr_names::*;
 
+static PAYABLE_TOKEN_GETTER: &str = "token_getter";
+
 pub struct PayableAttribute {
-    pub identifier: Option<String>,
+    pub identifiers: Vec<String>,
+    pub token_getter: Option<syn::Ident>,
 }
 
 impl PayableAttribute {
     pub fn parse(attr: &syn::Attribute) -> Option<PayableAttribute> {
         if let Some(first_seg) = attr.path.segments.first() {
             if first_seg.ident == ATTR_PAYABLE {
-                Some(PayableAttribute {
-                    identifier: extract_token_identifier(attr),
-                })
+                Some(extract_payable_arguments(attr))
             } else {
                 None
             }
@@ -20,38 +21,42 @@ impl PayableAttribute {
     }
 }
 
-/// Current implementation only works with 1 token name.
-/// Might be extended in the future.
-fn extract_token_identifier(attr: &syn::Attribute) -> Option<String> {
-    let mut iter = attr.clone().tokens.into_iter();
-    let result_str = match iter.next() {
-        Some(proc_macro2::TokenTree::Group(group)) => {
-            assert!(
-                group.delimiter() == proc_macro2::Delimiter::Parenthesis,
-                "payable token name must be specified in parantheses"
-            );
-            let mut iter2 = group.stream().into_iter();
-            match iter2.next() {
-                Some(proc_macro2::TokenTree::Literal(lit)) => {
-                    let str_val = lit.to_string();
-                    assert!(
-                        str_val.starts_with('\"') && str_val.ends_with('\"'),
-                        "string literal expected as attribute argument"
-                    );
-                    let substr = &str_val[1..str_val.len() - 1];
-                    Some(substr.to_string())
-                },
-                _ => panic!("literal expected as event identifier"),
-            }
-        },
-        None => None,
-        _ => panic!("unexpected payable attribute format"),
-    };
+/// Accepts one or more token names, e.g. `#[payable("TOKEN-1", "TOKEN-2")]`,
+/// or the name of a method providing the accepted tokens, e.g. `#[payable(token_getter = "accepted_tokens")]`.
+fn extract_payable_arguments(attr: &syn::Attribute) -> PayableAttribute {
+    let mut identifiers = Vec::new();
+    let mut token_getter = None;
+    if attr.tokens.is_empty() {
+        return PayableAttribute {
+            identifiers,
+            token_getter,
+        };
+    }
 
-    assert!(
-        iter.next().is_none(),
-        "event too many tokens in event attribute"
-    );
+    let meta_list = match attr.parse_meta() {
+        Ok(syn::Meta::List(meta_list)) => meta_list,
+        _ => panic!("payable token name must be specified in parantheses"),
+    };
+    for nested in meta_list.nested.iter() {
+        match nested {
+            syn::NestedMeta::Lit(syn::Lit::Str(lit_str)) => {
+                identifiers.push(lit_str.value());
+            },
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident(PAYABLE_TOKEN_GETTER) =>
+            {
+                if let syn::Lit::Str(lit_str) = &name_value.lit {
+                    token_getter = Some(syn::Ident::new(lit_str.value().as_str(), lit_str.span()));
+                } else {
+                    panic!("payable token getter must be a method name, e.g. `#[payable(token_getter = \"accepted_tokens\")]`");
+                }
+            },
+            _ => panic!("string literal expected as payable attribute argument"),
+        }
+    }
 
-    result_str
+    PayableAttribute {
+        identifiers,
+        token_getter,
+    }
 }
@@ -5,18 +5,48 @@ use crate::model::{
 
 use super::{
     attributes::{
-        is_callback_raw, is_init, is_only_owner, CallbackAttribute, EndpointAttribute,
-        ExternalViewAttribute, OutputNameAttribute, ViewAttribute,
+        is_callback_raw, is_init, is_only_owner, is_upgrade, is_when_not_paused, only_role_names,
+        CallbackAttribute, EndpointAttribute, ExternalViewAttribute, OutputNameAttribute,
+        ViewAttribute,
     },
     MethodAttributesPass1,
 };
 
 fn check_single_role(method: &Method) {
     assert!(matches!(method.public_role, PublicRole::Private),
-		"Can only annotate with one of the following arguments: `#[init]`, `#[endpoint]`, `#[view]`, `#[callback]`, `#[callback_raw]`."
+		"Can only annotate with one of the following arguments: `#[init]`, `#[upgrade]`, `#[endpoint]`, `#[view]`, `#[callback]`, `#[callback_raw]`."
 	);
 }
 
+fn check_no_only_role(pass_1_data: &MethodAttributesPass1) {
+    assert!(
+        pass_1_data.only_roles.is_empty(),
+        "`#[only_role]` is only allowed on endpoints and views, not on `#[init]` or `#[upgrade]`."
+    );
+}
+
+fn check_no_when_not_paused(pass_1_data: &MethodAttributesPass1) {
+    assert!(
+        !pass_1_data.when_not_paused,
+        "`#[when_not_paused]` is only allowed on endpoints and views, not on `#[init]` or `#[upgrade]`."
+    );
+}
+
+/// Called once all attributes are processed, for the methods that ended up neither endpoints nor views.
+pub fn check_endpoint_only_attributes(pass_1_data: &MethodAttributesPass1, method: &Method) {
+    if matches!(method.public_role, PublicRole::Endpoint(_)) {
+        return;
+    }
+    assert!(
+        pass_1_data.only_roles.is_empty(),
+        "`#[only_role]` is only allowed on endpoints and views."
+    );
+    assert!(
+        !pass_1_data.when_not_paused,
+        "`#[when_not_paused]` is only allowed on endpoints and views."
+    );
+}
+
 pub fn process_init_attribute(
     attr: &syn::Attribute,
     pass_1_data: &MethodAttributesPass1,
@@ -24,6 +54,8 @@ pub fn process_init_attribute(
 ) -> bool {
     if is_init(attr) {
         check_single_role(&*method);
+        check_no_only_role(pass_1_data);
+        check_no_when_not_paused(pass_1_data);
         method.public_role = PublicRole::Init(InitMetadata {
             payable: pass_1_data.payable.clone(),
         });
@@ -33,6 +65,24 @@ pub fn process_init_attribute(
     }
 }
 
+pub fn process_upgrade_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &MethodAttributesPass1,
+    method: &mut Method,
+) -> bool {
+    if is_upgrade(attr) {
+        check_single_role(&*method);
+        check_no_only_role(pass_1_data);
+        check_no_when_not_paused(pass_1_data);
+        method.public_role = PublicRole::Upgrade(InitMetadata {
+            payable: pass_1_data.payable.clone(),
+        });
+        true
+    } else {
+        false
+    }
+}
+
 pub fn process_only_owner_attribute(
     attr: &syn::Attribute,
     pass_1_data: &mut MethodAttributesPass1,
@@ -44,6 +94,29 @@ pub fn process_only_owner_attribute(
     is_only_owner
 }
 
+pub fn process_only_role_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    if let Some(role_names) = only_role_names(attr) {
+        pass_1_data.only_roles.extend(role_names);
+        true
+    } else {
+        false
+    }
+}
+
+pub fn process_when_not_paused_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let is_when_not_paused = is_when_not_paused(attr);
+    if is_when_not_paused {
+        pass_1_data.when_not_paused = true;
+    }
+    is_when_not_paused
+}
+
 pub fn process_endpoint_attribute(
     attr: &syn::Attribute,
     pass_1_data: &MethodAttributesPass1,
@@ -60,6 +133,8 @@ pub fn process_endpoint_attribute(
                 public_name: endpoint_ident,
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
+                only_roles: pass_1_data.only_roles.clone(),
+                when_not_paused: pass_1_data.when_not_paused,
                 mutability: EndpointMutabilityMetadata::Mutable,
                 location: EndpointLocationMetadata::MainContract,
             });
@@ -83,6 +158,8 @@ pub fn process_view_attribute(
                 public_name: view_ident,
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
+                only_roles: pass_1_data.only_roles.clone(),
+                when_not_paused: pass_1_data.when_not_paused,
                 mutability: EndpointMutabilityMetadata::Readonly,
                 location: EndpointLocationMetadata::MainContract,
             });
@@ -106,6 +183,8 @@ pub fn process_external_view_attribute(
                 public_name: view_ident,
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
+                only_roles: pass_1_data.only_roles.clone(),
+                when_not_paused: pass_1_data.when_not_paused,
                 mutability: EndpointMutabilityMetadata::Readonly,
                 location: EndpointLocationMetadata::ViewContract,
             });
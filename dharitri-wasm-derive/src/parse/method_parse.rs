@@ -8,15 +8,18 @@ use super::{
         process_storage_is_empty_attribute, process_storage_mapper_attribute,
         process_storage_set_attribute,
     },
-    extract_method_args, process_callback_attribute, process_callback_raw_attribute,
-    process_endpoint_attribute, process_external_view_attribute, process_init_attribute,
-    process_only_owner_attribute, process_output_names_attribute, process_payable_attribute,
-    process_view_attribute,
+    check_endpoint_only_attributes, extract_method_args, process_callback_attribute,
+    process_callback_raw_attribute, process_endpoint_attribute, process_external_view_attribute,
+    process_init_attribute, process_only_owner_attribute, process_only_role_attribute,
+    process_output_names_attribute, process_payable_attribute, process_upgrade_attribute,
+    process_view_attribute, process_when_not_paused_attribute,
 };
 pub struct MethodAttributesPass1 {
     pub method_name: String,
     pub payable: MethodPayableMetadata,
     pub only_owner: bool,
+    pub only_roles: Vec<String>,
+    pub when_not_paused: bool,
 }
 
 pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperties) -> Method {
@@ -32,6 +35,8 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
         method_name: m.sig.ident.to_string(),
         payable: MethodPayableMetadata::NotPayable,
         only_owner: trait_attributes.only_owner,
+        only_roles: Vec::new(),
+        when_not_paused: false,
     };
     let mut first_pass_unprocessed_attributes = Vec::new();
 
@@ -58,6 +63,7 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
         &first_pass_data,
         &mut method,
     );
+    check_endpoint_only_attributes(&first_pass_data, &method);
 
     method
 }
@@ -81,6 +87,8 @@ fn process_attribute_first_pass(
 ) -> bool {
     process_payable_attribute(attr, first_pass_data)
         || process_only_owner_attribute(attr, first_pass_data)
+        || process_only_role_attribute(attr, first_pass_data)
+        || process_when_not_paused_attribute(attr, first_pass_data)
 }
 
 fn process_attributes_second_pass(
@@ -102,6 +110,7 @@ fn process_attribute_second_pass(
     method: &mut Method,
 ) -> bool {
     process_init_attribute(attr, first_pass_data, method)
+        || process_upgrade_attribute(attr, first_pass_data, method)
         || process_endpoint_attribute(attr, first_pass_data, method)
         || process_view_attribute(attr, first_pass_data, method)
         || process_external_view_attribute(attr, first_pass_data, method)
@@ -6,12 +6,20 @@ pub fn process_payable_attribute(
     pass_1_data: &mut MethodAttributesPass1,
 ) -> bool {
     PayableAttribute::parse(attr).map(|payable_attr| {
-		if let Some(identifier) = payable_attr.identifier {
-			pass_1_data.payable = parse_payable_identifier(identifier.as_str());
-		} else {
+		if let Some(token_getter) = payable_attr.token_getter {
+			assert!(
+				payable_attr.identifiers.is_empty(),
+				"Endpoint `payable` attribute cannot have both token names and a token getter. Method name: {}",
+				&pass_1_data.method_name);
+			pass_1_data.payable = MethodPayableMetadata::TokenGetter(token_getter);
+		} else if payable_attr.identifiers.is_empty() {
 			panic!(
 				"Endpoint `payable` attribute requires one argument. Replace with `#[payable(\"*\")]` or `#[payable(\"MOAX\")]`. Method name: {}",
 				&pass_1_data.method_name);
+		} else if payable_attr.identifiers.len() == 1 {
+			pass_1_data.payable = parse_payable_identifier(payable_attr.identifiers[0].as_str());
+		} else {
+			pass_1_data.payable = parse_payable_identifier_list(payable_attr.identifiers);
 		}
 	}).is_some()
 }
@@ -24,3 +32,21 @@ fn parse_payable_identifier(identifier: &str) -> MethodPayableMetadata {
         _ => MethodPayableMetadata::SingleDctToken(identifier.to_string()),
     }
 }
+
+fn parse_payable_identifier_list(identifiers: Vec<String>) -> MethodPayableMetadata {
+    for (index, identifier) in identifiers.iter().enumerate() {
+        match identifier.as_str() {
+            "*" => {
+                panic!("\"*\" cannot be combined with other token names in #[payable] attribute")
+            },
+            "" => panic!("empty token name not allowed in #[payable] attribute"),
+            _ => {},
+        }
+        assert!(
+            !identifiers[..index].contains(identifier),
+            "duplicate token name `{}` in #[payable] attribute",
+            identifier
+        );
+    }
+    MethodPayableMetadata::AnyOfTokens(identifiers)
+}
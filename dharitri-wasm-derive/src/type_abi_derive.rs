@@ -1,8 +1,11 @@
-use super::parse::attributes::extract_doc;
+use super::parse::attributes::{codec_version, extract_doc, is_codec_skip};
 use proc_macro::TokenStream;
 use quote::quote;
 
 fn field_snippet(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
+    if field.attrs.iter().any(is_codec_skip) {
+        return quote! {};
+    }
     let field_docs = extract_doc(field.attrs.as_slice());
     let field_name_str = if let Some(ident) = &field.ident {
         ident.to_string()
@@ -38,17 +41,35 @@ fn fields_snippets(fields: &syn::Fields) -> Vec<proc_macro2::TokenStream> {
     }
 }
 
+/// Versioned types are encoded with a leading version tag, which shows up in the ABI as an extra first field.
+fn version_field_snippet(attrs: &[syn::Attribute]) -> proc_macro2::TokenStream {
+    if let Some(version) = codec_version(attrs) {
+        let version_doc = format!("Layout version, currently {}.", version);
+        quote! {
+            field_descriptions.push(dharitri_wasm::abi::StructFieldDescription {
+                docs: &[#version_doc],
+                name: "_version",
+                field_type: <u8>::type_name(),
+            });
+        }
+    } else {
+        quote! {}
+    }
+}
+
 pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
     let type_docs = extract_doc(ast.attrs.as_slice());
     let type_description_impl = match &ast.data {
         syn::Data::Struct(data_struct) => {
             let struct_field_snippets = fields_snippets(&data_struct.fields);
+            let version_field_snippet = version_field_snippet(ast.attrs.as_slice());
             quote! {
                 fn provide_type_descriptions<TDC: dharitri_wasm::abi::TypeDescriptionContainer>(accumulator: &mut TDC) {
                     let type_name = Self::type_name();
                     if !accumulator.contains_type(&type_name) {
                         accumulator.reserve_type_name(type_name.clone());
                         let mut field_descriptions = dharitri_wasm::Vec::new();
+                        #version_field_snippet
                         #(#struct_field_snippets)*
                         accumulator.insert(
                             type_name.clone(),
@@ -1,12 +1,52 @@
 dharitri_wasm::imports!();
 dharitri_wasm::derive_imports!();
 
-use crate::bonding_curve::utils::structs::CurveArguments;
-pub trait CurveFunction<M: ManagedTypeApi> {
+use dharitri_wasm::abi::TypeAbi;
+
+use crate::bonding_curve::{curves::fixed_point::CURVE_PRECISION, utils::structs::CurveArguments};
+
+/// Direction in which fractional prices get rounded.
+/// Buying rounds up and selling rounds down,
+/// so buying and then selling the same tokens never pays out more than was paid in.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+/// A price curve, giving the price of a token based on the supply already sold.
+///
+/// The price of several tokens is the area under the curve over the corresponding supply interval.
+/// Contracts can plug in their own curves by implementing this trait.
+/// Curves get saved to storage together with the rest of the bonding curve data, and show up in the ABI.
+pub trait CurveFunction<M: ManagedTypeApi>:
+    NestedEncode + NestedDecode + TypeAbi + 'static
+{
+    /// Area under the curve between 0 and `supply`, in fixed point (see `CURVE_PRECISION`).
+    /// Must not decrease as the supply increases.
+    fn area_until(
+        &self,
+        supply: &BigUint<M>,
+        arguments: &CurveArguments<M>,
+    ) -> SCResult<BigUint<M>>;
+
+    /// Total price of `amount` tokens, starting from `token_start`.
     fn calculate_price(
         &self,
         token_start: &BigUint<M>,
         amount: &BigUint<M>,
         arguments: &CurveArguments<M>,
-    ) -> SCResult<BigUint<M>>;
+        rounding: Rounding,
+    ) -> SCResult<BigUint<M>> {
+        let area_start = self.area_until(token_start, arguments)?;
+        let area_end = self.area_until(&(token_start + amount), arguments)?;
+        require_old!(area_end >= area_start, "Curve area must not decrease");
+
+        let area = area_end - area_start;
+        let price = match rounding {
+            Rounding::Down => area / CURVE_PRECISION,
+            Rounding::Up => (area + CURVE_PRECISION - 1u64) / CURVE_PRECISION,
+        };
+        Ok(price)
+    }
 }
@@ -0,0 +1,35 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{
+    curves::{
+        curve_function::CurveFunction,
+        fixed_point::{self, CURVE_PRECISION},
+    },
+    utils::structs::CurveArguments,
+};
+
+/// `price(x) = initial_price * e^(growth_rate * x)`
+///
+/// The growth rate is in fixed point and must not be 0.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct ExponentialFunction<M: ManagedTypeApi> {
+    pub initial_price: BigUint<M>,
+    pub growth_rate: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for ExponentialFunction<M> {
+    fn area_until(
+        &self,
+        supply: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> SCResult<BigUint<M>> {
+        require_old!(self.growth_rate > 0, "Growth rate must not be 0");
+
+        // initial_price * (e^(growth_rate * supply) - 1) / growth_rate
+        let exp = fixed_point::exp(&(&self.growth_rate * supply))?;
+        let area =
+            &self.initial_price * &(exp - CURVE_PRECISION) * CURVE_PRECISION / &self.growth_rate;
+        Ok(area)
+    }
+}
@@ -0,0 +1,61 @@
+dharitri_wasm::imports!();
+
+/// The value of 1 in the fixed point representation used by the curves (18 decimals).
+pub const CURVE_PRECISION: u64 = 1_000_000_000_000_000_000;
+
+/// ln(2), in fixed point.
+const LN_2: u64 = 693_147_180_559_945_309;
+
+/// Largest power of 2 `exp` scales its result by, keeping results within 2^255 * e^ln(2) in fixed point.
+const MAX_EXP_POWER_OF_TWO: u64 = 255;
+
+/// e^x, for a fixed point `x`.
+/// The result is in fixed point, every step rounds down, so it can be a few units below the exact value.
+pub fn exp<M: ManagedTypeApi>(x: &BigUint<M>) -> SCResult<BigUint<M>> {
+    // e^x = 2^k * e^r, with 0 <= r < ln(2)
+    let k = (x / LN_2).to_u64().ok_or("Curve value out of range")?;
+    require_old!(k <= MAX_EXP_POWER_OF_TWO, "Curve value out of range");
+    let r = x - &(BigUint::from(LN_2) * k);
+
+    let mut result = BigUint::from(CURVE_PRECISION);
+    let mut term = BigUint::from(CURVE_PRECISION);
+    let mut i = 1u64;
+    loop {
+        term = term * &r / CURVE_PRECISION / i;
+        if term == 0 {
+            break;
+        }
+        result += &term;
+        i += 1;
+    }
+
+    Ok(result << k as usize)
+}
+
+/// ln(x), for an integer `x` of at least 1.
+/// The result is in fixed point, every step rounds down, so it can be a few units below the exact value.
+pub fn ln<M: ManagedTypeApi>(x: &BigUint<M>) -> SCResult<BigUint<M>> {
+    require_old!(x > &0, "Cannot compute the logarithm of 0");
+
+    // ln(x) = k * ln(2) + ln(m), with x = 2^k * m and 1 <= m < 2
+    let k = x.log2();
+    let m = (x * CURVE_PRECISION) >> k as usize;
+
+    // ln(m) = 2 * (z + z^3 / 3 + z^5 / 5 + ...), with z = (m - 1) / (m + 1) <= 1/3
+    let z = (&m - CURVE_PRECISION) * CURVE_PRECISION / (&m + CURVE_PRECISION);
+    let z_squared = &z * &z / CURVE_PRECISION;
+    let mut series = BigUint::zero();
+    let mut power = z;
+    let mut i = 1u64;
+    loop {
+        let term = &power / i;
+        if term == 0 {
+            break;
+        }
+        series += term;
+        power = power * &z_squared / CURVE_PRECISION;
+        i += 2;
+    }
+
+    Ok(BigUint::from(LN_2) * k as u64 + series * 2u32)
+}
@@ -1,8 +1,12 @@
 dharitri_wasm::imports!();
 dharitri_wasm::derive_imports!();
 
-use crate::bonding_curve::{curves::curve_function::CurveFunction, utils::structs::CurveArguments};
+use crate::bonding_curve::{
+    curves::{curve_function::CurveFunction, fixed_point::CURVE_PRECISION},
+    utils::structs::CurveArguments,
+};
 
+/// `price(x) = linear_coefficient * x + initial_price`
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
 pub struct LinearFunction<M: ManagedTypeApi> {
     pub initial_price: BigUint<M>,
@@ -10,18 +14,14 @@ pub struct LinearFunction<M: ManagedTypeApi> {
 }
 
 impl<M: ManagedTypeApi> CurveFunction<M> for LinearFunction<M> {
-    fn calculate_price(
+    fn area_until(
         &self,
-        token_start: &BigUint<M>,
-        amount: &BigUint<M>,
+        supply: &BigUint<M>,
         _arguments: &CurveArguments<M>,
     ) -> SCResult<BigUint<M>> {
-        Ok(
-            &self.linear_coefficient * &sum_interval(amount, token_start)
-                + &self.initial_price * amount,
-        )
+        // linear_coefficient * supply^2 / 2 + initial_price * supply
+        let area = &self.linear_coefficient * &(supply * supply) * (CURVE_PRECISION / 2)
+            + &self.initial_price * supply * CURVE_PRECISION;
+        Ok(area)
     }
 }
-fn sum_interval<M: ManagedTypeApi>(n: &BigUint<M>, x: &BigUint<M>) -> BigUint<M> {
-    x * n + &(n - 1u32) * n / 2u32
-}
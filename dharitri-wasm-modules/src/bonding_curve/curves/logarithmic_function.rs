@@ -0,0 +1,35 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{
+    curves::{
+        curve_function::CurveFunction,
+        fixed_point::{self, CURVE_PRECISION},
+    },
+    utils::structs::CurveArguments,
+};
+
+/// `price(x) = coefficient * ln(1 + x) + initial_price`
+///
+/// The coefficient is in fixed point.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct LogarithmicFunction<M: ManagedTypeApi> {
+    pub initial_price: BigUint<M>,
+    pub coefficient: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for LogarithmicFunction<M> {
+    fn area_until(
+        &self,
+        supply: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> SCResult<BigUint<M>> {
+        // coefficient * ((1 + supply) * ln(1 + supply) - supply) + initial_price * supply
+        let supply_plus_one = supply + 1u32;
+        let ln = fixed_point::ln(&supply_plus_one)?;
+        let log_area = supply_plus_one * ln - supply * CURVE_PRECISION;
+        let area = &self.coefficient * &log_area / CURVE_PRECISION
+            + &self.initial_price * supply * CURVE_PRECISION;
+        Ok(area)
+    }
+}
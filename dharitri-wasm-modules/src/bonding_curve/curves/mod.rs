@@ -1,2 +1,7 @@
 pub mod curve_function;
+pub mod exponential_function;
+pub mod fixed_point;
 pub mod linear_function;
+pub mod logarithmic_function;
+pub mod piecewise_linear_function;
+pub mod power_function;
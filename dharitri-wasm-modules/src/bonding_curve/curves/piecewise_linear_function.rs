@@ -0,0 +1,92 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{
+    curves::{curve_function::CurveFunction, fixed_point::CURVE_PRECISION},
+    utils::structs::CurveArguments,
+};
+
+#[derive(
+    ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone,
+)]
+pub struct CurvePoint<M: ManagedTypeApi> {
+    pub supply: BigUint<M>,
+    pub price: BigUint<M>,
+}
+
+/// Price interpolated linearly between consecutive points.
+///
+/// The first point must be at supply 0, the supplies of the points must be strictly increasing.
+/// The price stays constant after the last point.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct PiecewiseLinearFunction<M: ManagedTypeApi> {
+    pub points: ManagedVec<M, CurvePoint<M>>,
+}
+
+impl<M: ManagedTypeApi> PiecewiseLinearFunction<M> {
+    /// Checks all the points, not only the ones before the requested supply,
+    /// so an invalid curve is rejected whatever the supply.
+    fn check_points(&self) -> SCResult<()> {
+        let first_point = self.points.try_get(0).ok_or("Curve has no points")?;
+        require_old!(
+            first_point.supply == 0,
+            "First curve point must be at supply 0"
+        );
+
+        let mut previous = first_point;
+        for point in self.points.iter().skip(1) {
+            require_old!(
+                point.supply > previous.supply,
+                "Curve points must have increasing supplies"
+            );
+            previous = point;
+        }
+        Ok(())
+    }
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for PiecewiseLinearFunction<M> {
+    fn area_until(
+        &self,
+        supply: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> SCResult<BigUint<M>> {
+        self.check_points()?;
+
+        let mut area = BigUint::zero();
+        let mut start = self.points.get(0);
+        for end in self.points.iter().skip(1) {
+            if supply <= &start.supply {
+                return Ok(area);
+            }
+
+            let width = &end.supply - &start.supply;
+            if supply < &end.supply {
+                let covered = supply - &start.supply;
+                return Ok(area + segment_area(&start, &end, &width, &covered));
+            }
+            area += segment_area(&start, &end, &width, &width);
+            start = end;
+        }
+
+        let remaining = supply - &start.supply;
+        Ok(area + &start.price * &remaining * CURVE_PRECISION)
+    }
+}
+
+/// Area over the first `covered` supply units of the segment between `start` and `end`, in fixed point.
+fn segment_area<M: ManagedTypeApi>(
+    start: &CurvePoint<M>,
+    end: &CurvePoint<M>,
+    width: &BigUint<M>,
+    covered: &BigUint<M>,
+) -> BigUint<M> {
+    // trapezoid: covered * (2 * start_price * width +/- price_difference * covered) / (2 * width)
+    let start_part = &start.price * width * 2u32;
+    let numerator = if end.price >= start.price {
+        start_part + &(&end.price - &start.price) * covered
+    } else {
+        start_part - &(&start.price - &end.price) * covered
+    };
+    numerator * covered * CURVE_PRECISION / (width * 2u32)
+}
@@ -0,0 +1,31 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{
+    curves::{curve_function::CurveFunction, fixed_point::CURVE_PRECISION},
+    utils::structs::CurveArguments,
+};
+
+/// `price(x) = coefficient * x^exponent + initial_price`
+///
+/// The coefficient is in fixed point.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct PowerFunction<M: ManagedTypeApi> {
+    pub initial_price: BigUint<M>,
+    pub coefficient: BigUint<M>,
+    pub exponent: u32,
+}
+
+impl<M: ManagedTypeApi> CurveFunction<M> for PowerFunction<M> {
+    fn area_until(
+        &self,
+        supply: &BigUint<M>,
+        _arguments: &CurveArguments<M>,
+    ) -> SCResult<BigUint<M>> {
+        // coefficient * supply^(exponent + 1) / (exponent + 1) + initial_price * supply
+        let next_exponent = self.exponent + 1;
+        let area = &self.coefficient * &supply.pow(next_exponent) / next_exponent
+            + &self.initial_price * supply * CURVE_PRECISION;
+        Ok(area)
+    }
+}
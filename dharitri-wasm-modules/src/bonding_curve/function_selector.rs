@@ -2,15 +2,32 @@ dharitri_wasm::imports!();
 dharitri_wasm::derive_imports!();
 
 use crate::bonding_curve::{
-    curves::{curve_function::CurveFunction, linear_function::LinearFunction},
+    curves::{
+        curve_function::{CurveFunction, Rounding},
+        exponential_function::ExponentialFunction,
+        linear_function::LinearFunction,
+        logarithmic_function::LogarithmicFunction,
+        piecewise_linear_function::PiecewiseLinearFunction,
+        power_function::PowerFunction,
+    },
     utils::structs::CurveArguments,
 };
 
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+/// The predefined curves.
+///
+/// Contracts needing other curves can define their own selector, implementing `CurveFunction`.
+/// The variants are stored by their index, so new curves only get added at the end.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone, Default)]
 pub enum FunctionSelector<M: ManagedTypeApi> {
     Linear(LinearFunction<M>),
+    /// `price = (token_start + amount)^3 / 3 + balance + initial_cost`, kept as it was.
     CustomExample(BigUint<M>),
+    #[default]
     None,
+    Exponential(ExponentialFunction<M>),
+    Power(PowerFunction<M>),
+    Logarithmic(LogarithmicFunction<M>),
+    PiecewiseLinear(PiecewiseLinearFunction<M>),
 }
 
 impl<M: ManagedTypeApi> FunctionSelector<M> {
@@ -20,22 +37,51 @@ impl<M: ManagedTypeApi> FunctionSelector<M> {
 }
 
 impl<M: ManagedTypeApi> CurveFunction<M> for FunctionSelector<M> {
+    fn area_until(
+        &self,
+        supply: &BigUint<M>,
+        arguments: &CurveArguments<M>,
+    ) -> SCResult<BigUint<M>> {
+        match &self {
+            FunctionSelector::Linear(function) => function.area_until(supply, arguments),
+            FunctionSelector::CustomExample(_) => {
+                Err("Custom example curve only provides prices".into())
+            },
+            FunctionSelector::Exponential(function) => function.area_until(supply, arguments),
+            FunctionSelector::Power(function) => function.area_until(supply, arguments),
+            FunctionSelector::Logarithmic(function) => function.area_until(supply, arguments),
+            FunctionSelector::PiecewiseLinear(function) => function.area_until(supply, arguments),
+            FunctionSelector::None => Err("Bonding Curve function is not assiged".into()),
+        }
+    }
     fn calculate_price(
         &self,
         token_start: &BigUint<M>,
         amount: &BigUint<M>,
         arguments: &CurveArguments<M>,
+        rounding: Rounding,
     ) -> SCResult<BigUint<M>> {
         match &self {
-            FunctionSelector::Linear(linear_function) => {
-                CurveFunction::calculate_price(linear_function, token_start, amount, arguments)
+            FunctionSelector::Linear(function) => {
+                function.calculate_price(token_start, amount, arguments, rounding)
             },
-
             FunctionSelector::CustomExample(initial_cost) => {
                 let sum = token_start + amount;
                 let price = &(&sum * &sum * sum / 3u32) + &arguments.balance + initial_cost.clone();
                 Ok(price)
             },
+            FunctionSelector::Exponential(function) => {
+                function.calculate_price(token_start, amount, arguments, rounding)
+            },
+            FunctionSelector::Power(function) => {
+                function.calculate_price(token_start, amount, arguments, rounding)
+            },
+            FunctionSelector::Logarithmic(function) => {
+                function.calculate_price(token_start, amount, arguments, rounding)
+            },
+            FunctionSelector::PiecewiseLinear(function) => {
+                function.calculate_price(token_start, amount, arguments, rounding)
+            },
             FunctionSelector::None => Err("Bonding Curve function is not assiged".into()),
         }
     }
@@ -4,12 +4,13 @@ dharitri_wasm::derive_imports!();
 pub mod curves;
 pub mod function_selector;
 pub mod utils;
-use utils::{events, owner_endpoints, storage, user_endpoints};
+use utils::{curve_operations, events, owner_endpoints, storage, user_endpoints};
 
 #[dharitri_wasm::module]
 pub trait BondingCurveModule:
     storage::StorageModule
     + events::EventsModule
+    + curve_operations::CurveOperationsModule
     + user_endpoints::UserEndpointsModule
     + owner_endpoints::OwnerEndpointsModule
 {
@@ -0,0 +1,390 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+use crate::bonding_curve::{
+    curves::curve_function::{CurveFunction, Rounding},
+    utils::{
+        events, storage,
+        structs::{BondingCurve, CurveArguments, TokenOwnershipData},
+    },
+};
+
+/// The logic behind the bonding curve endpoints, generic over the curve type.
+///
+/// The endpoint modules call it with `FunctionSelector`.
+/// Contracts using their own curves declare their own endpoints, calling these methods with their curve type.
+#[dharitri_wasm::module]
+pub trait CurveOperationsModule: storage::StorageModule + events::EventsModule {
+    fn set_curve<T>(
+        &self,
+        identifier: TokenIdentifier,
+        function: T,
+        sell_availability: bool,
+    ) -> SCResult<()>
+    where
+        T: CurveFunction<Self::Api>,
+    {
+        require!(
+            !self.token_details(&identifier).is_empty(),
+            "Token is not issued yet!"
+        );
+
+        let caller = self.blockchain().get_caller();
+
+        let details = self.token_details(&identifier).get();
+        require!(
+            details.owner == caller,
+            "The price function can only be set by the seller."
+        );
+        self.bonding_curve::<T>(&identifier)
+            .update(|bonding_curve| {
+                bonding_curve.curve = function;
+                bonding_curve.sell_availability = sell_availability
+            });
+        Ok(())
+    }
+
+    /// The curve is left unset, i.e. `T::default()`, until the seller sets it.
+    fn deposit_tokens<T>(
+        &self,
+        amount: BigUint,
+        identifier: TokenIdentifier,
+        nonce: u64,
+        payment_token: OptionalArg<TokenIdentifier>,
+    ) -> SCResult<()>
+    where
+        T: CurveFunction<Self::Api> + Default,
+    {
+        let caller = self.blockchain().get_caller();
+        let mut set_payment = TokenIdentifier::moax();
+        if self.bonding_curve::<T>(&identifier).is_empty() {
+            set_payment = payment_token
+                .into_option()
+                .ok_or("Expected provided accepted_payment for the token")?;
+        }
+        if self.token_details(&identifier).is_empty() {
+            self.token_details(&identifier).set(&TokenOwnershipData {
+                token_nonces: [nonce].to_vec(),
+                owner: caller.clone(),
+            });
+        } else {
+            let mut details = self.token_details(&identifier).get();
+            require!(
+                details.owner == caller,
+                "The token was already deposited by another address"
+            );
+            if !details.token_nonces.contains(&nonce) {
+                details.token_nonces.push(nonce);
+                self.token_details(&identifier).set(&details);
+            }
+        }
+
+        self.set_curve_storage::<T>(&identifier, amount.clone(), set_payment);
+        self.owned_tokens(&caller).insert(identifier.clone());
+        self.nonce_amount(&identifier, nonce)
+            .update(|current_amount| *current_amount += amount);
+        Ok(())
+    }
+
+    fn claim_tokens<T>(&self) -> SCResult<()>
+    where
+        T: CurveFunction<Self::Api>,
+    {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.owned_tokens(&caller).is_empty(),
+            "You have nothing to claim"
+        );
+        for token in self.owned_tokens(&caller).iter() {
+            let nonces = self.token_details(&token).get().token_nonces;
+            for nonce in nonces {
+                self.send().direct(
+                    &caller,
+                    &token,
+                    nonce,
+                    &self.nonce_amount(&token, nonce).get(),
+                    b"claim",
+                );
+                self.nonce_amount(&token, nonce).clear();
+            }
+            self.token_details(&token).clear();
+            self.bonding_curve::<T>(&token).clear();
+        }
+        self.owned_tokens(&caller).clear();
+
+        Ok(())
+    }
+
+    fn set_curve_storage<T>(
+        &self,
+        identifier: &TokenIdentifier,
+        amount: BigUint,
+        payment: TokenIdentifier,
+    ) where
+        T: CurveFunction<Self::Api> + Default,
+    {
+        let mut curve = T::default();
+        let mut arguments;
+        let payment_token;
+        let payment_amount: BigUint;
+        let sell_availability: bool;
+
+        if self.bonding_curve::<T>(identifier).is_empty() {
+            arguments = CurveArguments {
+                available_supply: amount.clone(),
+                balance: amount,
+            };
+            payment_token = payment;
+            payment_amount = BigUint::zero();
+            sell_availability = false;
+        } else {
+            let bonding_curve = self.bonding_curve::<T>(identifier).get();
+            payment_token = bonding_curve.payment_token;
+            payment_amount = bonding_curve.payment_amount;
+            curve = bonding_curve.curve;
+            arguments = bonding_curve.arguments;
+            arguments.balance += &amount;
+            arguments.available_supply += amount;
+            sell_availability = bonding_curve.sell_availability;
+        }
+        self.bonding_curve::<T>(identifier).set(&BondingCurve {
+            curve,
+            arguments,
+            sell_availability,
+            payment_token,
+            payment_amount,
+        });
+    }
+
+    fn sell<T>(
+        &self,
+        sell_amount: BigUint,
+        nonce: u64,
+        offered_token: TokenIdentifier,
+    ) -> SCResult<()>
+    where
+        T: CurveFunction<Self::Api>,
+    {
+        let _ = self.check_owned_return_payment_token::<T>(&offered_token, &sell_amount)?;
+
+        let calculated_price = self
+            .bonding_curve::<T>(&offered_token)
+            .update(|bonding_curve| {
+                require!(
+                    bonding_curve.sell_availability,
+                    "Selling is not available on this token"
+                );
+                let price = self.compute_sell_price(
+                    &bonding_curve.curve,
+                    sell_amount.clone(),
+                    bonding_curve.arguments.clone(),
+                );
+                bonding_curve.payment_amount -= price.clone()?;
+                bonding_curve.arguments.balance += sell_amount.clone();
+                price
+            })?;
+
+        let caller = self.blockchain().get_caller();
+
+        self.nonce_amount(&offered_token, nonce)
+            .update(|val| *val += sell_amount);
+
+        self.send().direct(
+            &caller,
+            &self.bonding_curve::<T>(&offered_token).get().payment_token,
+            0u64,
+            &calculated_price,
+            b"selling",
+        );
+        self.token_details(&offered_token)
+            .update(|details| details.add_nonce(nonce));
+
+        self.sell_token_event(&caller, &calculated_price);
+
+        Ok(())
+    }
+
+    fn buy<T>(
+        &self,
+        payment: BigUint,
+        offered_token: TokenIdentifier,
+        requested_amount: BigUint,
+        requested_token: TokenIdentifier,
+        requested_nonce: OptionalArg<u64>,
+    ) -> SCResult<()>
+    where
+        T: CurveFunction<Self::Api>,
+    {
+        let payment_token =
+            self.check_owned_return_payment_token::<T>(&requested_token, &requested_amount)?;
+        self.check_given_token(&payment_token, &offered_token);
+
+        let calculated_price =
+            self.bonding_curve::<T>(&requested_token)
+                .update(|bonding_curve| {
+                    let price = self.compute_buy_price(
+                        &bonding_curve.curve,
+                        requested_amount.clone(),
+                        bonding_curve.arguments.clone(),
+                    );
+                    let price_clone = price.clone()?;
+                    require!(
+                        price_clone <= payment,
+                        "The payment provided is not enough for the transaction"
+                    );
+                    bonding_curve.payment_amount += price_clone;
+                    bonding_curve.arguments.balance -= &requested_amount;
+
+                    price
+                })?;
+
+        let caller = self.blockchain().get_caller();
+
+        match requested_nonce {
+            OptionalArg::Some(nonce) => {
+                self.send().direct(
+                    &caller,
+                    &requested_token,
+                    nonce,
+                    &requested_amount,
+                    b"buying",
+                );
+                if self.nonce_amount(&requested_token, nonce).get() - requested_amount.clone() > 0 {
+                    self.nonce_amount(&requested_token, nonce)
+                        .update(|val| *val -= requested_amount.clone());
+                } else {
+                    self.nonce_amount(&requested_token, nonce).clear();
+                    self.token_details(&requested_token)
+                        .update(|details| details.remove_nonce(nonce))?;
+                }
+            },
+            OptionalArg::None => {
+                self.send_bought_tokens(&caller, requested_token, requested_amount)?;
+            },
+        };
+
+        self.send().direct(
+            &caller,
+            &offered_token,
+            0u64,
+            &(&payment - &calculated_price),
+            b"rest",
+        );
+
+        self.buy_token_event(&caller, &calculated_price);
+        Ok(())
+    }
+
+    fn send_bought_tokens(
+        &self,
+        caller: &ManagedAddress,
+        token: TokenIdentifier,
+        amount: BigUint,
+    ) -> SCResult<()> {
+        let mut nonces = self.token_details(&token).get().token_nonces;
+        let mut total_amount = amount;
+        loop {
+            let nonce = *nonces.first().ok_or("Requested nonce does not exist")?;
+            let available_amount = self.nonce_amount(&token, nonce).get();
+
+            let amount_to_send: BigUint;
+            if available_amount <= total_amount {
+                amount_to_send = available_amount.clone();
+                total_amount -= amount_to_send.clone();
+                self.nonce_amount(&token, nonce).clear();
+                nonces.remove(0);
+            } else {
+                self.nonce_amount(&token, nonce)
+                    .update(|val| *val -= total_amount.clone());
+                amount_to_send = total_amount.clone();
+                total_amount = BigUint::zero();
+            }
+            self.send()
+                .direct(caller, &token, nonce, &amount_to_send, b"buying");
+            if total_amount == 0 {
+                break;
+            }
+        }
+        self.token_details(&token)
+            .update(|token_ownership| token_ownership.token_nonces = nonces);
+        Ok(())
+    }
+
+    fn buy_price<T>(&self, amount: BigUint, identifier: TokenIdentifier) -> SCResult<BigUint>
+    where
+        T: CurveFunction<Self::Api>,
+    {
+        self.check_token_exists::<T>(&identifier)?;
+
+        let bonding_curve = self.bonding_curve::<T>(&identifier).get();
+        self.compute_buy_price(&bonding_curve.curve, amount, bonding_curve.arguments)
+    }
+
+    fn sell_price<T>(&self, amount: BigUint, identifier: TokenIdentifier) -> SCResult<BigUint>
+    where
+        T: CurveFunction<Self::Api>,
+    {
+        self.check_token_exists::<T>(&identifier)?;
+
+        let bonding_curve = self.bonding_curve::<T>(&identifier).get();
+        self.compute_sell_price(&bonding_curve.curve, amount, bonding_curve.arguments)
+    }
+
+    fn check_token_exists<T>(&self, issued_token: &TokenIdentifier) -> SCResult<()>
+    where
+        T: CurveFunction<Self::Api>,
+    {
+        require!(
+            !self.bonding_curve::<T>(issued_token).is_empty(),
+            "Token is not issued yet!"
+        );
+
+        Ok(())
+    }
+
+    fn check_owned_return_payment_token<T>(
+        &self,
+        issued_token: &TokenIdentifier,
+        amount: &BigUint,
+    ) -> SCResult<TokenIdentifier>
+    where
+        T: CurveFunction<Self::Api>,
+    {
+        self.check_token_exists::<T>(issued_token)?;
+
+        let bonding_curve = self.bonding_curve::<T>(issued_token).get();
+
+        require!(amount > &0, "Must pay more than 0 tokens!");
+        Ok(bonding_curve.payment_token)
+    }
+
+    fn check_given_token(&self, accepted_token: &TokenIdentifier, given_token: &TokenIdentifier) {
+        if given_token != accepted_token {
+            let mut err = self.error().new_error();
+            err.append_bytes(&b"Only"[..]);
+            err.append_bytes(accepted_token.to_dct_identifier().as_slice());
+            err.append_bytes(&b" tokens accepted"[..]);
+            err.exit_now()
+        }
+    }
+
+    fn compute_buy_price<T: CurveFunction<Self::Api>>(
+        &self,
+        curve: &T,
+        amount: BigUint,
+        arguments: CurveArguments<Self::Api>,
+    ) -> SCResult<BigUint> {
+        let token_start = arguments.first_token_available();
+        curve.calculate_price(&token_start, &amount, &arguments, Rounding::Up)
+    }
+
+    fn compute_sell_price<T: CurveFunction<Self::Api>>(
+        &self,
+        curve: &T,
+        amount: BigUint,
+        arguments: CurveArguments<Self::Api>,
+    ) -> SCResult<BigUint> {
+        let token_start = &arguments.first_token_available() - &amount;
+        curve.calculate_price(&token_start, &amount, &arguments, Rounding::Down)
+    }
+}
@@ -1,3 +1,4 @@
+pub mod curve_operations;
 pub mod events;
 pub mod owner_endpoints;
 pub mod storage;
@@ -1,6 +1,8 @@
 dharitri_wasm::imports!();
 dharitri_wasm::derive_imports!();
 
+use crate::bonding_curve::curves::curve_function::CurveFunction;
+
 use super::structs::{BondingCurve, TokenOwnershipData};
 
 #[dharitri_wasm::module]
@@ -12,7 +14,12 @@ pub trait StorageModule {
     ) -> SingleValueMapper<TokenOwnershipData<Self::Api>>;
 
     #[storage_mapper("bonding_curve")]
-    fn bonding_curve(&self, token: &TokenIdentifier) -> SingleValueMapper<BondingCurve<Self::Api>>;
+    fn bonding_curve<T>(
+        &self,
+        token: &TokenIdentifier,
+    ) -> SingleValueMapper<BondingCurve<Self::Api, T>>
+    where
+        T: CurveFunction<Self::Api>;
 
     #[storage_mapper("owned_tokens")]
     fn owned_tokens(&self, owner: &ManagedAddress) -> SetMapper<TokenIdentifier>;
@@ -1,4 +1,4 @@
-use crate::bonding_curve::function_selector::FunctionSelector;
+use crate::bonding_curve::curves::curve_function::CurveFunction;
 
 dharitri_wasm::imports!();
 dharitri_wasm::derive_imports!();
@@ -15,9 +15,14 @@ impl<M: ManagedTypeApi> CurveArguments<M> {
     }
 }
 
+/// `T` is the curve type, usually `FunctionSelector`.
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
-pub struct BondingCurve<M: ManagedTypeApi> {
-    pub curve: FunctionSelector<M>,
+pub struct BondingCurve<M, T>
+where
+    M: ManagedTypeApi,
+    T: CurveFunction<M>,
+{
+    pub curve: T,
     pub arguments: CurveArguments<M>,
     pub sell_availability: bool,
     pub payment_token: TokenIdentifier<M>,
@@ -1,26 +1,34 @@
 dharitri_wasm::imports!();
 
+use super::governance_proposal::GovernanceVoteWeighting;
+
 /// # Dharitri smart contract module - Governance
 ///
 /// This is a standard smart contract module, that when added to a smart contract offers governance features:
 /// - proposing actions
-/// - voting/downvoting a certain proposal
+/// - voting/downvoting/abstaining on a certain proposal, directly or through a delegate
 /// - after a voting period, either putting the action in a queue (if it reached quorum), or canceling
 ///
-/// Voting can only be done by depositing a certain token, decided upon first time setup.  
+/// Voting power is obtained by depositing a certain token, decided upon first time setup.  
+/// Votes on a proposal are counted using the voting power at the time the proposal was created.  
 ///
 /// The module provides the following configurable parameters:  
-/// - `quorum` - the minimum number of (`votes` minus `downvotes`) at the end of voting period  
-/// - `minTokenBalanceForProposing` - Minimum numbers of tokens the proposer has to deposit. These automatically count as `votes` as well  
+/// - `quorum` - the minimum number of (`votes` minus `downvotes`) plus `abstain` votes at the end of voting period. Given in tokens, it gets the same weighting as the votes  
+/// - `minTokenBalanceForProposing` - Minimum voting power of the proposer. It automatically counts as `votes` as well  
 /// - `maxActionsPerProposal` - Maximum number of actions (transfers and/or smart contract calls) that a proposal may have  
 /// - `votingDelayInBlocks` - Number of blocks to wait after a block is proposed before being able to vote/downvote that proposal
 /// - `votingPeriodInBlocks` - Number of blocks the voting period lasts (voting delay does not count towards this)  
 /// - `lockTimeAfterVotingEndsInBlocks` - Number of blocks to wait before a successful proposal can be executed  
+/// - `voteWeighting` - Either `Linear` (one vote per token, the default) or `Quadratic` (square root of the tokens)  
+///
+/// A proposal succeeds before the voting period ends, if the remaining voting power can no longer change the outcome.  
 ///
 /// The module also provides events for most actions that happen:
 /// - `proposalCreated` - triggers when a proposal is created. Also provoides all the relevant information, like proposer, actions etc.  
 /// - `voteCast` - user voted on a proposal  
 /// - `downvoteCast` - user downvoted a proposal  
+/// - `abstainCast` - user abstained on a proposal  
+/// - `votingPowerDeposit`, `votingPowerWithdraw` and `delegateChanged` - changes in voting power  
 /// - `proposalCanceled`, `proposalQueued` and `proposalExecuted` - provides the ID of the specific proposal  
 /// - `userDeposit` - a user deposited some tokens needed for a future payable action  
 ///
@@ -44,6 +52,7 @@ pub trait GovernanceConfigurablePropertiesModule {
         voting_delay_in_blocks: u64,
         voting_period_in_blocks: u64,
         lock_time_after_voting_ends_in_blocks: u64,
+        #[var_args] opt_vote_weighting: OptionalArg<GovernanceVoteWeighting>,
     ) -> SCResult<()> {
         require!(
             governance_token_id.is_valid_dct_identifier(),
@@ -61,6 +70,9 @@ pub trait GovernanceConfigurablePropertiesModule {
         self.try_change_lock_time_after_voting_ends_in_blocks(
             lock_time_after_voting_ends_in_blocks,
         )?;
+        if let OptionalArg::Some(vote_weighting) = opt_vote_weighting {
+            self.vote_weighting().set(vote_weighting);
+        }
 
         Ok(())
     }
@@ -122,6 +134,15 @@ pub trait GovernanceConfigurablePropertiesModule {
         Ok(())
     }
 
+    #[endpoint(changeVoteWeighting)]
+    fn change_vote_weighting(&self, new_value: GovernanceVoteWeighting) -> SCResult<()> {
+        self.require_caller_self()?;
+
+        self.vote_weighting().set(new_value);
+
+        Ok(())
+    }
+
     // private
 
     fn require_caller_self(&self) -> SCResult<()> {
@@ -224,4 +245,9 @@ pub trait GovernanceConfigurablePropertiesModule {
     #[view(getLockTimeAfterVotingEndsInBlocks)]
     #[storage_mapper("governance:lockTimeAfterVotingEndsInBlocks")]
     fn lock_time_after_voting_ends_in_blocks(&self) -> SingleValueMapper<u64>;
+
+    /// Only applies to proposals created after it is changed.
+    #[view(getVoteWeighting)]
+    #[storage_mapper("governance:voteWeighting")]
+    fn vote_weighting(&self) -> SingleValueMapper<GovernanceVoteWeighting>;
 }
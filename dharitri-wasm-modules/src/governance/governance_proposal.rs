@@ -26,6 +26,34 @@ pub enum GovernanceProposalStatus {
     Queued,
 }
 
+/// How the voting power of an address translates into votes.
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Default,
+)]
+pub enum GovernanceVoteWeighting {
+    /// One vote per token.
+    #[default]
+    Linear,
+    /// Votes are the square root of the number of tokens.
+    Quadratic,
+}
+
+impl GovernanceVoteWeighting {
+    pub fn apply<M: ManagedTypeApi>(self, voting_power: &BigUint<M>) -> BigUint<M> {
+        match self {
+            GovernanceVoteWeighting::Linear => voting_power.clone(),
+            GovernanceVoteWeighting::Quadratic => voting_power.sqrt(),
+        }
+    }
+}
+
+/// The voting power of an address starting with a certain block.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct VotingPowerCheckpoint<M: ManagedTypeApi> {
+    pub block: u64,
+    pub voting_power: BigUint<M>,
+}
+
 #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
 pub struct GovernanceAction<M: ManagedTypeApi> {
     pub gas_limit: u64,
@@ -57,4 +85,12 @@ pub struct GovernanceProposal<M: ManagedTypeApi> {
     pub proposer: ManagedAddress<M>,
     pub actions: Vec<GovernanceAction<M>>,
     pub description: BoxedBytes,
+    /// Votes are counted using the voting power at the end of this block.
+    /// Proposals stored before snapshots were introduced decode with block 0.
+    #[codec(default)]
+    pub snapshot_block: u64,
+    /// The vote weighting in place when the proposal was created.
+    /// Proposals stored before vote weighting was introduced decode as `Linear`.
+    #[codec(default)]
+    pub vote_weighting: GovernanceVoteWeighting,
 }
@@ -14,7 +14,6 @@ pub trait GovernanceModule:
     // endpoints
 
     // Used to deposit tokens for "payable" actions
-    // There is no "withdraw" functionality
     // Funds can only be retrived through an action
     #[payable("*")]
     #[endpoint(depositTokensForAction)]
@@ -29,10 +28,60 @@ pub trait GovernanceModule:
         self.user_deposit_event(&caller, &payment_token, payment_nonce, &payment_amount);
     }
 
-    // Used to withdraw the tokens after the action was executed or cancelled
+    /// Deposits governance tokens, which give voting power to the caller,
+    /// or to their delegate, if they have one.
+    /// Counts towards proposals created in the current block or later.
+    #[payable("*")]
+    #[endpoint(depositVotingPower)]
+    fn deposit_voting_power(&self, #[payment_amount] payment_amount: BigUint) -> SCResult<()> {
+        self.require_payment_token_governance_token()?;
+        require!(payment_amount > 0, "Must deposit a non-zero amount");
+
+        let caller = self.blockchain().get_caller();
+        self.voting_power_deposit(&caller)
+            .update(|deposit| *deposit += &payment_amount);
+        self.increase_voting_power(&self.current_delegate(&caller), &payment_amount);
+        self.increase_total_voting_power(&payment_amount);
+
+        self.voting_power_deposit_event(&caller, &payment_amount);
+
+        Ok(())
+    }
+
+    /// Tokens can be withdrawn at any time,
+    /// the votes they were used for on ongoing proposals remain valid.
+    #[endpoint(withdrawVotingPower)]
+    fn withdraw_voting_power(&self, amount: BigUint) -> SCResult<()> {
+        let caller = self.blockchain().get_caller();
+        let deposit = self.voting_power_deposit(&caller).get();
+        require!(
+            amount > 0 && amount <= deposit,
+            "Cannot withdraw more than the deposited amount"
+        );
+
+        self.voting_power_deposit(&caller).set(&(deposit - &amount));
+        self.decrease_voting_power(&self.current_delegate(&caller), &amount);
+        self.decrease_total_voting_power(&amount);
+
+        let governance_token_id = self.governance_token_id().get();
+        self.send()
+            .direct(&caller, &governance_token_id, 0, &amount, &[]);
+
+        self.voting_power_withdraw_event(&caller, &amount);
+
+        Ok(())
+    }
+
+    /// Pays out the tokens locked by `propose`, `vote` and `downvote` in the previous version,
+    /// once their proposal was executed or canceled.
+    /// Proposals created with voting power lock no tokens, so there is nothing to withdraw from them.
     #[endpoint(withdrawGovernanceTokens)]
     fn withdraw_governance_tokens(&self, proposal_id: usize) -> SCResult<()> {
         self.require_valid_proposal_id(proposal_id)?;
+        require!(
+            !self.is_voting_power_proposal(proposal_id).get(),
+            "Proposal did not lock any tokens"
+        );
         require!(
             self.get_proposal_status(proposal_id) == GovernanceProposalStatus::None,
             "Proposal has to be executed or canceled first"
@@ -55,17 +104,43 @@ pub trait GovernanceModule:
         Ok(())
     }
 
-    #[payable("*")]
+    /// Hands over the voting power of the deposited tokens to another address.
+    /// Delegating to oneself cancels the delegation.
+    #[endpoint(delegateVotingPower)]
+    fn delegate_voting_power(&self, delegate: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        let old_delegate = self.current_delegate(&caller);
+        if old_delegate == delegate {
+            return;
+        }
+
+        let deposit = self.voting_power_deposit(&caller).get();
+        if deposit > 0 {
+            self.decrease_voting_power(&old_delegate, &deposit);
+            self.increase_voting_power(&delegate, &deposit);
+        }
+
+        if delegate == caller {
+            self.delegate(&caller).clear();
+        } else {
+            self.delegate(&caller).set(&delegate);
+        }
+
+        self.delegate_changed_event(&caller, &old_delegate, &delegate);
+    }
+
+    /// The voting power of the proposer automatically counts as `votes`.
     #[endpoint]
     fn propose(
         &self,
-        #[payment_amount] payment_amount: BigUint,
         description: BoxedBytes,
         #[var_args] actions: VarArgs<GovernanceActionAsMultiArg<Self::Api>>,
     ) -> SCResult<usize> {
-        self.require_payment_token_governance_token()?;
+        let proposer = self.blockchain().get_caller();
+        let current_block = self.blockchain().get_block_nonce();
+        let proposer_voting_power = self.get_voting_power_at(proposer.clone(), current_block);
         require!(
-            payment_amount >= self.min_token_balance_for_proposing().get(),
+            proposer_voting_power >= self.min_token_balance_for_proposing().get(),
             "Not enough tokens for proposing action"
         );
         require!(!actions.is_empty(), "Proposal has no actions");
@@ -101,8 +176,6 @@ pub trait GovernanceModule:
             "Actions require too much gas to be executed"
         );
 
-        let proposer = self.blockchain().get_caller();
-        let current_block = self.blockchain().get_block_nonce();
         let proposal_id = self.proposals().len() + 1;
 
         self.proposal_created_event(
@@ -113,69 +186,68 @@ pub trait GovernanceModule:
             &gov_actions,
         );
 
+        let vote_weighting = self.vote_weighting().get();
         let proposal = GovernanceProposal {
             proposer: proposer.clone(),
             description,
             actions: gov_actions,
+            snapshot_block: current_block,
+            vote_weighting,
         };
         let _ = self.proposals().push(&proposal);
 
         self.proposal_start_block(proposal_id).set(&current_block);
+        self.is_voting_power_proposal(proposal_id).set(&true);
 
-        self.total_votes(proposal_id).set(&payment_amount);
-        self.votes(proposal_id).insert(proposer, payment_amount);
+        let nr_votes = vote_weighting.apply(&proposer_voting_power);
+        self.cast_voting_power(proposal_id)
+            .set(&proposer_voting_power);
+        self.total_votes(proposal_id).set(&nr_votes);
+        self.votes(proposal_id).insert(proposer, nr_votes);
 
         Ok(proposal_id)
     }
 
-    #[payable("*")]
     #[endpoint]
-    fn vote(&self, #[payment_amount] payment_amount: BigUint, proposal_id: usize) -> SCResult<()> {
-        self.require_payment_token_governance_token()?;
-        self.require_valid_proposal_id(proposal_id)?;
-        require!(
-            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
-            "Proposal is not active"
-        );
-
+    fn vote(&self, proposal_id: usize) -> SCResult<()> {
         let voter = self.blockchain().get_caller();
+        let nr_votes = self.cast_vote(&voter, proposal_id)?;
 
-        self.vote_cast_event(&voter, proposal_id, &payment_amount);
+        self.vote_cast_event(&voter, proposal_id, &nr_votes);
 
         self.total_votes(proposal_id)
-            .update(|total_votes| *total_votes += &payment_amount);
-        self.votes(proposal_id)
-            .entry(voter)
-            .and_modify(|nr_votes| *nr_votes += &payment_amount)
-            .or_insert(payment_amount);
+            .update(|total_votes| *total_votes += &nr_votes);
+        self.votes(proposal_id).insert(voter, nr_votes);
 
         Ok(())
     }
 
-    #[payable("*")]
     #[endpoint]
-    fn downvote(
-        &self,
-        #[payment_amount] payment_amount: BigUint,
-        proposal_id: usize,
-    ) -> SCResult<()> {
-        self.require_payment_token_governance_token()?;
-        self.require_valid_proposal_id(proposal_id)?;
-        require!(
-            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
-            "Proposal is not active"
-        );
-
+    fn downvote(&self, proposal_id: usize) -> SCResult<()> {
         let downvoter = self.blockchain().get_caller();
+        let nr_downvotes = self.cast_vote(&downvoter, proposal_id)?;
 
-        self.downvote_cast_event(&downvoter, proposal_id, &payment_amount);
+        self.downvote_cast_event(&downvoter, proposal_id, &nr_downvotes);
 
         self.total_downvotes(proposal_id)
-            .update(|total_downvotes| *total_downvotes += &payment_amount);
-        self.downvotes(proposal_id)
-            .entry(downvoter)
-            .and_modify(|nr_downvotes| *nr_downvotes += &payment_amount)
-            .or_insert(payment_amount);
+            .update(|total_downvotes| *total_downvotes += &nr_downvotes);
+        self.downvotes(proposal_id).insert(downvoter, nr_downvotes);
+
+        Ok(())
+    }
+
+    /// Abstain votes count towards the quorum, but not towards the outcome.
+    #[endpoint]
+    fn abstain(&self, proposal_id: usize) -> SCResult<()> {
+        let voter = self.blockchain().get_caller();
+        let nr_abstain_votes = self.cast_vote(&voter, proposal_id)?;
+
+        self.abstain_cast_event(&voter, proposal_id, &nr_abstain_votes);
+
+        self.total_abstain_votes(proposal_id)
+            .update(|total_abstain_votes| *total_abstain_votes += &nr_abstain_votes);
+        self.abstain_votes(proposal_id)
+            .insert(voter, nr_abstain_votes);
 
         Ok(())
     }
@@ -299,21 +371,73 @@ pub trait GovernanceModule:
         if current_block < voting_start {
             return GovernanceProposalStatus::Pending;
         }
-        if current_block >= voting_start && current_block < voting_end {
-            return GovernanceProposalStatus::Active;
-        }
 
+        let proposal = self.proposals().get(proposal_id);
         let total_votes = self.total_votes(proposal_id).get();
         let total_downvotes = self.total_downvotes(proposal_id).get();
-        let quorum = self.quorum().get();
+        let total_abstain_votes = self.total_abstain_votes(proposal_id).get();
+
+        if current_block < voting_end {
+            // the vote weighting never yields more votes than the voting power,
+            // so the remaining voting power bounds the possible downvotes
+            let total_voting_power = self.get_total_voting_power_at(proposal.snapshot_block);
+            let cast_voting_power = self.cast_voting_power(proposal_id).get();
+            let remaining_voting_power = if total_voting_power > cast_voting_power {
+                total_voting_power - cast_voting_power
+            } else {
+                BigUint::zero()
+            };
+            let worst_case_downvotes = total_downvotes + remaining_voting_power;
+            return if self.is_quorum_reached(
+                &proposal,
+                &total_votes,
+                &worst_case_downvotes,
+                &total_abstain_votes,
+            ) {
+                GovernanceProposalStatus::Succeeded
+            } else {
+                GovernanceProposalStatus::Active
+            };
+        }
 
-        if total_votes > total_downvotes && total_votes - total_downvotes >= quorum {
+        if self.is_quorum_reached(
+            &proposal,
+            &total_votes,
+            &total_downvotes,
+            &total_abstain_votes,
+        ) {
             GovernanceProposalStatus::Succeeded
         } else {
             GovernanceProposalStatus::Defeated
         }
     }
 
+    #[view(getVotingPower)]
+    fn get_voting_power(&self, address: ManagedAddress) -> BigUint {
+        self.get_voting_power_at(address, self.blockchain().get_block_nonce())
+    }
+
+    /// The voting power at the end of the given block.
+    #[view(getVotingPowerAt)]
+    fn get_voting_power_at(&self, address: ManagedAddress, block: u64) -> BigUint {
+        self.checkpoint_value_at(&self.voting_power_checkpoints(&address), block)
+    }
+
+    #[view(getTotalVotingPowerAt)]
+    fn get_total_voting_power_at(&self, block: u64) -> BigUint {
+        self.checkpoint_value_at(&self.total_voting_power_checkpoints(), block)
+    }
+
+    #[view(getDelegate)]
+    fn current_delegate(&self, address: &ManagedAddress) -> ManagedAddress {
+        let delegate_mapper = self.delegate(address);
+        if delegate_mapper.is_empty() {
+            address.clone()
+        } else {
+            delegate_mapper.get()
+        }
+    }
+
     #[view(getProposer)]
     fn get_proposer(&self, proposal_id: usize) -> OptionalArg<ManagedAddress> {
         if !self.proposal_exists(proposal_id) {
@@ -369,6 +493,111 @@ pub trait GovernanceModule:
         Ok(())
     }
 
+    /// Checks that the proposal is active and the voter did not vote already,
+    /// then records the voting power used. Returns the number of votes it is worth.
+    fn cast_vote(&self, voter: &ManagedAddress, proposal_id: usize) -> SCResult<BigUint> {
+        self.require_valid_proposal_id(proposal_id)?;
+        require!(
+            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
+            "Proposal is not active"
+        );
+        require!(
+            self.is_voting_power_proposal(proposal_id).get(),
+            "Votes on proposals from the previous version are closed"
+        );
+        require!(
+            !self.votes(proposal_id).contains_key(voter)
+                && !self.downvotes(proposal_id).contains_key(voter)
+                && !self.abstain_votes(proposal_id).contains_key(voter),
+            "Already voted for this proposal"
+        );
+
+        let proposal = self.proposals().get(proposal_id);
+        let voting_power = self.get_voting_power_at(voter.clone(), proposal.snapshot_block);
+        require!(voting_power > 0, "No voting power for this proposal");
+
+        self.cast_voting_power(proposal_id)
+            .update(|cast_voting_power| *cast_voting_power += &voting_power);
+
+        Ok(proposal.vote_weighting.apply(&voting_power))
+    }
+
+    /// The vote totals are weighted, so the quorum, given in tokens, gets the same weighting.
+    fn is_quorum_reached(
+        &self,
+        proposal: &GovernanceProposal<Self::Api>,
+        total_votes: &BigUint,
+        total_downvotes: &BigUint,
+        total_abstain_votes: &BigUint,
+    ) -> bool {
+        let quorum = proposal.vote_weighting.apply(&self.quorum().get());
+        total_votes > total_downvotes
+            && total_votes - total_downvotes + total_abstain_votes >= quorum
+    }
+
+    fn increase_voting_power(&self, address: &ManagedAddress, amount: &BigUint) {
+        let voting_power = self.get_voting_power(address.clone()) + amount;
+        self.push_checkpoint(self.voting_power_checkpoints(address), voting_power);
+    }
+
+    fn decrease_voting_power(&self, address: &ManagedAddress, amount: &BigUint) {
+        let voting_power = self.get_voting_power(address.clone()) - amount;
+        self.push_checkpoint(self.voting_power_checkpoints(address), voting_power);
+    }
+
+    fn increase_total_voting_power(&self, amount: &BigUint) {
+        let current_block = self.blockchain().get_block_nonce();
+        let total_voting_power = self.get_total_voting_power_at(current_block) + amount;
+        self.push_checkpoint(self.total_voting_power_checkpoints(), total_voting_power);
+    }
+
+    fn decrease_total_voting_power(&self, amount: &BigUint) {
+        let current_block = self.blockchain().get_block_nonce();
+        let total_voting_power = self.get_total_voting_power_at(current_block) - amount;
+        self.push_checkpoint(self.total_voting_power_checkpoints(), total_voting_power);
+    }
+
+    /// Only the last value in a block is kept.
+    fn push_checkpoint(
+        &self,
+        mut checkpoints: VecMapper<VotingPowerCheckpoint<Self::Api>>,
+        voting_power: BigUint,
+    ) {
+        let checkpoint = VotingPowerCheckpoint {
+            block: self.blockchain().get_block_nonce(),
+            voting_power,
+        };
+        let len = checkpoints.len();
+        if len > 0 && checkpoints.get(len).block == checkpoint.block {
+            checkpoints.set(len, &checkpoint);
+        } else {
+            let _ = checkpoints.push(&checkpoint);
+        }
+    }
+
+    /// Binary search for the last checkpoint at or before the given block.
+    fn checkpoint_value_at(
+        &self,
+        checkpoints: &VecMapper<VotingPowerCheckpoint<Self::Api>>,
+        block: u64,
+    ) -> BigUint {
+        let mut result = BigUint::zero();
+        let mut low = 1;
+        let mut high = checkpoints.len();
+        while low <= high {
+            let mid = (low + high) / 2;
+            let checkpoint = checkpoints.get(mid);
+            if checkpoint.block <= block {
+                result = checkpoint.voting_power;
+                low = mid + 1;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        result
+    }
+
     fn is_valid_proposal_id(&self, proposal_id: usize) -> bool {
         proposal_id >= 1 && proposal_id <= self.proposals().len()
     }
@@ -386,8 +615,8 @@ pub trait GovernanceModule:
         total
     }
 
-    /// specific votes/downvotes are not cleared,
-    /// as they're used for reclaim tokens logic and cleared one by one
+    /// specific votes/downvotes/abstain votes are not cleared,
+    /// as that would require iterating over all voters
     fn clear_proposal(&self, proposal_id: usize) {
         self.proposals().clear_entry(proposal_id);
         self.proposal_start_block(proposal_id).clear();
@@ -395,6 +624,8 @@ pub trait GovernanceModule:
 
         self.total_votes(proposal_id).clear();
         self.total_downvotes(proposal_id).clear();
+        self.total_abstain_votes(proposal_id).clear();
+        self.cast_voting_power(proposal_id).clear();
     }
 
     // events
@@ -425,6 +656,28 @@ pub trait GovernanceModule:
         nr_downvotes: &BigUint,
     );
 
+    #[event("abstainCast")]
+    fn abstain_cast_event(
+        &self,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] proposal_id: usize,
+        nr_abstain_votes: &BigUint,
+    );
+
+    #[event("votingPowerDeposit")]
+    fn voting_power_deposit_event(&self, #[indexed] address: &ManagedAddress, amount: &BigUint);
+
+    #[event("votingPowerWithdraw")]
+    fn voting_power_withdraw_event(&self, #[indexed] address: &ManagedAddress, amount: &BigUint);
+
+    #[event("delegateChanged")]
+    fn delegate_changed_event(
+        &self,
+        #[indexed] delegator: &ManagedAddress,
+        #[indexed] from_delegate: &ManagedAddress,
+        #[indexed] to_delegate: &ManagedAddress,
+    );
+
     #[event("proposalCanceled")]
     fn proposal_canceled_event(&self, #[indexed] proposal_id: usize);
 
@@ -461,6 +714,18 @@ pub trait GovernanceModule:
     #[storage_mapper("governance:downvotes")]
     fn downvotes(&self, proposal_id: usize) -> MapMapper<ManagedAddress, BigUint>;
 
+    #[storage_mapper("governance:abstainVotes")]
+    fn abstain_votes(&self, proposal_id: usize) -> MapMapper<ManagedAddress, BigUint>;
+
+    /// Set for proposals counted with voting power, as opposed to the tokens locked in the previous version.
+    /// Not cleared with the proposal, so that the legacy withdrawal can tell them apart.
+    #[storage_mapper("governance:votingPowerProposal")]
+    fn is_voting_power_proposal(&self, proposal_id: usize) -> SingleValueMapper<bool>;
+
+    /// Voting power used on the proposal so far, before applying the vote weighting.
+    #[storage_mapper("governance:castVotingPower")]
+    fn cast_voting_power(&self, proposal_id: usize) -> SingleValueMapper<BigUint>;
+
     /// Could be calculated by iterating over the "votes" mapper, but that costs a lot of gas
     #[view(getTotalVotes)]
     #[storage_mapper("governance:totalVotes")]
@@ -470,4 +735,28 @@ pub trait GovernanceModule:
     #[view(getTotalDownvotes)]
     #[storage_mapper("governance:totalDownvotes")]
     fn total_downvotes(&self, proposal_id: usize) -> SingleValueMapper<BigUint>;
+
+    /// Could be calculated by iterating over the "abstainVotes" mapper, but that costs a lot of gas
+    #[view(getTotalAbstainVotes)]
+    #[storage_mapper("governance:totalAbstainVotes")]
+    fn total_abstain_votes(&self, proposal_id: usize) -> SingleValueMapper<BigUint>;
+
+    // storage - voting power
+
+    #[view(getVotingPowerDeposit)]
+    #[storage_mapper("governance:votingPowerDeposit")]
+    fn voting_power_deposit(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("governance:delegate")]
+    fn delegate(&self, address: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    /// Own deposits, unless delegated, plus the deposits delegated to the address.
+    #[storage_mapper("governance:votingPowerCheckpoints")]
+    fn voting_power_checkpoints(
+        &self,
+        address: &ManagedAddress,
+    ) -> VecMapper<VotingPowerCheckpoint<Self::Api>>;
+
+    #[storage_mapper("governance:totalVotingPowerCheckpoints")]
+    fn total_voting_power_checkpoints(&self) -> VecMapper<VotingPowerCheckpoint<Self::Api>>;
 }
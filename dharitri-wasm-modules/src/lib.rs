@@ -1,4 +1,5 @@
 #![no_std]
+#![feature(generic_associated_types)]
 
 pub mod bonding_curve;
 pub mod dns;
@@ -6,4 +7,7 @@ pub mod dct;
 pub mod features;
 pub mod governance;
 pub mod pause;
+pub mod permissions;
+pub mod staking;
 pub mod users;
+pub mod vesting;
@@ -1,48 +1,297 @@
 dharitri_wasm::imports!();
 
+/// Pause timestamp meaning that the pause lasts until explicitly lifted.
+pub const NO_AUTO_UNPAUSE: u64 = 0;
+
 /// Standard smart contract module that, when added to a smart contract, offers pausability.
 ///
-/// It provides a flag that contracts can use to check if owner decided to pause the entire contract.
-/// Use the features module for more granular on/off switches.
+/// The contract can be paused as a whole, or only some of its endpoints or named features.
+/// Each pause can end automatically at a given block timestamp.
+/// Pausing something already paused never shortens the existing pause.
 ///
 /// It offers:
-/// * an endpoint where the owner can pause/unpause contract
-/// * a method to check if contract is paused or not
+/// * endpoints where the owner or a pause guardian can pause the contract, endpoints or features;
+///   only the owner can lift a pause
+/// * endpoints where the owner can add and remove pause guardians
+/// * views and methods to check if the contract, an endpoint or a feature is paused;
+///   endpoints can also use the `#[when_not_paused]` attribute, which performs the check automatically
+/// * events for each pause and unpause; pauses that end automatically do not emit an event
 ///
 #[dharitri_wasm::module]
 pub trait PauseModule {
+    // contract
+
     #[view(isPaused)]
-    #[storage_get("pause_module:paused")]
-    fn is_paused(&self) -> bool;
+    fn is_paused(&self) -> bool {
+        self.paused_flag().get() && !self.pause_expired(self.paused_until().get())
+    }
 
     fn not_paused(&self) -> bool {
         !self.is_paused()
     }
 
-    #[storage_set("pause_module:paused")]
-    fn set_paused(&self, paused: bool);
+    fn set_paused(&self, paused: bool) {
+        self.paused_flag().set(paused);
+        self.paused_until().clear();
+    }
 
+    /// Pauses the whole contract.
+    /// Optionally, the pause ends automatically once the block timestamp reaches `opt_until`.
     #[endpoint(pause)]
-    fn pause_endpoint(&self) -> SCResult<()> {
+    fn pause_endpoint(&self, #[var_args] opt_until: OptionalArg<u64>) {
+        let caller = self.require_caller_can_pause();
+        let mut until = self.pause_until_argument(opt_until);
+        if self.is_paused() {
+            until = later_pause_end(self.paused_until().get(), until);
+        }
+
+        self.paused_flag().set(true);
+        self.paused_until().set(until);
+        self.pause_event(&caller, until);
+    }
+
+    #[endpoint(unpause)]
+    fn unpause_endpoint(&self) {
+        let caller = self.require_caller_is_owner();
+
+        self.set_paused(false);
+        self.unpause_event(&caller);
+    }
+
+    /// Stops execution if the whole contract is paused.
+    fn require_not_paused(&self) {
+        require!(self.not_paused(), "contract is paused");
+    }
+
+    // endpoints
+
+    #[endpoint(pauseEndpoint)]
+    fn pause_endpoint_endpoint(
+        &self,
+        endpoint_name: ManagedBuffer,
+        #[var_args] opt_until: OptionalArg<u64>,
+    ) {
+        let caller = self.require_caller_can_pause();
+        let mut until = self.pause_until_argument(opt_until);
+        if let Some(current_until) = self.paused_endpoints().get(&endpoint_name) {
+            if !self.pause_expired(current_until) {
+                until = later_pause_end(current_until, until);
+            }
+        }
+
+        self.paused_endpoints().insert(endpoint_name.clone(), until);
+        self.pause_endpoint_event(&endpoint_name, &caller, until);
+    }
+
+    #[endpoint(unpauseEndpoint)]
+    fn unpause_endpoint_endpoint(&self, endpoint_name: ManagedBuffer) {
+        let caller = self.require_caller_is_owner();
+
+        if self.paused_endpoints().remove(&endpoint_name).is_some() {
+            self.unpause_endpoint_event(&endpoint_name, &caller);
+        }
+    }
+
+    #[view(isEndpointPaused)]
+    fn is_endpoint_paused(&self, endpoint_name: ManagedBuffer) -> bool {
+        match self.paused_endpoints().get(&endpoint_name) {
+            Some(until) => !self.pause_expired(until),
+            None => false,
+        }
+    }
+
+    /// Stops execution if the contract or the given endpoint is paused.
+    /// Endpoints annotated with `#[when_not_paused]` call this before anything else.
+    fn require_endpoint_not_paused(&self, endpoint_name: &[u8]) {
+        self.require_not_paused();
         require!(
-            self.blockchain().get_caller() == self.blockchain().get_owner_address(),
-            "only owner allowed to pause contract"
+            !self.is_endpoint_paused(ManagedBuffer::new_from_bytes(endpoint_name)),
+            "endpoint is paused"
         );
+    }
 
-        self.set_paused(true);
-        // TODO: event
-        Ok(())
+    // features
+
+    #[endpoint(pauseFeature)]
+    fn pause_feature_endpoint(
+        &self,
+        feature_name: ManagedBuffer,
+        #[var_args] opt_until: OptionalArg<u64>,
+    ) {
+        let caller = self.require_caller_can_pause();
+        let mut until = self.pause_until_argument(opt_until);
+        if let Some(current_until) = self.paused_features().get(&feature_name) {
+            if !self.pause_expired(current_until) {
+                until = later_pause_end(current_until, until);
+            }
+        }
+
+        self.paused_features().insert(feature_name.clone(), until);
+        self.pause_feature_event(&feature_name, &caller, until);
     }
 
-    #[endpoint(unpause)]
-    fn unpause_endpoint(&self) -> SCResult<()> {
+    #[endpoint(unpauseFeature)]
+    fn unpause_feature_endpoint(&self, feature_name: ManagedBuffer) {
+        let caller = self.require_caller_is_owner();
+
+        if self.paused_features().remove(&feature_name).is_some() {
+            self.unpause_feature_event(&feature_name, &caller);
+        }
+    }
+
+    #[view(isFeaturePaused)]
+    fn is_feature_paused(&self, feature_name: ManagedBuffer) -> bool {
+        match self.paused_features().get(&feature_name) {
+            Some(until) => !self.pause_expired(until),
+            None => false,
+        }
+    }
+
+    /// Stops execution if the contract or the given feature is paused.
+    fn require_feature_not_paused(&self, feature_name: &[u8]) {
+        self.require_not_paused();
         require!(
-            self.blockchain().get_caller() == self.blockchain().get_owner_address(),
-            "only owner allowed to unpause contract"
+            !self.is_feature_paused(ManagedBuffer::new_from_bytes(feature_name)),
+            "feature is paused"
         );
+    }
 
-        self.set_paused(false);
-        // TODO: event
-        Ok(())
+    // guardians
+
+    #[only_owner]
+    #[endpoint(addPauseGuardian)]
+    fn add_pause_guardian(&self, address: ManagedAddress) {
+        if self.pause_guardians().insert(address.clone()) {
+            self.add_pause_guardian_event(&address);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removePauseGuardian)]
+    fn remove_pause_guardian(&self, address: ManagedAddress) {
+        if self.pause_guardians().swap_remove(&address) {
+            self.remove_pause_guardian_event(&address);
+        }
+    }
+
+    #[view(isPauseGuardian)]
+    fn is_pause_guardian(&self, address: ManagedAddress) -> bool {
+        self.pause_guardians().contains(&address)
+    }
+
+    #[view(getPauseGuardians)]
+    fn get_pause_guardians(&self) -> ManagedMultiResultVec<ManagedAddress> {
+        let mut result = ManagedMultiResultVec::new();
+        for guardian in self.pause_guardians().iter() {
+            result.push(guardian);
+        }
+        result
+    }
+
+    // private
+
+    fn require_caller_can_pause(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.blockchain().get_owner_address()
+                || self.pause_guardians().contains(&caller),
+            "only owner or pause guardians allowed to pause"
+        );
+        caller
+    }
+
+    fn require_caller_is_owner(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.blockchain().get_owner_address(),
+            "only owner allowed to unpause"
+        );
+        caller
+    }
+
+    fn pause_until_argument(&self, opt_until: OptionalArg<u64>) -> u64 {
+        let until = opt_until.into_option().unwrap_or(NO_AUTO_UNPAUSE);
+        require!(
+            until == NO_AUTO_UNPAUSE || until > self.blockchain().get_block_timestamp(),
+            "pause end must be in the future"
+        );
+        until
+    }
+
+    fn pause_expired(&self, until: u64) -> bool {
+        until != NO_AUTO_UNPAUSE && self.blockchain().get_block_timestamp() >= until
+    }
+
+    // events
+
+    #[event("pause")]
+    fn pause_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] until: u64);
+
+    #[event("unpause")]
+    fn unpause_event(&self, #[indexed] caller: &ManagedAddress);
+
+    #[event("pauseEndpoint")]
+    fn pause_endpoint_event(
+        &self,
+        #[indexed] endpoint_name: &ManagedBuffer,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] until: u64,
+    );
+
+    #[event("unpauseEndpoint")]
+    fn unpause_endpoint_event(
+        &self,
+        #[indexed] endpoint_name: &ManagedBuffer,
+        #[indexed] caller: &ManagedAddress,
+    );
+
+    #[event("pauseFeature")]
+    fn pause_feature_event(
+        &self,
+        #[indexed] feature_name: &ManagedBuffer,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] until: u64,
+    );
+
+    #[event("unpauseFeature")]
+    fn unpause_feature_event(
+        &self,
+        #[indexed] feature_name: &ManagedBuffer,
+        #[indexed] caller: &ManagedAddress,
+    );
+
+    #[event("addPauseGuardian")]
+    fn add_pause_guardian_event(&self, #[indexed] address: &ManagedAddress);
+
+    #[event("removePauseGuardian")]
+    fn remove_pause_guardian_event(&self, #[indexed] address: &ManagedAddress);
+
+    // storage
+
+    #[storage_mapper("pause_module:paused")]
+    fn paused_flag(&self) -> SingleValueMapper<bool>;
+
+    /// Timestamp when the contract pause ends, `NO_AUTO_UNPAUSE` if it has no set end.
+    #[storage_mapper("pause_module:pausedUntil")]
+    fn paused_until(&self) -> SingleValueMapper<u64>;
+
+    /// Maps the paused endpoint names to the timestamp when their pause ends.
+    #[storage_mapper("pause_module:pausedEndpoints")]
+    fn paused_endpoints(&self) -> MapMapper<ManagedBuffer, u64>;
+
+    /// Maps the paused feature names to the timestamp when their pause ends.
+    #[storage_mapper("pause_module:pausedFeatures")]
+    fn paused_features(&self) -> MapMapper<ManagedBuffer, u64>;
+
+    #[storage_mapper("pause_module:guardians")]
+    fn pause_guardians(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
+
+/// The end of the longer of two pauses, `NO_AUTO_UNPAUSE` lasting the longest.
+fn later_pause_end(until: u64, other_until: u64) -> u64 {
+    if until == NO_AUTO_UNPAUSE || other_until == NO_AUTO_UNPAUSE {
+        NO_AUTO_UNPAUSE
+    } else {
+        core::cmp::max(until, other_until)
     }
 }
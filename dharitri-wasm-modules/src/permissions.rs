@@ -0,0 +1,129 @@
+dharitri_wasm::imports!();
+
+/// Role flags are kept in a `u64`, so at most this many distinct roles can exist.
+pub const MAX_ROLES: usize = 64;
+
+/// Standard smart contract module that, when added to a smart contract, offers role-based access control.
+///
+/// Roles are identified by name, e.g. `ADMIN` or `MINTER`.
+/// Each role is assigned a bit the first time it is granted,
+/// the roles of an address are stored as bit flags.
+///
+/// It offers:
+/// * endpoints where the owner can grant and revoke roles
+/// * views to check the roles of an address
+/// * a method to restrict calls to addresses having certain roles;
+///   endpoints can also use the `#[only_role("ADMIN")]` attribute, which calls it automatically
+/// * events for each role change
+///
+#[dharitri_wasm::module]
+pub trait PermissionsModule {
+    #[only_owner]
+    #[endpoint(grantRole)]
+    fn grant_role(&self, address: ManagedAddress, role: ManagedBuffer) {
+        let role_flag = self.get_or_create_role_flag(&role);
+        let already_granted = self.address_role_flags(&address).update(|flags| {
+            let already_granted = *flags & role_flag != 0;
+            *flags |= role_flag;
+            already_granted
+        });
+        if !already_granted {
+            self.grant_role_event(&address, &role);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, address: ManagedAddress, role: ManagedBuffer) {
+        let role_flag = self.role_flag(&role);
+        if role_flag == 0 {
+            return;
+        }
+        let was_granted = self.address_role_flags(&address).update(|flags| {
+            let was_granted = *flags & role_flag != 0;
+            *flags &= !role_flag;
+            was_granted
+        });
+        if was_granted {
+            self.revoke_role_event(&address, &role);
+        }
+    }
+
+    #[view(hasRole)]
+    fn has_role(&self, address: ManagedAddress, role: ManagedBuffer) -> bool {
+        let role_flag = self.role_flag(&role);
+        role_flag != 0 && self.address_role_flags(&address).get() & role_flag != 0
+    }
+
+    #[view(getAddressRoles)]
+    fn get_address_roles(&self, address: ManagedAddress) -> ManagedMultiResultVec<ManagedBuffer> {
+        let flags = self.address_role_flags(&address).get();
+        let mut result = ManagedMultiResultVec::new();
+        for (index, role) in self.role_names().iter().enumerate() {
+            if flags & (1u64 << index) != 0 {
+                result.push(role);
+            }
+        }
+        result
+    }
+
+    /// Stops execution unless the caller was granted at least one of the given roles.
+    /// Endpoints annotated with `#[only_role(...)]` call this before anything else.
+    fn require_caller_has_any_role(&self, roles: &[&[u8]]) {
+        let caller_flags = self
+            .address_role_flags(&self.blockchain().get_caller())
+            .get();
+        let allowed = roles
+            .iter()
+            .any(|role| caller_flags & self.role_flag(&ManagedBuffer::from(*role)) != 0);
+        require!(allowed, "caller does not have the required role");
+    }
+
+    /// The flag corresponding to a role, 0 if the role was never granted.
+    fn role_flag(&self, role: &ManagedBuffer) -> u64 {
+        let role_id = self.role_id(role).get();
+        if role_id == 0 {
+            0
+        } else {
+            1u64 << (role_id - 1)
+        }
+    }
+
+    fn get_or_create_role_flag(&self, role: &ManagedBuffer) -> u64 {
+        if self.role_id(role).is_empty() {
+            let mut role_names = self.role_names();
+            require!(role_names.len() < MAX_ROLES, "too many roles");
+            let role_id = role_names.push(role);
+            self.role_id(role).set(role_id);
+        }
+        self.role_flag(role)
+    }
+
+    // events
+
+    #[event("grantRole")]
+    fn grant_role_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] role: &ManagedBuffer,
+    );
+
+    #[event("revokeRole")]
+    fn revoke_role_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] role: &ManagedBuffer,
+    );
+
+    // storage
+
+    #[storage_mapper("permissions:addressRoleFlags")]
+    fn address_role_flags(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// Position of the role in `role_names`, starting from 1.
+    #[storage_mapper("permissions:roleId")]
+    fn role_id(&self, role: &ManagedBuffer) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("permissions:roleNames")]
+    fn role_names(&self) -> VecMapper<ManagedBuffer>;
+}
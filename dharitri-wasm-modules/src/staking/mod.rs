@@ -0,0 +1,383 @@
+dharitri_wasm::imports!();
+
+pub mod staking_position;
+use staking_position::*;
+
+/// # Dharitri smart contract module - Staking
+///
+/// This is a standard smart contract module, that when added to a smart contract offers staking:
+/// - stakers deposit the staking token and accrue rewards every block, proportionally to their stake
+/// - rewards are paid in the reward token, out of a reserve that anyone can fund
+/// - the owner configures the reward per block and the unbonding period
+/// - unstaked tokens can be withdrawn once the unbonding period has passed, or right away if it is 0
+/// - if the staking and reward tokens are the same, rewards can be compounded into the stake
+///
+/// Rewards are tracked with a global reward per staked token, which only grows.
+/// Each position remembers its value at the last update, so updating a position is constant-time,
+/// no matter how many stakers there are.
+/// No more rewards accrue once the reserve runs out, until it is funded again.
+///
+/// The module also provides events for most actions that happen:
+/// - `stake`, `unstake` - also provides the block starting with which the tokens can be withdrawn
+/// - `withdrawUnbonded`, `claimRewards`, `compoundRewards`
+/// - `depositRewards`, `rewardPerBlockChanged`
+///
+#[dharitri_wasm::module]
+pub trait StakingModule {
+    // endpoints - owner-only
+
+    #[only_owner]
+    #[endpoint(initStakingModule)]
+    fn init_staking_module(
+        &self,
+        staking_token_id: TokenIdentifier,
+        reward_token_id: TokenIdentifier,
+        reward_per_block: BigUint,
+        unbonding_period: u64,
+    ) {
+        require!(
+            staking_token_id.is_valid_dct_identifier(),
+            "Invalid DCT token ID provided for staking_token_id"
+        );
+        require!(
+            reward_token_id.is_valid_dct_identifier(),
+            "Invalid DCT token ID provided for reward_token_id"
+        );
+
+        self.staking_token_id().set_if_empty(&staking_token_id);
+        self.reward_token_id().set_if_empty(&reward_token_id);
+        self.set_reward_per_block(reward_per_block);
+        self.set_unbonding_period(unbonding_period);
+    }
+
+    /// Rewards accrued until the current block still use the previous rate.
+    #[only_owner]
+    #[endpoint(setRewardPerBlock)]
+    fn set_reward_per_block(&self, reward_per_block: BigUint) {
+        self.update_reward_per_share();
+        self.reward_per_block().set(&reward_per_block);
+
+        self.reward_per_block_changed_event(&reward_per_block);
+    }
+
+    /// Number of blocks unstaked tokens are locked for. Only applies to future unstakes.
+    #[only_owner]
+    #[endpoint(setUnbondingPeriod)]
+    fn set_unbonding_period(&self, unbonding_period: u64) {
+        self.unbonding_period().set(unbonding_period);
+    }
+
+    // endpoints
+
+    /// Adds reward tokens to the reserve the rewards are paid from.
+    #[payable("*")]
+    #[endpoint(depositRewards)]
+    fn deposit_rewards(
+        &self,
+        #[payment_token] payment_token: TokenIdentifier,
+        #[payment_amount] payment_amount: BigUint,
+    ) {
+        require!(
+            payment_token == self.reward_token_id().get(),
+            "Only reward token accepted"
+        );
+        require!(payment_amount > 0, "Must deposit a non-zero amount");
+
+        // blocks passed while the reserve was empty do not get rewards retroactively
+        self.update_reward_per_share();
+        self.reward_reserve()
+            .update(|reserve| *reserve += &payment_amount);
+
+        let caller = self.blockchain().get_caller();
+        self.deposit_rewards_event(&caller, &payment_amount);
+    }
+
+    #[payable("*")]
+    #[endpoint(stake)]
+    fn stake(
+        &self,
+        #[payment_token] payment_token: TokenIdentifier,
+        #[payment_amount] payment_amount: BigUint,
+    ) {
+        require!(
+            payment_token == self.staking_token_id().get(),
+            "Only staking token accepted"
+        );
+        require!(payment_amount > 0, "Must stake a non-zero amount");
+
+        let caller = self.blockchain().get_caller();
+        let mut position = self.update_staking_position(&caller);
+        position.staked_amount += &payment_amount;
+        self.staking_positions().insert(caller.clone(), position);
+        self.total_staked()
+            .update(|total_staked| *total_staked += &payment_amount);
+
+        self.stake_event(&caller, &payment_amount);
+    }
+
+    /// The unstaked tokens stop earning rewards right away.
+    /// They are sent back once the unbonding period has passed, through `withdrawUnbonded`.
+    /// Unstaking again restarts the unbonding period for all the tokens not yet withdrawn.
+    #[endpoint(unstake)]
+    fn unstake(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let mut position = self.update_staking_position(&caller);
+        require!(
+            amount > 0 && amount <= position.staked_amount,
+            "Cannot unstake more than the staked amount"
+        );
+
+        position.staked_amount -= &amount;
+        self.save_staking_position(&caller, position);
+        self.total_staked()
+            .update(|total_staked| *total_staked -= &amount);
+
+        let unbond_block = self.blockchain().get_block_nonce() + self.unbonding_period().get();
+        let mut unbonding_stake =
+            self.unbonding_stakes()
+                .get(&caller)
+                .unwrap_or_else(|| UnbondingStake {
+                    amount: BigUint::zero(),
+                    unbond_block,
+                });
+        unbonding_stake.amount += &amount;
+        unbonding_stake.unbond_block = unbond_block;
+        self.unbonding_stakes()
+            .insert(caller.clone(), unbonding_stake);
+
+        self.unstake_event(&caller, unbond_block, &amount);
+
+        if self.unbonding_period().get() == 0 {
+            self.withdraw_unbonded();
+        }
+    }
+
+    #[endpoint(withdrawUnbonded)]
+    fn withdraw_unbonded(&self) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        let unbonding_stake = match self.unbonding_stakes().get(&caller) {
+            Some(unbonding_stake) => unbonding_stake,
+            None => sc_panic!("No unstaked tokens to withdraw"),
+        };
+        require!(
+            self.blockchain().get_block_nonce() >= unbonding_stake.unbond_block,
+            "Unbonding period has not ended"
+        );
+
+        self.unbonding_stakes().remove(&caller);
+        self.send().direct(
+            &caller,
+            &self.staking_token_id().get(),
+            0,
+            &unbonding_stake.amount,
+            &[],
+        );
+
+        self.withdraw_unbonded_event(&caller, &unbonding_stake.amount);
+
+        unbonding_stake.amount
+    }
+
+    #[endpoint(claimRewards)]
+    fn claim_rewards(&self) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        let mut position = self.update_staking_position(&caller);
+        let rewards = core::mem::replace(&mut position.accrued_rewards, BigUint::zero());
+        require!(rewards > 0, "No rewards to claim");
+
+        self.save_staking_position(&caller, position);
+        self.send()
+            .direct(&caller, &self.reward_token_id().get(), 0, &rewards, &[]);
+
+        self.claim_rewards_event(&caller, &rewards);
+
+        rewards
+    }
+
+    /// Adds the accrued rewards to the stake, instead of sending them.
+    /// Only possible if the rewards are paid in the staking token.
+    #[endpoint(compoundRewards)]
+    fn compound_rewards(&self) -> BigUint {
+        require!(
+            self.staking_token_id().get() == self.reward_token_id().get(),
+            "Rewards can only be compounded if paid in the staking token"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let mut position = self.update_staking_position(&caller);
+        let rewards = core::mem::replace(&mut position.accrued_rewards, BigUint::zero());
+        require!(rewards > 0, "No rewards to compound");
+
+        position.staked_amount += &rewards;
+        self.staking_positions().insert(caller.clone(), position);
+        self.total_staked()
+            .update(|total_staked| *total_staked += &rewards);
+
+        self.compound_rewards_event(&caller, &rewards);
+
+        rewards
+    }
+
+    // views
+
+    #[view(getStakedAmount)]
+    fn get_staked_amount(&self, address: ManagedAddress) -> BigUint {
+        match self.staking_positions().get(&address) {
+            Some(position) => position.staked_amount,
+            None => BigUint::zero(),
+        }
+    }
+
+    /// Rewards the address could claim in the current block.
+    #[view(getPendingRewards)]
+    fn get_pending_rewards(&self, address: ManagedAddress) -> BigUint {
+        match self.staking_positions().get(&address) {
+            Some(position) => position.pending_rewards(&self.current_reward_per_share()),
+            None => BigUint::zero(),
+        }
+    }
+
+    #[view(getUnbondingStake)]
+    fn get_unbonding_stake(
+        &self,
+        address: ManagedAddress,
+    ) -> OptionalResult<UnbondingStake<Self::Api>> {
+        self.unbonding_stakes().get(&address).into()
+    }
+
+    // private
+
+    /// Rewards accrued since the last update, limited by what is left in the reserve.
+    fn rewards_since_last_update(&self) -> BigUint {
+        if self.total_staked().get() == 0 {
+            return BigUint::zero();
+        }
+
+        let blocks = self
+            .blockchain()
+            .get_block_nonce()
+            .saturating_sub(self.last_reward_block().get());
+        let rewards = self.reward_per_block().get() * blocks;
+        let reserve = self.reward_reserve().get();
+        if rewards > reserve {
+            reserve
+        } else {
+            rewards
+        }
+    }
+
+    fn current_reward_per_share(&self) -> BigUint {
+        let reward_per_share = self.reward_per_share().get();
+        let rewards = self.rewards_since_last_update();
+        if rewards == 0 {
+            return reward_per_share;
+        }
+
+        reward_per_share + rewards * REWARD_PER_SHARE_PRECISION / self.total_staked().get()
+    }
+
+    fn update_reward_per_share(&self) {
+        let rewards = self.rewards_since_last_update();
+        if rewards > 0 {
+            let reward_per_share = self.current_reward_per_share();
+            self.reward_per_share().set(&reward_per_share);
+            self.reward_reserve().update(|reserve| *reserve -= &rewards);
+        }
+        self.last_reward_block()
+            .set(self.blockchain().get_block_nonce());
+    }
+
+    /// Brings the rewards of the position up to date, without saving it.
+    fn update_staking_position(&self, address: &ManagedAddress) -> StakingPosition<Self::Api> {
+        self.update_reward_per_share();
+
+        let reward_per_share = self.reward_per_share().get();
+        match self.staking_positions().get(address) {
+            Some(mut position) => {
+                position.update_rewards(&reward_per_share);
+                position
+            },
+            None => StakingPosition::new(reward_per_share),
+        }
+    }
+
+    fn save_staking_position(
+        &self,
+        address: &ManagedAddress,
+        position: StakingPosition<Self::Api>,
+    ) {
+        if position.staked_amount == 0 && position.accrued_rewards == 0 {
+            self.staking_positions().remove(address);
+        } else {
+            self.staking_positions().insert(address.clone(), position);
+        }
+    }
+
+    // events
+
+    #[event("stake")]
+    fn stake_event(&self, #[indexed] caller: &ManagedAddress, amount: &BigUint);
+
+    #[event("unstake")]
+    fn unstake_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] unbond_block: u64,
+        amount: &BigUint,
+    );
+
+    #[event("withdrawUnbonded")]
+    fn withdraw_unbonded_event(&self, #[indexed] caller: &ManagedAddress, amount: &BigUint);
+
+    #[event("claimRewards")]
+    fn claim_rewards_event(&self, #[indexed] caller: &ManagedAddress, amount: &BigUint);
+
+    #[event("compoundRewards")]
+    fn compound_rewards_event(&self, #[indexed] caller: &ManagedAddress, amount: &BigUint);
+
+    #[event("depositRewards")]
+    fn deposit_rewards_event(&self, #[indexed] caller: &ManagedAddress, amount: &BigUint);
+
+    #[event("rewardPerBlockChanged")]
+    fn reward_per_block_changed_event(&self, reward_per_block: &BigUint);
+
+    // storage
+
+    #[view(getStakingTokenId)]
+    #[storage_mapper("staking_module:stakingTokenId")]
+    fn staking_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getRewardTokenId)]
+    #[storage_mapper("staking_module:rewardTokenId")]
+    fn reward_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getRewardPerBlock)]
+    #[storage_mapper("staking_module:rewardPerBlock")]
+    fn reward_per_block(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getUnbondingPeriod)]
+    #[storage_mapper("staking_module:unbondingPeriod")]
+    fn unbonding_period(&self) -> SingleValueMapper<u64>;
+
+    #[view(getTotalStaked)]
+    #[storage_mapper("staking_module:totalStaked")]
+    fn total_staked(&self) -> SingleValueMapper<BigUint>;
+
+    /// Reward tokens deposited and not yet distributed.
+    #[view(getRewardReserve)]
+    #[storage_mapper("staking_module:rewardReserve")]
+    fn reward_reserve(&self) -> SingleValueMapper<BigUint>;
+
+    /// Rewards accrued by each staked token since the start, in fixed point (see `REWARD_PER_SHARE_PRECISION`).
+    #[storage_mapper("staking_module:rewardPerShare")]
+    fn reward_per_share(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("staking_module:lastRewardBlock")]
+    fn last_reward_block(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("staking_module:positions")]
+    fn staking_positions(&self) -> MapMapper<ManagedAddress, StakingPosition<Self::Api>>;
+
+    #[storage_mapper("staking_module:unbonding")]
+    fn unbonding_stakes(&self) -> MapMapper<ManagedAddress, UnbondingStake<Self::Api>>;
+}
@@ -0,0 +1,44 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+/// Scaling factor of the reward per staked token, which is usually a fraction.
+pub const REWARD_PER_SHARE_PRECISION: u64 = 1_000_000_000_000_000_000;
+
+/// The stake of an address and the rewards it has accrued so far.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone)]
+pub struct StakingPosition<M: ManagedTypeApi> {
+    pub staked_amount: BigUint<M>,
+    /// The reward per share when the position was last updated.
+    pub reward_per_share: BigUint<M>,
+    /// Rewards accrued up to the last update, not yet claimed.
+    pub accrued_rewards: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> StakingPosition<M> {
+    pub fn new(reward_per_share: BigUint<M>) -> Self {
+        StakingPosition {
+            staked_amount: BigUint::zero(),
+            reward_per_share,
+            accrued_rewards: BigUint::zero(),
+        }
+    }
+
+    /// Rewards accrued up to the last update, plus those earned since then.
+    pub fn pending_rewards(&self, reward_per_share: &BigUint<M>) -> BigUint<M> {
+        let reward_per_share_increase = reward_per_share - &self.reward_per_share;
+        &self.staked_amount * &reward_per_share_increase / REWARD_PER_SHARE_PRECISION
+            + &self.accrued_rewards
+    }
+
+    pub fn update_rewards(&mut self, reward_per_share: &BigUint<M>) {
+        self.accrued_rewards = self.pending_rewards(reward_per_share);
+        self.reward_per_share = reward_per_share.clone();
+    }
+}
+
+/// Unstaked tokens, which can be withdrawn starting with `unbond_block`.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone)]
+pub struct UnbondingStake<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub unbond_block: u64,
+}
@@ -0,0 +1,328 @@
+dharitri_wasm::imports!();
+
+pub mod vesting_schedule;
+use vesting_schedule::*;
+
+/// # Dharitri smart contract module - Vesting
+///
+/// This is a standard smart contract module, that when added to a smart contract offers token vesting:
+/// - the owner defines vesting groups, each with its own schedule: cliff, linear or periodic
+/// - the owner locks tokens in grants, each grant belonging to a beneficiary and following the schedule of a group
+/// - beneficiaries claim the tokens unlocked so far from all their grants at once
+/// - revocable grants can be revoked by the owner, who gets back the tokens that have not vested yet
+///
+/// Grants are funded by paying the vesting token when adding them.
+/// Contracts that mint the vested tokens themselves can call `add_vesting_grant` directly.
+///
+/// The module also provides events for most actions that happen:
+/// - `vestingGroupAdded`, `vestingGroupRemoved`
+/// - `vestingGrantAdded` - also provides the beneficiary, group and amount
+/// - `vestingGrantRevoked` - also provides the amount returned to the owner
+/// - `vestedTokensClaimed`
+///
+#[dharitri_wasm::module]
+pub trait VestingModule {
+    // endpoints - owner-only
+
+    #[only_owner]
+    #[endpoint(initVestingModule)]
+    fn init_vesting_module(&self, vesting_token_id: TokenIdentifier) -> SCResult<()> {
+        require!(
+            vesting_token_id.is_valid_dct_identifier(),
+            "Invalid DCT token ID provided for vesting_token_id"
+        );
+
+        self.vesting_token_id().set_if_empty(&vesting_token_id);
+        Ok(())
+    }
+
+    #[only_owner]
+    #[endpoint(addVestingGroup)]
+    fn add_vesting_group(
+        &self,
+        group_identifier: ManagedBuffer,
+        schedule: VestingSchedule,
+    ) -> SCResult<()> {
+        require!(
+            self.vesting_group(&group_identifier).is_empty(),
+            "The group already exists"
+        );
+        schedule.validate()?;
+
+        self.vesting_group(&group_identifier).set(&schedule);
+        self.vesting_group_added_event(&group_identifier, &schedule);
+
+        Ok(())
+    }
+
+    /// Only groups that never had grants can be removed.
+    #[only_owner]
+    #[endpoint(removeVestingGroup)]
+    fn remove_vesting_group(&self, group_identifier: ManagedBuffer) -> SCResult<()> {
+        self.require_group_exists(&group_identifier)?;
+        require!(
+            self.vesting_group_grant_count(&group_identifier).get() == 0,
+            "The group has grants"
+        );
+
+        self.vesting_group(&group_identifier).clear();
+        self.vesting_group_removed_event(&group_identifier);
+
+        Ok(())
+    }
+
+    /// Locks the paid tokens in a new grant and returns its ID.
+    /// The grant starts vesting at `opt_start_timestamp`, by default the current block timestamp.
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(addVestingGrant)]
+    fn add_vesting_grant_endpoint(
+        &self,
+        #[payment_token] payment_token: TokenIdentifier,
+        #[payment_amount] payment_amount: BigUint,
+        beneficiary: ManagedAddress,
+        group_identifier: ManagedBuffer,
+        revocable: bool,
+        #[var_args] opt_start_timestamp: OptionalArg<u64>,
+    ) -> SCResult<usize> {
+        require!(
+            payment_token == self.vesting_token_id().get(),
+            "Only vesting token accepted as payment"
+        );
+
+        let start_timestamp = opt_start_timestamp
+            .into_option()
+            .unwrap_or_else(|| self.blockchain().get_block_timestamp());
+        self.add_vesting_grant(
+            beneficiary,
+            group_identifier,
+            payment_amount,
+            revocable,
+            start_timestamp,
+        )
+    }
+
+    /// The tokens vested so far remain claimable by the beneficiary.
+    /// The rest are sent back to the owner.
+    #[only_owner]
+    #[endpoint(revokeVestingGrant)]
+    fn revoke_vesting_grant(&self, grant_id: usize) -> SCResult<BigUint> {
+        self.require_grant_exists(grant_id)?;
+
+        let mut grant = self.vesting_grants().get(grant_id);
+        require!(grant.revocable, "The grant is not revocable");
+        require!(!grant.revoked, "The grant was already revoked");
+
+        let schedule = self.vesting_group(&grant.group_identifier).get();
+        let vested_amount = grant.vested_amount(&schedule, self.blockchain().get_block_timestamp());
+        let unvested_amount = &grant.total_amount - &vested_amount;
+
+        grant.total_amount = vested_amount;
+        grant.revoked = true;
+        self.vesting_grants().set(grant_id, &grant);
+
+        if unvested_amount > 0 {
+            let caller = self.blockchain().get_caller();
+            self.send().direct(
+                &caller,
+                &self.vesting_token_id().get(),
+                0,
+                &unvested_amount,
+                &[],
+            );
+        }
+
+        self.vesting_grant_revoked_event(grant_id, &unvested_amount);
+
+        Ok(unvested_amount)
+    }
+
+    // endpoints - beneficiaries
+
+    /// Sends the caller all the tokens unlocked so far from their grants.
+    #[endpoint(claimVested)]
+    fn claim_vested(&self) -> SCResult<BigUint> {
+        let caller = self.blockchain().get_caller();
+        let timestamp = self.blockchain().get_block_timestamp();
+
+        let mut claimed_amount = BigUint::zero();
+        for grant_id in self.beneficiary_grants(&caller).iter() {
+            let mut grant = self.vesting_grants().get(grant_id);
+            let schedule = self.vesting_group(&grant.group_identifier).get();
+            let claimable_amount = grant.claimable_amount(&schedule, timestamp);
+            if claimable_amount == 0 {
+                continue;
+            }
+
+            grant.claimed_amount += &claimable_amount;
+            self.vesting_grants().set(grant_id, &grant);
+            claimed_amount += claimable_amount;
+        }
+
+        require!(
+            claimed_amount > 0,
+            "This address cannot currently claim any more tokens"
+        );
+
+        self.send().direct(
+            &caller,
+            &self.vesting_token_id().get(),
+            0,
+            &claimed_amount,
+            &[],
+        );
+        self.vested_tokens_claimed_event(&caller, &claimed_amount);
+
+        Ok(claimed_amount)
+    }
+
+    // views
+
+    /// Total amount the address can currently claim, from all their grants.
+    #[view(getClaimableAmount)]
+    fn get_claimable_amount(&self, beneficiary: ManagedAddress) -> BigUint {
+        let timestamp = self.blockchain().get_block_timestamp();
+
+        let mut claimable_amount = BigUint::zero();
+        for grant_id in self.beneficiary_grants(&beneficiary).iter() {
+            let grant = self.vesting_grants().get(grant_id);
+            let schedule = self.vesting_group(&grant.group_identifier).get();
+            claimable_amount += grant.claimable_amount(&schedule, timestamp);
+        }
+        claimable_amount
+    }
+
+    #[view(getGrantClaimableAmount)]
+    fn get_grant_claimable_amount(&self, grant_id: usize) -> SCResult<BigUint> {
+        self.require_grant_exists(grant_id)?;
+
+        let grant = self.vesting_grants().get(grant_id);
+        let schedule = self.vesting_group(&grant.group_identifier).get();
+        Ok(grant.claimable_amount(&schedule, self.blockchain().get_block_timestamp()))
+    }
+
+    #[view(getGrantVestedAmount)]
+    fn get_grant_vested_amount(&self, grant_id: usize) -> SCResult<BigUint> {
+        self.require_grant_exists(grant_id)?;
+
+        let grant = self.vesting_grants().get(grant_id);
+        let schedule = self.vesting_group(&grant.group_identifier).get();
+        Ok(grant.vested_amount(&schedule, self.blockchain().get_block_timestamp()))
+    }
+
+    #[view(getVestingGrant)]
+    fn get_vesting_grant(&self, grant_id: usize) -> SCResult<VestingGrant<Self::Api>> {
+        self.require_grant_exists(grant_id)?;
+
+        Ok(self.vesting_grants().get(grant_id))
+    }
+
+    // private
+
+    /// Adds a grant for tokens that the contract already holds, or will mint, and returns its ID.
+    fn add_vesting_grant(
+        &self,
+        beneficiary: ManagedAddress,
+        group_identifier: ManagedBuffer,
+        amount: BigUint,
+        revocable: bool,
+        start_timestamp: u64,
+    ) -> SCResult<usize> {
+        self.require_group_exists(&group_identifier)?;
+        require!(amount > 0, "Must grant a non-zero amount");
+
+        let grant = VestingGrant {
+            beneficiary: beneficiary.clone(),
+            group_identifier: group_identifier.clone(),
+            start_timestamp,
+            total_amount: amount,
+            claimed_amount: BigUint::zero(),
+            revocable,
+            revoked: false,
+        };
+        let grant_id = self.vesting_grants().push(&grant);
+        self.beneficiary_grants(&beneficiary).insert(grant_id);
+        self.vesting_group_grant_count(&group_identifier)
+            .update(|count| *count += 1);
+
+        self.vesting_grant_added_event(
+            grant_id,
+            &beneficiary,
+            &group_identifier,
+            &grant.total_amount,
+        );
+
+        Ok(grant_id)
+    }
+
+    fn require_group_exists(&self, group_identifier: &ManagedBuffer) -> SCResult<()> {
+        require!(
+            !self.vesting_group(group_identifier).is_empty(),
+            "The group does not exist"
+        );
+        Ok(())
+    }
+
+    fn require_grant_exists(&self, grant_id: usize) -> SCResult<()> {
+        require!(
+            grant_id > 0 && grant_id <= self.vesting_grants().len(),
+            "The grant does not exist"
+        );
+        Ok(())
+    }
+
+    // events
+
+    #[event("vestingGroupAdded")]
+    fn vesting_group_added_event(
+        &self,
+        #[indexed] group_identifier: &ManagedBuffer,
+        schedule: &VestingSchedule,
+    );
+
+    #[event("vestingGroupRemoved")]
+    fn vesting_group_removed_event(&self, #[indexed] group_identifier: &ManagedBuffer);
+
+    #[event("vestingGrantAdded")]
+    fn vesting_grant_added_event(
+        &self,
+        #[indexed] grant_id: usize,
+        #[indexed] beneficiary: &ManagedAddress,
+        #[indexed] group_identifier: &ManagedBuffer,
+        amount: &BigUint,
+    );
+
+    #[event("vestingGrantRevoked")]
+    fn vesting_grant_revoked_event(&self, #[indexed] grant_id: usize, unvested_amount: &BigUint);
+
+    #[event("vestedTokensClaimed")]
+    fn vested_tokens_claimed_event(
+        &self,
+        #[indexed] beneficiary: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    // storage
+
+    #[view(getVestingTokenId)]
+    #[storage_mapper("vesting_module:vestingTokenId")]
+    fn vesting_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getVestingGroup)]
+    #[storage_mapper("vesting_module:group")]
+    fn vesting_group(&self, group_identifier: &ManagedBuffer)
+        -> SingleValueMapper<VestingSchedule>;
+
+    #[storage_mapper("vesting_module:groupGrantCount")]
+    fn vesting_group_grant_count(
+        &self,
+        group_identifier: &ManagedBuffer,
+    ) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("vesting_module:grants")]
+    fn vesting_grants(&self) -> VecMapper<VestingGrant<Self::Api>>;
+
+    #[view(getBeneficiaryGrants)]
+    #[storage_mapper("vesting_module:beneficiaryGrants")]
+    fn beneficiary_grants(&self, beneficiary: &ManagedAddress) -> UnorderedSetMapper<usize>;
+}
@@ -0,0 +1,113 @@
+dharitri_wasm::imports!();
+dharitri_wasm::derive_imports!();
+
+/// How the tokens of a grant unlock over time.
+/// All durations are in seconds and count from the start timestamp of the grant.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone)]
+pub enum VestingSchedule {
+    /// All tokens unlock at once, `cliff_duration` after the start.
+    Cliff { cliff_duration: u64 },
+    /// Tokens unlock continuously until `duration` has passed.
+    /// Nothing can be claimed before `cliff_duration` has passed,
+    /// after which the amount unlocked so far becomes available at once.
+    Linear { cliff_duration: u64, duration: u64 },
+    /// Tokens unlock in `release_ticks` equal parts, one at the end of each `release_period`.
+    Periodic {
+        release_period: u64,
+        release_ticks: u64,
+    },
+}
+
+impl VestingSchedule {
+    pub fn validate(&self) -> SCResult<()> {
+        match self {
+            VestingSchedule::Cliff { .. } => {},
+            VestingSchedule::Linear {
+                cliff_duration,
+                duration,
+            } => {
+                require_old!(*duration > 0, "Vesting duration must be positive");
+                require_old!(
+                    cliff_duration <= duration,
+                    "Cliff cannot be longer than the vesting duration"
+                );
+            },
+            VestingSchedule::Periodic {
+                release_period,
+                release_ticks,
+            } => {
+                require_old!(*release_period > 0, "Release period must be positive");
+                require_old!(
+                    *release_ticks > 0,
+                    "The schedule must have at least 1 unlock period"
+                );
+            },
+        }
+        Ok(())
+    }
+
+    /// The part of `total_amount` unlocked once `time_passed` seconds have passed since the start.
+    pub fn vested_amount<M: ManagedTypeApi>(
+        &self,
+        total_amount: &BigUint<M>,
+        time_passed: u64,
+    ) -> BigUint<M> {
+        match self {
+            VestingSchedule::Cliff { cliff_duration } => {
+                if time_passed >= *cliff_duration {
+                    total_amount.clone()
+                } else {
+                    BigUint::zero()
+                }
+            },
+            VestingSchedule::Linear {
+                cliff_duration,
+                duration,
+            } => {
+                if time_passed < *cliff_duration {
+                    BigUint::zero()
+                } else if time_passed >= *duration {
+                    total_amount.clone()
+                } else {
+                    total_amount * time_passed / *duration
+                }
+            },
+            VestingSchedule::Periodic {
+                release_period,
+                release_ticks,
+            } => {
+                let periods_passed = core::cmp::min(time_passed / release_period, *release_ticks);
+                total_amount * periods_passed / *release_ticks
+            },
+        }
+    }
+}
+
+/// An amount of tokens that unlocks for a beneficiary, following the schedule of a vesting group.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone)]
+pub struct VestingGrant<M: ManagedTypeApi> {
+    pub beneficiary: ManagedAddress<M>,
+    pub group_identifier: ManagedBuffer<M>,
+    pub start_timestamp: u64,
+    pub total_amount: BigUint<M>,
+    pub claimed_amount: BigUint<M>,
+    pub revocable: bool,
+    /// Revoked grants keep only the tokens vested up to the revocation, all of them claimable.
+    pub revoked: bool,
+}
+
+impl<M: ManagedTypeApi> VestingGrant<M> {
+    pub fn vested_amount(&self, schedule: &VestingSchedule, timestamp: u64) -> BigUint<M> {
+        if self.revoked {
+            return self.total_amount.clone();
+        }
+        if timestamp < self.start_timestamp {
+            return BigUint::zero();
+        }
+        schedule.vested_amount(&self.total_amount, timestamp - self.start_timestamp)
+    }
+
+    pub fn claimable_amount(&self, schedule: &VestingSchedule, timestamp: u64) -> BigUint<M> {
+        self.vested_amount(schedule, timestamp) - &self.claimed_amount
+    }
+}
@@ -369,6 +369,21 @@ impl SendApiImpl for VmApiImpl {
         }
     }
 
+    fn create_async_call_raw<M: ManagedTypeApi>(
+        &self,
+        _to: &ManagedAddress<M>,
+        _amount: &BigUint<M>,
+        _endpoint_name: &ManagedBuffer<M>,
+        _arg_buffer: &ManagedArgBuffer<M>,
+        _success_callback_closure: &ManagedBuffer<M>,
+        _error_callback_closure: &ManagedBuffer<M>,
+        _gas: u64,
+        _extra_gas_for_callback: u64,
+    ) {
+        // the VM does not offer promises yet
+        crate::error_hook::signal_error(b"async call promises not supported")
+    }
+
     fn deploy_contract<M: ManagedTypeApi>(
         &self,
         gas: u64,
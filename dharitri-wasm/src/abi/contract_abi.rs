@@ -7,6 +7,7 @@ pub struct ContractAbi {
     pub docs: &'static [&'static str],
     pub name: &'static str,
     pub constructors: Vec<EndpointAbi>,
+    pub upgrade_constructors: Vec<EndpointAbi>,
     pub endpoints: Vec<EndpointAbi>,
     pub has_callback: bool,
     pub type_descriptions: TypeDescriptionContainerImpl,
@@ -16,6 +17,8 @@ impl ContractAbi {
     pub fn coalesce(&mut self, other: Self) {
         self.constructors
             .extend_from_slice(other.constructors.as_slice());
+        self.upgrade_constructors
+            .extend_from_slice(other.upgrade_constructors.as_slice());
         self.endpoints.extend_from_slice(other.endpoints.as_slice());
         self.has_callback |= other.has_callback;
         self.type_descriptions.insert_all(&other.type_descriptions);
@@ -28,6 +31,7 @@ impl ContractAbi {
             docs: self.docs,
             name: self.name,
             constructors: self.constructors.clone(),
+            upgrade_constructors: self.upgrade_constructors.clone(),
             endpoints: self
                 .endpoints
                 .clone()
@@ -43,6 +47,7 @@ impl ContractAbi {
     pub fn location_exists(&self, location: EndpointLocationAbi) -> bool {
         self.constructors
             .iter()
+            .chain(self.upgrade_constructors.iter())
             .chain(self.endpoints.iter())
             .any(|endpoint| endpoint.location == location)
     }
@@ -54,6 +59,7 @@ impl ContractAbi {
             docs: self.docs,
             name: self.name,
             constructors: Vec::new(),
+            upgrade_constructors: Vec::new(),
             endpoints: self
                 .endpoints
                 .clone()
@@ -33,6 +33,7 @@ pub struct EndpointAbi {
     pub docs: &'static [&'static str],
     pub name: &'static str,
     pub only_owner: bool,
+    pub only_roles: &'static [&'static str],
     pub mutability: EndpointMutabilityAbi,
     pub location: EndpointLocationAbi,
     pub payable_in_tokens: &'static [&'static str],
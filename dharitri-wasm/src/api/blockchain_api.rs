@@ -1,7 +1,7 @@
 use super::{Handle, ManagedTypeApi, ManagedTypeApiImpl};
 use crate::types::{
-    Address, BigUint, DctLocalRoleFlags, DctTokenData, ManagedAddress, ManagedByteArray,
-    TokenIdentifier, H256,
+    Address, BigUint, DctLocalRoleFlags, DctTokenData, ManagedAddress, ManagedBuffer,
+    ManagedByteArray, TokenIdentifier, H256,
 };
 use alloc::boxed::Box;
 
@@ -73,6 +73,12 @@ pub trait BlockchainApiImpl: ManagedTypeApiImpl {
         ManagedByteArray::new_from_bytes(self.get_tx_hash_legacy().as_array())
     }
 
+    /// The serialized callback closure of the promise whose callback is currently being executed.
+    /// Empty outside of promise callbacks.
+    fn get_callback_closure<M: ManagedTypeApi>(&self) -> ManagedBuffer<M> {
+        ManagedBuffer::new()
+    }
+
     fn get_gas_left(&self) -> u64;
 
     fn get_block_timestamp(&self) -> u64;
@@ -5,7 +5,13 @@ pub const DCT_NFT_TRANSFER_FUNC_NAME: &[u8] = b"DCTNFTTransfer";
 pub const DCT_NFT_CREATE_FUNC_NAME: &[u8] = b"DCTNFTCreate";
 pub const DCT_NFT_ADD_QUANTITY_FUNC_NAME: &[u8] = b"DCTNFTAddQuantity";
 pub const DCT_NFT_BURN_FUNC_NAME: &[u8] = b"DCTNFTBurn";
+pub const DCT_NFT_ADD_URI_FUNC_NAME: &[u8] = b"DCTNFTAddURI";
+pub const DCT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME: &[u8] = b"DCTNFTUpdateAttributes";
+pub const DCT_FREEZE_FUNC_NAME: &[u8] = b"DCTFreeze";
+pub const DCT_UNFREEZE_FUNC_NAME: &[u8] = b"DCTUnFreeze";
+pub const DCT_WIPE_FUNC_NAME: &[u8] = b"DCTWipe";
 pub const DCT_TRANSFER_FUNC_NAME: &[u8] = b"DCTTransfer";
 pub const CHANGE_OWNER_BUILTIN_FUNC_NAME: &[u8] = b"ChangeOwnerAddress";
 pub const SET_USERNAME_FUNC_NAME: &[u8] = b"SetUserName";
+pub const SAVE_KEY_VALUE_FUNC_NAME: &[u8] = b"SaveKeyValue";
 pub const UPGRADE_CONTRACT_FUNC_NAME: &[u8] = b"upgradeContract";
@@ -76,6 +76,26 @@ pub trait SendApiImpl {
         arg_buffer: &ManagedArgBuffer<M>,
     ) -> !;
 
+    /// Registers an asynchronous call to another contract, without terminating tx execution.
+    /// Several calls can be registered during the same transaction.
+    /// They are performed after the transaction ends, in the order in which they were registered.
+    ///
+    /// The callback closures are not interpreted by the VM.
+    /// Depending on the outcome of the call, one of them is handed back to the `callBack` endpoint,
+    /// via `BlockchainApiImpl::get_callback_closure`.
+    #[allow(clippy::too_many_arguments)]
+    fn create_async_call_raw<M: ManagedTypeApi>(
+        &self,
+        to: &ManagedAddress<M>,
+        amount: &BigUint<M>,
+        endpoint_name: &ManagedBuffer<M>,
+        arg_buffer: &ManagedArgBuffer<M>,
+        success_callback_closure: &ManagedBuffer<M>,
+        error_callback_closure: &ManagedBuffer<M>,
+        gas: u64,
+        extra_gas_for_callback: u64,
+    );
+
     /// Deploys a new contract in the same shard.
     /// Unlike `async_call_raw`, the deployment is synchronous and tx execution continues afterwards.
     /// Also unlike `async_call_raw`, it uses an argument buffer to pass arguments
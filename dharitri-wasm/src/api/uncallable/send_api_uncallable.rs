@@ -82,6 +82,20 @@ impl SendApiImpl for UncallableApi {
         unreachable!()
     }
 
+    fn create_async_call_raw<M: ManagedTypeApi>(
+        &self,
+        _to: &ManagedAddress<M>,
+        _amount: &BigUint<M>,
+        _endpoint_name: &ManagedBuffer<M>,
+        _arg_buffer: &ManagedArgBuffer<M>,
+        _success_callback_closure: &ManagedBuffer<M>,
+        _error_callback_closure: &ManagedBuffer<M>,
+        _gas: u64,
+        _extra_gas_for_callback: u64,
+    ) {
+        unreachable!()
+    }
+
     fn deploy_contract<M: ManagedTypeApi>(
         &self,
         _gas: u64,
@@ -1,15 +1,27 @@
 use alloc::boxed::Box;
 
-use crate::api::VMApi;
+use crate::{abi::ContractAbi, api::VMApi};
 
 /// CallableContract is the means by which the debugger calls methods in the contract.
 pub trait CallableContract {
     fn call(&self, fn_name: &[u8]) -> bool;
 
+    /// Whether the contract has a method annotated with `#[upgrade]`.
+    /// Only then does the debugger call `upgrade` when upgrading, otherwise it calls the constructor.
+    fn has_upgrade_method(&self) -> bool {
+        false
+    }
+
     fn clone_obj(&self) -> Box<dyn CallableContract>;
 }
 
 /// Describes objects that can create instances of contract objects, with the given API.
 pub trait CallableContractBuilder {
     fn new_contract_obj<A: VMApi>(&self) -> Box<dyn CallableContract>;
+
+    /// The ABI of the contract, if known.
+    /// The debugger uses it to also list the endpoints that were never called.
+    fn abi(&self) -> Option<ContractAbi> {
+        None
+    }
 }
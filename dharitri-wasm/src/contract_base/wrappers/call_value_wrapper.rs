@@ -1,7 +1,8 @@
 use core::marker::PhantomData;
 
 use crate::{
-    api::{CallValueApi, CallValueApiImpl, ErrorApi, ManagedTypeApi},
+    api::{CallValueApi, CallValueApiImpl, ErrorApi, ErrorApiImpl, ManagedTypeApi},
+    err_msg,
     types::{BigUint, DctTokenPayment, DctTokenType, ManagedType, ManagedVec, TokenIdentifier},
 };
 
@@ -75,6 +76,41 @@ where
         BigUint::from_raw_handle(A::call_value_api_impl().require_dct(token))
     }
 
+    /// Fails if any of the received payments is in a token that is not accepted.
+    /// A call without DCT transfers is considered a MOAX payment, even if the MOAX value is zero.
+    /// All transfers of a multi-transfer are checked.
+    pub fn require_accepted_tokens<F>(&self, is_accepted: F)
+    where
+        F: Fn(&TokenIdentifier<A>) -> bool,
+    {
+        if A::call_value_api_impl().dct_num_transfers() == 0 {
+            if !is_accepted(&TokenIdentifier::moax()) {
+                A::error_api_impl().signal_error(err_msg::BAD_TOKEN_PROVIDED);
+            }
+            return;
+        }
+        for payment in self.all_dct_transfers().iter() {
+            if !is_accepted(&payment.token_identifier) {
+                A::error_api_impl().signal_error(err_msg::BAD_TOKEN_PROVIDED);
+            }
+        }
+    }
+
+    /// Fails if any of the received payments is in a token other than the given ones.
+    /// `MOAX` can also be part of the list.
+    /// Especially used in the `#[payable("TOKEN-1", "TOKEN-2")]` auto-generated snippets.
+    pub fn require_any_of_tokens(&self, tokens: &[&[u8]]) {
+        self.require_accepted_tokens(|token| {
+            tokens.iter().any(|accepted| {
+                if token.is_moax() {
+                    *accepted == &TokenIdentifier::<A>::MOAX_REPRESENTATION[..]
+                } else {
+                    token.as_managed_buffer() == *accepted
+                }
+            })
+        });
+    }
+
     /// Returns both the call value (either MOAX or DCT) and the token identifier.
     /// Especially used in the `#[payable("*")] auto-generated snippets.
     /// The method might seem redundant, but there is such a hook in Arwen
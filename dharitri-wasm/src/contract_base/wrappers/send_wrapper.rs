@@ -4,8 +4,9 @@ use crate::{
     api::{
         BlockchainApi, BlockchainApiImpl, CallTypeApi, SendApiImpl, StorageReadApi,
         CHANGE_OWNER_BUILTIN_FUNC_NAME, DCT_LOCAL_BURN_FUNC_NAME, DCT_LOCAL_MINT_FUNC_NAME,
-        DCT_MULTI_TRANSFER_FUNC_NAME, DCT_NFT_ADD_QUANTITY_FUNC_NAME, DCT_NFT_BURN_FUNC_NAME,
-        DCT_NFT_CREATE_FUNC_NAME, DCT_NFT_TRANSFER_FUNC_NAME, DCT_TRANSFER_FUNC_NAME,
+        DCT_MULTI_TRANSFER_FUNC_NAME, DCT_NFT_ADD_QUANTITY_FUNC_NAME, DCT_NFT_ADD_URI_FUNC_NAME,
+        DCT_NFT_BURN_FUNC_NAME, DCT_NFT_CREATE_FUNC_NAME, DCT_NFT_TRANSFER_FUNC_NAME,
+        DCT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME, DCT_TRANSFER_FUNC_NAME,
     },
     dct::DCTSystemSmartContractProxy,
     types::{
@@ -373,6 +374,51 @@ where
         }
     }
 
+    /// Replaces the attributes of an NFT held by the smart contract.  
+    /// `new_attributes` can be any serializable custom struct.  
+    /// This is a built-in function, so the smart contract execution is resumed after.
+    /// Must have `DCTRoleNFTUpdateAttributes` role set, or this will fail with "action is not allowed".
+    pub fn dct_nft_update_attributes<T: dharitri_codec::TopEncode>(
+        &self,
+        token: &TokenIdentifier<A>,
+        nonce: u64,
+        new_attributes: &T,
+    ) {
+        let mut arg_buffer = ManagedArgBuffer::new_empty();
+        arg_buffer.push_arg(token);
+        arg_buffer.push_arg(nonce);
+        arg_buffer.push_arg(new_attributes);
+
+        let _ = self.call_local_dct_built_in_function(
+            A::blockchain_api_impl().get_gas_left(),
+            &ManagedBuffer::new_from_bytes(DCT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME),
+            &arg_buffer,
+        );
+    }
+
+    /// Adds URIs to an NFT held by the smart contract, after the ones it already has.  
+    /// This is a built-in function, so the smart contract execution is resumed after.
+    /// Must have `DCTRoleNFTAddURI` role set, or this will fail with "action is not allowed".
+    pub fn dct_nft_add_uris(
+        &self,
+        token: &TokenIdentifier<A>,
+        nonce: u64,
+        uris: &ManagedVec<A, ManagedBuffer<A>>,
+    ) {
+        let mut arg_buffer = ManagedArgBuffer::new_empty();
+        arg_buffer.push_arg(token);
+        arg_buffer.push_arg(nonce);
+        for uri in uris {
+            arg_buffer.push_arg(uri);
+        }
+
+        let _ = self.call_local_dct_built_in_function(
+            A::blockchain_api_impl().get_gas_left(),
+            &ManagedBuffer::new_from_bytes(DCT_NFT_ADD_URI_FUNC_NAME),
+            &arg_buffer,
+        );
+    }
+
     /// Sends thr NFTs to the buyer address and calculates and sends the required royalties to the NFT creator.
     /// Returns the payment amount left after sending royalties.
     #[allow(clippy::too_many_arguments)]
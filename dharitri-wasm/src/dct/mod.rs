@@ -2,4 +2,4 @@ mod properties;
 mod system_sc_proxy;
 
 pub use properties::*;
-pub use system_sc_proxy::DCTSystemSmartContractProxy;
+pub use system_sc_proxy::{DCTSystemSmartContractProxy, DCT_SYSTEM_SC_ADDRESS_ARRAY};
@@ -352,6 +352,54 @@ where
         contract_call
     }
 
+    /// Freezes a single NFT held by an account, leaving the other nonces of the token transferable.
+    pub fn freeze_single_nft(
+        self,
+        token_identifier: &TokenIdentifier<SA>,
+        nonce: u64,
+        address: &ManagedAddress<SA>,
+    ) -> ContractCall<SA, ()> {
+        let mut contract_call = self.dct_system_sc_call_no_args(b"freezeSingleNFT");
+
+        contract_call.push_endpoint_arg(token_identifier);
+        contract_call.push_endpoint_arg(nonce);
+        contract_call.push_endpoint_arg(address);
+
+        contract_call
+    }
+
+    /// The reverse operation of `freeze_single_nft`.
+    pub fn unfreeze_single_nft(
+        self,
+        token_identifier: &TokenIdentifier<SA>,
+        nonce: u64,
+        address: &ManagedAddress<SA>,
+    ) -> ContractCall<SA, ()> {
+        let mut contract_call = self.dct_system_sc_call_no_args(b"unFreezeSingleNFT");
+
+        contract_call.push_endpoint_arg(token_identifier);
+        contract_call.push_endpoint_arg(nonce);
+        contract_call.push_endpoint_arg(address);
+
+        contract_call
+    }
+
+    /// Wipes out a single frozen NFT held by an account.
+    pub fn wipe_single_nft(
+        self,
+        token_identifier: &TokenIdentifier<SA>,
+        nonce: u64,
+        address: &ManagedAddress<SA>,
+    ) -> ContractCall<SA, ()> {
+        let mut contract_call = self.dct_system_sc_call_no_args(b"wipeSingleNFT");
+
+        contract_call.push_endpoint_arg(token_identifier);
+        contract_call.push_endpoint_arg(nonce);
+        contract_call.push_endpoint_arg(address);
+
+        contract_call
+    }
+
     /// This function converts an SFT to a metaDCT by adding decimals to its structure in the metachain DCT System SC.
     /// This function as almost all in case of DCT can be called only by the owner.
     pub fn change_sft_to_meta_dct(
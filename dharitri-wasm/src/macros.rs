@@ -44,7 +44,7 @@ macro_rules! derive_imports {
             dharitri_codec,
             dharitri_codec::dharitri_codec_derive::{
                 NestedDecode, NestedEncode, TopDecode, TopDecodeOrDefault, TopEncode,
-                TopEncodeOrDefault,
+                TopEncodeOrDefault, VersionedCodec,
             },
         };
     };
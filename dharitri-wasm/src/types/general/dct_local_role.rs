@@ -10,6 +10,9 @@ const DCT_ROLE_LOCAL_BURN: &[u8] = b"DCTRoleLocalBurn";
 const DCT_ROLE_NFT_CREATE: &[u8] = b"DCTRoleNFTCreate";
 const DCT_ROLE_NFT_ADD_QUANTITY: &[u8] = b"DCTRoleNFTAddQuantity";
 const DCT_ROLE_NFT_BURN: &[u8] = b"DCTRoleNFTBurn";
+const DCT_ROLE_TRANSFER: &[u8] = b"DCTTransferRole";
+const DCT_ROLE_NFT_ADD_URI: &[u8] = b"DCTRoleNFTAddURI";
+const DCT_ROLE_NFT_UPDATE_ATTRIBUTES: &[u8] = b"DCTRoleNFTUpdateAttributes";
 
 #[derive(
     TopDecode, TopEncode, NestedDecode, NestedEncode, TypeAbi, Clone, PartialEq, Debug, Copy,
@@ -21,6 +24,9 @@ pub enum DctLocalRole {
     NftCreate,
     NftAddQuantity,
     NftBurn,
+    Transfer,
+    NftAddUri,
+    NftUpdateAttributes,
 }
 
 impl DctLocalRole {
@@ -32,6 +38,9 @@ impl DctLocalRole {
             Self::NftCreate => 3,
             Self::NftAddQuantity => 4,
             Self::NftBurn => 5,
+            Self::Transfer => 6,
+            Self::NftAddUri => 7,
+            Self::NftUpdateAttributes => 8,
         }
     }
 
@@ -43,6 +52,9 @@ impl DctLocalRole {
             Self::NftCreate => DCT_ROLE_NFT_CREATE,
             Self::NftAddQuantity => DCT_ROLE_NFT_ADD_QUANTITY,
             Self::NftBurn => DCT_ROLE_NFT_BURN,
+            Self::Transfer => DCT_ROLE_TRANSFER,
+            Self::NftAddUri => DCT_ROLE_NFT_ADD_URI,
+            Self::NftUpdateAttributes => DCT_ROLE_NFT_UPDATE_ATTRIBUTES,
         }
     }
 
@@ -54,18 +66,24 @@ impl DctLocalRole {
             Self::NftCreate => DctLocalRoleFlags::NFT_CREATE,
             Self::NftAddQuantity => DctLocalRoleFlags::NFT_ADD_QUANTITY,
             Self::NftBurn => DctLocalRoleFlags::NFT_BURN,
+            Self::Transfer => DctLocalRoleFlags::TRANSFER,
+            Self::NftAddUri => DctLocalRoleFlags::NFT_ADD_URI,
+            Self::NftUpdateAttributes => DctLocalRoleFlags::NFT_UPDATE_ATTRIBUTES,
         }
     }
 }
 
 // TODO: can be done with macros, but I didn't find a public library that does it and is no_std
 // we can implement it, it's easy
-const ALL_ROLES: [DctLocalRole; 5] = [
+const ALL_ROLES: [DctLocalRole; 8] = [
     DctLocalRole::Mint,
     DctLocalRole::Burn,
     DctLocalRole::NftCreate,
     DctLocalRole::NftAddQuantity,
     DctLocalRole::NftBurn,
+    DctLocalRole::NftAddUri,
+    DctLocalRole::NftUpdateAttributes,
+    DctLocalRole::Transfer,
 ];
 
 impl DctLocalRole {
@@ -83,6 +101,9 @@ impl From<u8> for DctLocalRole {
             3 => Self::NftCreate,
             4 => Self::NftAddQuantity,
             5 => Self::NftBurn,
+            6 => Self::Transfer,
+            7 => Self::NftAddUri,
+            8 => Self::NftUpdateAttributes,
             _ => Self::None,
         }
     }
@@ -101,6 +122,12 @@ impl<'a> From<&'a [u8]> for DctLocalRole {
             Self::NftAddQuantity
         } else if byte_slice == DCT_ROLE_NFT_BURN {
             Self::NftBurn
+        } else if byte_slice == DCT_ROLE_TRANSFER {
+            Self::Transfer
+        } else if byte_slice == DCT_ROLE_NFT_ADD_URI {
+            Self::NftAddUri
+        } else if byte_slice == DCT_ROLE_NFT_UPDATE_ATTRIBUTES {
+            Self::NftUpdateAttributes
         } else {
             Self::None
         }
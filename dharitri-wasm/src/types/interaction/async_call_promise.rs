@@ -0,0 +1,89 @@
+use core::marker::PhantomData;
+
+use crate::{
+    api::{CallTypeApi, SendApiImpl},
+    contract_base::ManagedSerializer,
+    types::{BigUint, CallbackClosure, ManagedAddress, ManagedArgBuffer, ManagedBuffer},
+};
+
+/// Gas reserved for the callback, unless overridden with `with_extra_gas_for_callback`.
+pub const DEFAULT_EXTRA_GAS_FOR_CALLBACK: u64 = 10_000_000;
+
+/// An asynchronous call that does not end the execution of the current transaction.
+///
+/// Any number of them can be registered during a transaction.
+/// They are performed after the transaction ends, in the order of registration,
+/// each followed by its own callback.
+#[must_use]
+pub struct AsyncCallPromise<SA>
+where
+    SA: CallTypeApi + 'static,
+{
+    pub(crate) _phantom: PhantomData<SA>,
+    pub(crate) to: ManagedAddress<SA>,
+    pub(crate) moax_payment: BigUint<SA>,
+    pub(crate) endpoint_name: ManagedBuffer<SA>,
+    pub(crate) arg_buffer: ManagedArgBuffer<SA>,
+    pub(crate) gas_limit: u64,
+    pub(crate) extra_gas_for_callback: u64,
+    pub(crate) success_callback: Option<CallbackClosure<SA>>,
+    pub(crate) error_callback: Option<CallbackClosure<SA>>,
+}
+
+#[allow(clippy::return_self_not_must_use)]
+impl<SA> AsyncCallPromise<SA>
+where
+    SA: CallTypeApi,
+{
+    /// The callback that gets called after the call.
+    /// Unless an error callback is also specified, it handles failed calls too.
+    pub fn with_callback(self, callback_call: CallbackClosure<SA>) -> Self {
+        AsyncCallPromise {
+            success_callback: Some(callback_call),
+            ..self
+        }
+    }
+
+    /// Overrides the callback for the case where the call fails.
+    pub fn with_error_callback(self, callback_call: CallbackClosure<SA>) -> Self {
+        AsyncCallPromise {
+            error_callback: Some(callback_call),
+            ..self
+        }
+    }
+
+    /// Gas reserved for the callback, on top of the gas limit of the call.
+    /// Defaults to `DEFAULT_EXTRA_GAS_FOR_CALLBACK`.
+    pub fn with_extra_gas_for_callback(self, gas_limit: u64) -> Self {
+        AsyncCallPromise {
+            extra_gas_for_callback: gas_limit,
+            ..self
+        }
+    }
+
+    /// Hands the call over to the VM. Execution of the current transaction continues.
+    pub fn register_promise(self) {
+        let serializer = ManagedSerializer::<SA>::new();
+        let success_callback_closure = self
+            .success_callback
+            .as_ref()
+            .map(|closure| serializer.top_encode_to_managed_buffer(closure))
+            .unwrap_or_default();
+        let error_callback_closure = self
+            .error_callback
+            .as_ref()
+            .map(|closure| serializer.top_encode_to_managed_buffer(closure))
+            .unwrap_or_else(|| success_callback_closure.clone());
+
+        SA::send_api_impl().create_async_call_raw(
+            &self.to,
+            &self.moax_payment,
+            &self.endpoint_name,
+            &self.arg_buffer,
+            &success_callback_closure,
+            &error_callback_closure,
+            self.gas_limit,
+            self.extra_gas_for_callback,
+        );
+    }
+}
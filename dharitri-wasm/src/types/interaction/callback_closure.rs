@@ -71,6 +71,20 @@ impl<M: ManagedTypeApi + ErrorApi> CallbackClosure<M> {
         }
     }
 
+    /// Retrieves the closure of the callback being executed:
+    /// the one handed back by the VM for promises,
+    /// otherwise the one saved to storage before the async call.
+    pub fn load_for_callback<A: BlockchainApi + StorageReadApi + StorageWriteApi>() -> Option<Self>
+    {
+        let promise_closure_raw = A::blockchain_api_impl().get_callback_closure::<A>();
+        if !promise_closure_raw.is_empty() {
+            let serializer = ManagedSerializer::<A>::new();
+            Some(serializer.top_decode_from_managed_buffer(&promise_closure_raw))
+        } else {
+            Self::storage_load_and_clear::<A>()
+        }
+    }
+
     pub fn matcher<const CB_NAME_MAX_LENGTH: usize>(
         &self,
     ) -> CallbackClosureMatcher<CB_NAME_MAX_LENGTH> {
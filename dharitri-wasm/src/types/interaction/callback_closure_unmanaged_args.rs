@@ -1,7 +1,8 @@
 use core::marker::PhantomData;
 
 use crate::{
-    api::{BlockchainApi, ManagedTypeApi, StorageReadApi, StorageWriteApi},
+    api::{BlockchainApi, BlockchainApiImpl, ManagedTypeApi, StorageReadApi, StorageWriteApi},
+    contract_base::ManagedSerializer,
     storage_clear, storage_get, storage_get_len,
     types::{BoxedBytes, ManagedBuffer, ManagedBytesNestedDecodeInput, ManagedType},
     BytesArgLoader,
@@ -41,6 +42,20 @@ impl<M: ManagedTypeApi> CallbackClosureUnmanagedArgs<M> {
         }
     }
 
+    /// Retrieves the closure of the callback being executed:
+    /// the one handed back by the VM for promises,
+    /// otherwise the one saved to storage before the async call.
+    pub fn load_for_callback<A: BlockchainApi + StorageReadApi + StorageWriteApi>() -> Option<Self>
+    {
+        let promise_closure_raw = A::blockchain_api_impl().get_callback_closure::<A>();
+        if !promise_closure_raw.is_empty() {
+            let serializer = ManagedSerializer::<A>::new();
+            Some(serializer.top_decode_from_managed_buffer(&promise_closure_raw))
+        } else {
+            Self::storage_load_and_clear::<A>()
+        }
+    }
+
     pub fn matcher<const CB_NAME_MAX_LENGTH: usize>(
         &self,
     ) -> CallbackClosureMatcher<CB_NAME_MAX_LENGTH> {
@@ -5,8 +5,8 @@ use crate::{
     },
     contract_base::BlockchainWrapper,
     types::{
-        AsyncCall, BigUint, DctTokenPayment, ManagedAddress, ManagedArgBuffer, ManagedBuffer,
-        ManagedType, ManagedVec, TokenIdentifier,
+        AsyncCall, AsyncCallPromise, BigUint, DctTokenPayment, ManagedAddress, ManagedArgBuffer,
+        ManagedBuffer, ManagedType, ManagedVec, TokenIdentifier, DEFAULT_EXTRA_GAS_FOR_CALLBACK,
     },
     ArgId, ContractCallArg, DynArg, ManagedResultArgLoader,
 };
@@ -140,6 +140,27 @@ where
         endpoint_arg.push_dyn_arg(&mut self.arg_buffer);
     }
 
+    pub fn get_to(&self) -> &ManagedAddress<SA> {
+        &self.to
+    }
+
+    pub fn get_endpoint_name(&self) -> &ManagedBuffer<SA> {
+        &self.endpoint_name
+    }
+
+    pub fn get_moax_payment(&self) -> &BigUint<SA> {
+        &self.moax_payment
+    }
+
+    /// The DCT payments, before being converted to a `DCTTransfer`/`DCTNFTTransfer`/`MultiDCTNFTTransfer` call.
+    pub fn get_dct_payments(&self) -> &ManagedVec<SA, DctTokenPayment<SA>> {
+        &self.payments
+    }
+
+    pub fn get_arg_buffer(&self) -> &ManagedArgBuffer<SA> {
+        &self.arg_buffer
+    }
+
     fn no_payments(&self) -> ManagedVec<SA, DctTokenPayment<SA>> {
         ManagedVec::new()
     }
@@ -272,6 +293,23 @@ where
             callback_call: None,
         }
     }
+
+    /// Converts the call into a promise, which does not end the current execution.
+    /// Several promises can be registered in the same transaction.
+    pub fn async_call_promise(mut self) -> AsyncCallPromise<SA> {
+        self = self.convert_to_dct_transfer_call();
+        AsyncCallPromise {
+            _phantom: PhantomData,
+            gas_limit: self.resolve_gas_limit(),
+            to: self.to,
+            moax_payment: self.moax_payment,
+            endpoint_name: self.endpoint_name,
+            arg_buffer: self.arg_buffer,
+            extra_gas_for_callback: DEFAULT_EXTRA_GAS_FOR_CALLBACK,
+            success_callback: None,
+            error_callback: None,
+        }
+    }
 }
 
 impl<SA, R> ContractCall<SA, R>
@@ -1,25 +1,29 @@
 mod arg_buffer;
 mod arg_buffer_managed;
 mod async_call;
+mod async_call_promise;
 mod callback_closure;
 mod callback_closure_unmanaged_args;
 mod callback_selector_result;
 mod contract_call;
 mod contract_deploy;
-mod send_moax;
 mod send_dct;
+mod send_moax;
 mod send_token;
 
 pub use arg_buffer::ArgBuffer;
 pub use arg_buffer_managed::ManagedArgBuffer;
 pub use async_call::AsyncCall;
-pub use callback_closure::{new_callback_call, CallbackClosure, CallbackClosureMatcher};
+pub use async_call_promise::{AsyncCallPromise, DEFAULT_EXTRA_GAS_FOR_CALLBACK};
+pub use callback_closure::{
+    new_callback_call, CallbackClosure, CallbackClosureMatcher, CALLBACK_CLOSURE_STORAGE_BASE_KEY,
+};
 pub use callback_closure_unmanaged_args::CallbackClosureUnmanagedArgs;
 pub use callback_selector_result::CallbackSelectorResult;
 pub use contract_call::{new_contract_call, ContractCall};
 pub use contract_deploy::{new_contract_deploy, ContractDeploy};
-pub use send_moax::SendMoax;
 pub use send_dct::SendDct;
+pub use send_moax::SendMoax;
 pub use send_token::SendToken;
 
 #[cfg(feature = "cb_closure_managed_deser")]
@@ -0,0 +1,38 @@
+use core::cmp::Ordering;
+
+use crate::api::ManagedTypeApi;
+
+use super::ManagedBuffer;
+
+const COMPARE_CHUNK_SIZE: usize = 32;
+
+impl<M: ManagedTypeApi> PartialOrd for ManagedBuffer<M> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Lexicographic comparison of the contents.
+/// Bytes are loaded in small chunks on the stack, so no heap allocation is performed.
+impl<M: ManagedTypeApi> Ord for ManagedBuffer<M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let self_len = self.len();
+        let other_len = other.len();
+        let common_len = core::cmp::min(self_len, other_len);
+        let mut self_chunk = [0u8; COMPARE_CHUNK_SIZE];
+        let mut other_chunk = [0u8; COMPARE_CHUNK_SIZE];
+        let mut position = 0;
+        while position < common_len {
+            let chunk_len = core::cmp::min(COMPARE_CHUNK_SIZE, common_len - position);
+            let _ = self.load_slice(position, &mut self_chunk[..chunk_len]);
+            let _ = other.load_slice(position, &mut other_chunk[..chunk_len]);
+            match self_chunk[..chunk_len].cmp(&other_chunk[..chunk_len]) {
+                Ordering::Equal => {},
+                other => return other,
+            }
+            position += chunk_len;
+        }
+        self_len.cmp(&other_len)
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    api::ManagedTypeApi,
+    hex_util::{byte_to_hex_digits, hex_digits_to_byte},
+};
+
+use super::{BigUint, ManagedBuffer, ManagedVec};
+
+/// Size of the stack buffers used to process managed buffer contents piece by piece.
+const TEXT_CHUNK_SIZE: usize = 64;
+
+/// Largest power of 10 that fits in a u64 is 10^19, keep some margin.
+const DECIMAL_DIGITS_PER_STEP: usize = 18;
+
+/// Text utilities for managed buffers.
+///
+/// All of them work on top of the managed buffer API,
+/// loading data in small chunks on the stack instead of copying it to the heap.
+impl<M: ManagedTypeApi> ManagedBuffer<M> {
+    /// Index of the first occurrence of the pattern, starting from the given position.
+    pub fn find_from(&self, start_position: usize, pattern: &[u8]) -> Option<usize> {
+        let len = self.len();
+        if start_position + pattern.len() > len {
+            return None;
+        }
+        if pattern.is_empty() {
+            return Some(start_position);
+        }
+        let last_match_position = len - pattern.len();
+        let mut chunk = [0u8; TEXT_CHUNK_SIZE];
+        let mut chunk_start = start_position;
+        while chunk_start <= last_match_position {
+            let chunk_len = core::cmp::min(TEXT_CHUNK_SIZE, len - chunk_start);
+            let _ = self.load_slice(chunk_start, &mut chunk[..chunk_len]);
+            for offset in 0..chunk_len {
+                let position = chunk_start + offset;
+                if position > last_match_position {
+                    return None;
+                }
+                if chunk[offset] == pattern[0]
+                    && self.matches_at(position, pattern, &chunk[offset..chunk_len])
+                {
+                    return Some(position);
+                }
+            }
+            chunk_start += chunk_len;
+        }
+        None
+    }
+
+    /// Checks whether the pattern occurs at the given position.
+    /// The bytes already loaded from that position are compared first,
+    /// the rest is loaded in chunks, only if needed.
+    fn matches_at(&self, position: usize, pattern: &[u8], preloaded: &[u8]) -> bool {
+        let known_len = core::cmp::min(preloaded.len(), pattern.len());
+        if preloaded[..known_len] != pattern[..known_len] {
+            return false;
+        }
+        let mut chunk = [0u8; TEXT_CHUNK_SIZE];
+        let mut checked_len = known_len;
+        while checked_len < pattern.len() {
+            let chunk_len = core::cmp::min(TEXT_CHUNK_SIZE, pattern.len() - checked_len);
+            if self
+                .load_slice(position + checked_len, &mut chunk[..chunk_len])
+                .is_err()
+            {
+                return false;
+            }
+            if chunk[..chunk_len] != pattern[checked_len..checked_len + chunk_len] {
+                return false;
+            }
+            checked_len += chunk_len;
+        }
+        true
+    }
+
+    /// Index of the first occurrence of the pattern, if any.
+    #[inline]
+    pub fn find(&self, pattern: &[u8]) -> Option<usize> {
+        self.find_from(0, pattern)
+    }
+
+    #[inline]
+    pub fn contains_slice(&self, pattern: &[u8]) -> bool {
+        self.find(pattern).is_some()
+    }
+
+    pub fn starts_with(&self, prefix: &[u8]) -> bool {
+        prefix.len() <= self.len() && self.matches_at(0, prefix, &[])
+    }
+
+    pub fn ends_with(&self, suffix: &[u8]) -> bool {
+        let len = self.len();
+        suffix.len() <= len && self.matches_at(len - suffix.len(), suffix, &[])
+    }
+
+    /// Splits the contents around each occurrence of the delimiter.
+    ///
+    /// Same semantics as `slice::split`: empty parts are kept,
+    /// so splitting an empty buffer yields a single empty part.
+    /// An empty delimiter yields the whole buffer as a single part.
+    pub fn split(&self, delimiter: &[u8]) -> ManagedVec<M, ManagedBuffer<M>> {
+        let mut result = ManagedVec::new();
+        let len = self.len();
+        let mut part_start = 0;
+        if !delimiter.is_empty() {
+            while let Some(delimiter_position) = self.find_from(part_start, delimiter) {
+                result.push(self.copy_slice_or_empty(part_start, delimiter_position));
+                part_start = delimiter_position + delimiter.len();
+            }
+        }
+        result.push(self.copy_slice_or_empty(part_start, len));
+        result
+    }
+
+    fn copy_slice_or_empty(&self, start: usize, end: usize) -> ManagedBuffer<M> {
+        self.copy_slice(start, end - start).unwrap_or_default()
+    }
+
+    /// The contents, encoded as lowercase hex digits, without any prefix.
+    pub fn to_hex(&self) -> ManagedBuffer<M> {
+        let mut result = ManagedBuffer::new();
+        let len = self.len();
+        let mut chunk = [0u8; TEXT_CHUNK_SIZE];
+        let mut hex_chunk = [0u8; TEXT_CHUNK_SIZE * 2];
+        let mut position = 0;
+        while position < len {
+            let chunk_len = core::cmp::min(TEXT_CHUNK_SIZE, len - position);
+            let _ = self.load_slice(position, &mut chunk[..chunk_len]);
+            for (i, byte) in chunk[..chunk_len].iter().enumerate() {
+                let (digit1, digit2) = byte_to_hex_digits(*byte);
+                hex_chunk[i * 2] = digit1;
+                hex_chunk[i * 2 + 1] = digit2;
+            }
+            result.append_bytes(&hex_chunk[..chunk_len * 2]);
+            position += chunk_len;
+        }
+        result
+    }
+
+    /// Interprets the contents as lowercase hex digits, without any prefix, and decodes them.
+    /// Returns `None` if the length is odd or if any of the characters is not a hex digit.
+    pub fn parse_hex(&self) -> Option<ManagedBuffer<M>> {
+        let len = self.len();
+        if len & 1 != 0 {
+            return None;
+        }
+        let mut result = ManagedBuffer::new();
+        let mut hex_chunk = [0u8; TEXT_CHUNK_SIZE * 2];
+        let mut chunk = [0u8; TEXT_CHUNK_SIZE];
+        let mut position = 0;
+        while position < len {
+            let hex_chunk_len = core::cmp::min(TEXT_CHUNK_SIZE * 2, len - position);
+            let _ = self.load_slice(position, &mut hex_chunk[..hex_chunk_len]);
+            let chunk_len = hex_chunk_len / 2;
+            for (i, byte) in chunk[..chunk_len].iter_mut().enumerate() {
+                *byte = hex_digits_to_byte(hex_chunk[i * 2], hex_chunk[i * 2 + 1])?;
+            }
+            result.append_bytes(&chunk[..chunk_len]);
+            position += hex_chunk_len;
+        }
+        Some(result)
+    }
+
+    /// Interprets the contents as a number written in base 10, e.g. `b"1234"`.
+    /// Returns `None` if the buffer is empty or if it contains anything other than decimal digits.
+    pub fn parse_as_biguint_decimal(&self) -> Option<BigUint<M>> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let mut result = BigUint::zero();
+        let mut digits = [0u8; DECIMAL_DIGITS_PER_STEP];
+        let mut position = 0;
+        while position < len {
+            let step_len = core::cmp::min(DECIMAL_DIGITS_PER_STEP, len - position);
+            let _ = self.load_slice(position, &mut digits[..step_len]);
+            let mut step_value = 0u64;
+            let mut step_multiplier = 1u64;
+            for digit in &digits[..step_len] {
+                if !digit.is_ascii_digit() {
+                    return None;
+                }
+                step_value = step_value * 10 + (digit - b'0') as u64;
+                step_multiplier *= 10;
+            }
+            result *= step_multiplier;
+            result += step_value;
+            position += step_len;
+        }
+        Some(result)
+    }
+}
@@ -0,0 +1,396 @@
+use crate::api::ManagedTypeApi;
+
+use super::{ManagedMapKey, ManagedVec, ManagedVecItem, ManagedVecOwnedIterator};
+
+/// An in-memory map that lives entirely in managed buffers.
+///
+/// Keys are kept sorted in one `ManagedVec` and the values sit at the same positions in another,
+/// so lookups are binary searches and no heap allocation is performed.
+/// Meant for aggregating data within a single call (e.g. summing payments by token);
+/// for persistent data use `MapMapper`, whose API this type mirrors.
+pub struct ManagedMap<M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M>,
+    V: ManagedVecItem,
+{
+    keys: ManagedVec<M, K>,
+    values: ManagedVec<M, V>,
+}
+
+impl<M, K, V> ManagedMap<M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M>,
+    V: ManagedVecItem,
+{
+    #[inline]
+    pub fn new() -> Self {
+        ManagedMap {
+            keys: ManagedVec::new(),
+            values: ManagedVec::new(),
+        }
+    }
+
+    /// `Ok` with the position of the key, or `Err` with the position where it should be inserted.
+    fn search_key(&self, key: &K) -> Result<usize, usize> {
+        self.keys.binary_search_by(|current| current.key_cmp(key))
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.search_key(key).is_ok()
+    }
+
+    /// Gets the value associated with the key, if present.
+    pub fn get(&self, key: &K) -> Option<V::Ref<'_>> {
+        match self.search_key(key) {
+            Ok(index) => self.values.try_get(index),
+            Err(_) => None,
+        }
+    }
+
+    /// Adds a key-value pair to the map.
+    /// If the key was already present, the old value is replaced and returned.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.search_key(&key) {
+            Ok(index) => {
+                let old_value = unsafe { self.values.get_unsafe(index) };
+                let _ = self.values.set(index, &value);
+                Some(old_value)
+            },
+            Err(index) => {
+                self.keys.insert(index, key);
+                self.values.insert(index, value);
+                None
+            },
+        }
+    }
+
+    /// Removes the entry from the map, returning the value, if the key was present.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        match self.search_key(key) {
+            Ok(index) => {
+                let _ = self.keys.remove(index);
+                Some(self.values.remove(index))
+            },
+            Err(_) => None,
+        }
+    }
+
+    /// Gets the given key's corresponding entry in the map for in-place manipulation.
+    pub fn entry(&mut self, key: K) -> Entry<'_, M, K, V> {
+        match self.search_key(&key) {
+            Ok(index) => Entry::Occupied(OccupiedEntry {
+                key,
+                index,
+                map: self,
+            }),
+            Err(index) => Entry::Vacant(VacantEntry {
+                key,
+                index,
+                map: self,
+            }),
+        }
+    }
+
+    /// Removes all entries while retaining the underlying handles.
+    pub fn clear(&mut self) {
+        self.keys.clear();
+        self.values.clear();
+    }
+
+    /// An iterator visiting all keys, in ascending order of their byte representation.
+    pub fn keys(&self) -> ManagedVecOwnedIterator<'_, M, K> {
+        self.keys.into_iter()
+    }
+
+    /// An iterator visiting all values, in the order of their keys.
+    pub fn values(&self) -> ManagedVecOwnedIterator<'_, M, V> {
+        self.values.into_iter()
+    }
+
+    /// An iterator visiting all key-value pairs, in ascending order of the keys.
+    pub fn iter(&self) -> Iter<'_, M, K, V> {
+        Iter {
+            keys: self.keys.into_iter(),
+            values: self.values.into_iter(),
+        }
+    }
+}
+
+impl<M, K, V> Default for ManagedMap<M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M>,
+    V: ManagedVecItem,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M, K, V> Clone for ManagedMap<M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M> + Clone,
+    V: ManagedVecItem + Clone,
+{
+    fn clone(&self) -> Self {
+        ManagedMap {
+            keys: self.keys.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<M, K, V> core::fmt::Debug for ManagedMap<M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M> + core::fmt::Debug,
+    V: ManagedVecItem + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+pub struct Iter<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M>,
+    V: ManagedVecItem,
+{
+    keys: ManagedVecOwnedIterator<'a, M, K>,
+    values: ManagedVecOwnedIterator<'a, M, V>,
+}
+
+impl<'a, M, K, V> Iterator for Iter<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M>,
+    V: ManagedVecItem,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        let key = self.keys.next()?;
+        let value = self.values.next()?;
+        Some((key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<'a, M, K, V> ExactSizeIterator for Iter<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M>,
+    V: ManagedVecItem,
+{
+}
+
+/// A view into a single entry in a map, which may either be vacant or occupied.
+///
+/// This `enum` is constructed from the [`entry`] method on [`ManagedMap`].
+///
+/// [`entry`]: ManagedMap::entry
+pub enum Entry<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M>,
+    V: ManagedVecItem,
+{
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, M, K, V>),
+
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, M, K, V>),
+}
+
+/// A view into a vacant entry in a `ManagedMap`.
+/// It is part of the [`Entry`] enum.
+pub struct VacantEntry<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M>,
+    V: ManagedVecItem,
+{
+    key: K,
+    index: usize,
+    map: &'a mut ManagedMap<M, K, V>,
+}
+
+/// A view into an occupied entry in a `ManagedMap`.
+/// It is part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M>,
+    V: ManagedVecItem,
+{
+    key: K,
+    index: usize,
+    map: &'a mut ManagedMap<M, K, V>,
+}
+
+impl<'a, M, K, V> Entry<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M>,
+    V: ManagedVecItem,
+{
+    /// Ensures a value is in the entry by inserting the default if empty, and returns
+    /// an `OccupiedEntry`.
+    pub fn or_insert(self, default: V) -> OccupiedEntry<'a, M, K, V> {
+        match self {
+            Entry::Occupied(entry) => entry,
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default function if empty,
+    /// and returns an `OccupiedEntry`.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> OccupiedEntry<'a, M, K, V> {
+        match self {
+            Entry::Occupied(entry) => entry,
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting, if empty, the result of the default function,
+    /// which receives a reference to the key.
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> OccupiedEntry<'a, M, K, V> {
+        match self {
+            Entry::Occupied(entry) => entry,
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            },
+        }
+    }
+
+    /// Returns a reference to this entry's key.
+    pub fn key(&self) -> &K {
+        match *self {
+            Entry::Occupied(ref entry) => entry.key(),
+            Entry::Vacant(ref entry) => entry.key(),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            Entry::Occupied(mut entry) => {
+                entry.update(f);
+                Entry::Occupied(entry)
+            },
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, M, K, V> Entry<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M>,
+    V: ManagedVecItem + Default,
+{
+    /// Ensures a value is in the entry by inserting the default value if empty,
+    /// and returns an `OccupiedEntry`.
+    pub fn or_default(self) -> OccupiedEntry<'a, M, K, V> {
+        match self {
+            Entry::Occupied(entry) => entry,
+            Entry::Vacant(entry) => entry.insert(Default::default()),
+        }
+    }
+}
+
+impl<'a, M, K, V> VacantEntry<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M>,
+    V: ManagedVecItem,
+{
+    /// Gets a reference to the key that would be used when inserting a value
+    /// through the VacantEntry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Sets the value of the entry with the `VacantEntry`'s key,
+    /// and returns an `OccupiedEntry`.
+    pub fn insert(self, value: V) -> OccupiedEntry<'a, M, K, V> {
+        self.map.keys.insert(self.index, self.key);
+        self.map.values.insert(self.index, value);
+        // the key handle is shared between the map and the entry, the entry only reads it
+        let key = unsafe { self.map.keys.get_unsafe(self.index) };
+        OccupiedEntry {
+            key,
+            index: self.index,
+            map: self.map,
+        }
+    }
+}
+
+impl<'a, M, K, V> OccupiedEntry<'a, M, K, V>
+where
+    M: ManagedTypeApi,
+    K: ManagedMapKey<M>,
+    V: ManagedVecItem,
+{
+    /// Gets a reference to the key in the entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Take ownership of the key and value from the map.
+    pub fn remove_entry(self) -> (K, V) {
+        let _ = self.map.keys.remove(self.index);
+        let value = self.map.values.remove(self.index);
+        (self.key, value)
+    }
+
+    /// Gets the value in the entry.
+    pub fn get(&self) -> V::Ref<'_> {
+        self.map.values.get(self.index)
+    }
+
+    /// Syntactic sugar, to more compactly express a get, update and set in one line.
+    /// Applies the given closure to the value and saves the final value back in the map.
+    /// Propagates the return value of the given function.
+    pub fn update<R, F: FnOnce(&mut V) -> R>(&mut self, f: F) -> R {
+        let mut value_ref = self.map.values.get_mut(self.index);
+        f(&mut value_ref)
+    }
+
+    /// Sets the value of the entry with the `OccupiedEntry`'s key,
+    /// and returns the entry's old value.
+    pub fn insert(self, value: V) -> V {
+        let old_value = unsafe { self.map.values.get_unsafe(self.index) };
+        let _ = self.map.values.set(self.index, &value);
+        old_value
+    }
+
+    /// Takes the value of the entry out of the map, and returns it.
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+}
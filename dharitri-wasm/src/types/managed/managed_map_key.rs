@@ -0,0 +1,37 @@
+use core::cmp::Ordering;
+
+use crate::{api::ManagedTypeApi, types::TokenIdentifier};
+
+use super::{ManagedAddress, ManagedBuffer, ManagedVecItem};
+
+/// Types that can serve as keys in a `ManagedMap` or as items in a `ManagedSet`.
+///
+/// Keys are ordered lexicographically by the contents of their underlying managed buffer.
+pub trait ManagedMapKey<M: ManagedTypeApi>: ManagedVecItem {
+    fn key_buffer(&self) -> &ManagedBuffer<M>;
+
+    fn key_cmp(&self, other: &Self) -> Ordering {
+        self.key_buffer().cmp(other.key_buffer())
+    }
+}
+
+impl<M: ManagedTypeApi> ManagedMapKey<M> for ManagedBuffer<M> {
+    #[inline]
+    fn key_buffer(&self) -> &ManagedBuffer<M> {
+        self
+    }
+}
+
+impl<M: ManagedTypeApi> ManagedMapKey<M> for TokenIdentifier<M> {
+    #[inline]
+    fn key_buffer(&self) -> &ManagedBuffer<M> {
+        self.as_managed_buffer()
+    }
+}
+
+impl<M: ManagedTypeApi> ManagedMapKey<M> for ManagedAddress<M> {
+    #[inline]
+    fn key_buffer(&self) -> &ManagedBuffer<M> {
+        self.as_managed_buffer()
+    }
+}
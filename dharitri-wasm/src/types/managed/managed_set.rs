@@ -0,0 +1,132 @@
+use crate::api::ManagedTypeApi;
+
+use super::{ManagedMapKey, ManagedVec, ManagedVecOwnedIterator};
+
+/// An in-memory set that lives entirely in managed buffers.
+///
+/// Items are kept sorted in a `ManagedVec`, so lookups are binary searches
+/// and no heap allocation is performed.
+/// Meant for use within a single call; for persistent data use `SetMapper`.
+pub struct ManagedSet<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedMapKey<M>,
+{
+    items: ManagedVec<M, T>,
+}
+
+impl<M, T> ManagedSet<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedMapKey<M>,
+{
+    #[inline]
+    pub fn new() -> Self {
+        ManagedSet {
+            items: ManagedVec::new(),
+        }
+    }
+
+    fn search(&self, item: &T) -> Result<usize, usize> {
+        self.items.binary_search_by(|current| current.key_cmp(item))
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Number of items.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn contains(&self, item: &T) -> bool {
+        self.search(item).is_ok()
+    }
+
+    /// Adds a value to the set.
+    /// If the set did not have this value present, `true` is returned.
+    /// If the set did have this value present, `false` is returned.
+    pub fn insert(&mut self, item: T) -> bool {
+        match self.search(&item) {
+            Ok(_) => false,
+            Err(index) => {
+                self.items.insert(index, item);
+                true
+            },
+        }
+    }
+
+    /// Removes a value from the set. Returns whether the value was present in the set.
+    pub fn remove(&mut self, item: &T) -> bool {
+        match self.search(item) {
+            Ok(index) => {
+                let _ = self.items.remove(index);
+                true
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// Removes all items while retaining the underlying handle.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// An iterator visiting all items, in ascending order of their byte representation.
+    pub fn iter(&self) -> ManagedVecOwnedIterator<'_, M, T> {
+        self.items.into_iter()
+    }
+
+    /// The items, in ascending order of their byte representation.
+    pub fn as_managed_vec(&self) -> &ManagedVec<M, T> {
+        &self.items
+    }
+}
+
+impl<M, T> Default for ManagedSet<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedMapKey<M>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M, T> Clone for ManagedSet<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedMapKey<M> + Clone,
+{
+    fn clone(&self) -> Self {
+        ManagedSet {
+            items: self.items.clone(),
+        }
+    }
+}
+
+impl<'a, M, T> IntoIterator for &'a ManagedSet<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedMapKey<M>,
+{
+    type Item = T;
+    type IntoIter = ManagedVecOwnedIterator<'a, M, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<M, T> core::fmt::Debug for ManagedSet<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedMapKey<M> + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
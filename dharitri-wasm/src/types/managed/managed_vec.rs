@@ -5,7 +5,7 @@ use crate::{
     types::{ArgBuffer, BoxedBytes, ManagedBufferNestedDecodeInput},
 };
 use alloc::{string::String, vec::Vec};
-use core::marker::PhantomData;
+use core::{cmp::Ordering, marker::PhantomData};
 use dharitri_codec::{
     DecodeError, EncodeError, NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeOutput,
     TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
@@ -232,6 +232,288 @@ where
     pub fn iter(&self) -> ManagedVecRefIterator<M, T> {
         ManagedVecRefIterator::new(self)
     }
+
+    /// Copies the payload of the items in the range `[start_index, end_index)`
+    /// into a new managed buffer.
+    fn copy_payload_range(&self, start_index: usize, end_index: usize) -> ManagedBuffer<M> {
+        let byte_start = start_index * T::PAYLOAD_SIZE;
+        let byte_end = end_index * T::PAYLOAD_SIZE;
+        self.buffer
+            .copy_slice(byte_start, byte_end - byte_start)
+            .unwrap_or_default()
+    }
+
+    /// Replaces the payload with the contents of the given buffer, keeping the current handle.
+    fn overwrite_payload(&mut self, payload: &ManagedBuffer<M>) {
+        self.buffer.overwrite(&[]);
+        self.buffer.append(payload);
+    }
+
+    /// Inserts an item at position `index`, shifting all items after it to the right.
+    /// Signals an error and terminates execution if `index > len`.
+    pub fn insert(&mut self, index: usize, item: T) {
+        let len = self.len();
+        if index > len {
+            M::error_api_impl().signal_error(INDEX_OUT_OF_RANGE_MSG);
+        }
+        let mut new_buffer = self.copy_payload_range(0, index);
+        item.to_byte_writer(|bytes| {
+            new_buffer.append_bytes(bytes);
+        });
+        new_buffer.append(&self.copy_payload_range(index, len));
+        self.overwrite_payload(&new_buffer);
+    }
+
+    /// Removes and returns the item at position `index`, shifting all items after it to the left.
+    /// Signals an error and terminates execution if the index is out of range.
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        if index >= len {
+            M::error_api_impl().signal_error(INDEX_OUT_OF_RANGE_MSG);
+        }
+        let removed = unsafe { self.get_unsafe(index) };
+        let mut new_buffer = self.copy_payload_range(0, index);
+        new_buffer.append(&self.copy_payload_range(index + 1, len));
+        self.overwrite_payload(&new_buffer);
+        removed
+    }
+
+    /// Swaps the items at the two given positions.
+    /// Signals an error and terminates execution if any of the indexes is out of range.
+    pub fn swap(&mut self, index_a: usize, index_b: usize) {
+        let len = self.len();
+        if index_a >= len || index_b >= len {
+            M::error_api_impl().signal_error(INDEX_OUT_OF_RANGE_MSG);
+        }
+        if index_a == index_b {
+            return;
+        }
+        let item_a = unsafe { self.get_unsafe(index_a) };
+        let item_b = unsafe { self.get_unsafe(index_b) };
+        let _ = self.set(index_a, &item_b);
+        let _ = self.set(index_b, &item_a);
+    }
+
+    /// Retains only the items for which the predicate returns `true`.
+    /// The order of the retained items is preserved.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut new_buffer = ManagedBuffer::new();
+        for item in self.into_iter() {
+            if f(&item) {
+                item.to_byte_writer(|bytes| {
+                    new_buffer.append_bytes(bytes);
+                });
+            }
+        }
+        self.overwrite_payload(&new_buffer);
+    }
+
+    /// Removes consecutive items that resolve to the same key.
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&T) -> K,
+        K: PartialEq,
+    {
+        let mut new_buffer = ManagedBuffer::new();
+        let mut last_key: Option<K> = None;
+        for item in self.into_iter() {
+            let item_key = key(&item);
+            if last_key.as_ref() != Some(&item_key) {
+                item.to_byte_writer(|bytes| {
+                    new_buffer.append_bytes(bytes);
+                });
+                last_key = Some(item_key);
+            }
+        }
+        self.overwrite_payload(&new_buffer);
+    }
+
+    /// Returns the index of the first item for which the predicate returns `true`.
+    pub fn find_by<F>(&self, mut predicate: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        for index in 0..self.len() {
+            let item = unsafe { self.get_unsafe(index) };
+            if predicate(&item) {
+                return Some(index);
+            }
+        }
+        None
+    }
+
+    /// Stable sort, using the given comparator function.
+    ///
+    /// Loads all the items at once, sorts them in memory, then writes them all back at once.
+    /// Unlike `sort_unstable_by`, it needs heap memory for the items.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        if self.len() < 2 {
+            return;
+        }
+        let payload = self.buffer.to_boxed_bytes();
+        let mut items: Vec<T> = payload
+            .as_slice()
+            .chunks(T::PAYLOAD_SIZE)
+            .map(|item_payload| {
+                T::from_byte_reader(|dest_slice| dest_slice.copy_from_slice(item_payload))
+            })
+            .collect();
+        items.sort_by(|a, b| compare(a, b));
+
+        let mut sorted_payload = Vec::with_capacity(payload.len());
+        for item in &items {
+            item.to_byte_writer(|bytes| sorted_payload.extend_from_slice(bytes));
+        }
+        self.buffer.overwrite(sorted_payload.as_slice());
+    }
+
+    /// Stable sort, comparing the keys extracted by the given function.
+    pub fn sort_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_by(|a, b| key(a).cmp(&key(b)));
+    }
+
+    /// Unstable in-place sort (heapsort), using the given comparator function.
+    ///
+    /// Unlike `sort_by`, it does not need any temporary buffer,
+    /// since items are swapped directly in the underlying buffer.
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = self.len();
+        for start in (0..len / 2).rev() {
+            self.sift_down(start, len, &mut compare);
+        }
+        for end in (1..len).rev() {
+            self.swap(0, end);
+            self.sift_down(0, end, &mut compare);
+        }
+    }
+
+    fn sift_down<F>(&mut self, mut root: usize, end: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        loop {
+            let mut child = 2 * root + 1;
+            if child >= end {
+                return;
+            }
+            let mut child_item = unsafe { self.get_unsafe(child) };
+            if child + 1 < end {
+                let right_item = unsafe { self.get_unsafe(child + 1) };
+                if compare(&child_item, &right_item) == Ordering::Less {
+                    child += 1;
+                    child_item = right_item;
+                }
+            }
+            let root_item = unsafe { self.get_unsafe(root) };
+            if compare(&root_item, &child_item) != Ordering::Less {
+                return;
+            }
+            let _ = self.set(root, &child_item);
+            let _ = self.set(child, &root_item);
+            root = child;
+        }
+    }
+
+    /// Binary searches this sorted vec with a comparator function.
+    ///
+    /// Same semantics as `slice::binary_search_by`:
+    /// `Ok` contains the index of a matching item,
+    /// `Err` contains the index where a matching item could be inserted while maintaining sort order.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut left = 0;
+        let mut right = self.len();
+        while left < right {
+            let mid = left + (right - left) / 2;
+            let item = unsafe { self.get_unsafe(mid) };
+            match f(&item) {
+                Ordering::Less => left = mid + 1,
+                Ordering::Greater => right = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(left)
+    }
+
+    /// Binary searches this sorted vec with a key extraction function.
+    pub fn binary_search_by_key<K, F>(&self, key: &K, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.binary_search_by(|item| f(item).cmp(key))
+    }
+}
+
+impl<M, T> ManagedVec<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedVecItem + PartialEq,
+{
+    /// Returns the index of the first occurrence of the item, if present.
+    pub fn find(&self, item: &T) -> Option<usize> {
+        self.find_by(|current| current == item)
+    }
+
+    #[inline]
+    pub fn contains(&self, item: &T) -> bool {
+        self.find(item).is_some()
+    }
+
+    /// Removes consecutive repeated items.
+    /// If the vec is sorted, this removes all duplicates.
+    pub fn dedup(&mut self) {
+        let mut new_buffer = ManagedBuffer::new();
+        let mut last_item: Option<T> = None;
+        for item in self.into_iter() {
+            if last_item.as_ref() != Some(&item) {
+                item.to_byte_writer(|bytes| {
+                    new_buffer.append_bytes(bytes);
+                });
+                last_item = Some(item);
+            }
+        }
+        self.overwrite_payload(&new_buffer);
+    }
+}
+
+impl<M, T> ManagedVec<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedVecItem + Ord,
+{
+    /// Stable sort, see `sort_by`.
+    #[inline]
+    pub fn sort(&mut self) {
+        self.sort_by(T::cmp);
+    }
+
+    /// Unstable in-place sort, see `sort_unstable_by`.
+    #[inline]
+    pub fn sort_unstable(&mut self) {
+        self.sort_unstable_by(T::cmp);
+    }
+
+    /// Binary searches this sorted vec for a given item.
+    #[inline]
+    pub fn binary_search(&self, item: &T) -> Result<usize, usize> {
+        self.binary_search_by(|current| current.cmp(item))
+    }
 }
 
 impl<M, T> Clone for ManagedVec<M, T>
@@ -9,12 +9,17 @@ mod elliptic_curve;
 mod managed_address;
 mod managed_buffer;
 mod managed_buffer_cached_builder;
+mod managed_buffer_cmp;
+mod managed_buffer_text;
 mod managed_byte_array;
+pub mod managed_map;
+mod managed_map_key;
 mod managed_multi_result_vec;
 mod managed_multi_result_vec_counted;
 mod managed_multi_result_vec_eager;
 mod managed_multi_result_vec_iter;
 mod managed_ref;
+mod managed_set;
 mod managed_type_trait;
 mod managed_vec;
 mod managed_vec_item;
@@ -32,11 +37,14 @@ pub use managed_buffer::ManagedBuffer;
 pub use managed_buffer_cached_builder::ManagedBufferCachedBuilder;
 pub(crate) use managed_byte_array::ManagedBufferSizeContext;
 pub use managed_byte_array::ManagedByteArray;
+pub use managed_map::ManagedMap;
+pub use managed_map_key::ManagedMapKey;
 pub use managed_multi_result_vec::{ManagedMultiResultVec, ManagedVarArgs};
 pub use managed_multi_result_vec_counted::{ManagedCountedMultiResultVec, ManagedCountedVarArgs};
 pub use managed_multi_result_vec_eager::{ManagedMultiResultVecEager, ManagedVarArgsEager};
 pub use managed_multi_result_vec_iter::ManagedMultiResultVecIterator;
 pub use managed_ref::ManagedRef;
+pub use managed_set::ManagedSet;
 pub use managed_type_trait::ManagedType;
 pub use managed_vec::{
     managed_vec_from_slice_of_boxed_bytes, managed_vec_of_buffers_to_arg_buffer, ManagedVec,